@@ -79,6 +79,7 @@ class Ruleset(Base):
     name = Column(String(255), nullable=False)
     description = Column(Text, nullable=True)
     rhai_script = Column("rhai_code", Text, nullable=False)  # DB column: rhai_code
+    test_cases = Column(JSONB, default=list)  # 룰셋 단위 테스트 케이스
     version = Column(String(50), default="1.0.0")
     is_active = Column(Boolean, default=True)
     created_by = Column(PGUUID(as_uuid=True), ForeignKey("core.users.user_id"), nullable=True)
@@ -13,22 +13,32 @@ from sqlalchemy.orm import Session
 
 from app.database import get_db
 from app.models import Ruleset, Tenant
+from app.tools.rhai import diagnose_rhai, invalidate_rhai_cache
 
 router = APIRouter()
 
 
 # ============ Pydantic Models ============
 
+class RulesetTestCase(BaseModel):
+    name: str = Field(..., description="테스트 케이스 이름")
+    input: Dict[str, Any] = Field(..., description="룰셋 입력 데이터")
+    expected_status: Optional[str] = Field(None, description="기대 status")
+    expected_checks: Optional[List[Dict[str, Any]]] = Field(None, description="기대 checks (명시한 키만 비교)")
+
+
 class RulesetCreate(BaseModel):
     name: str = Field(..., description="룰셋 이름")
     description: Optional[str] = Field(None, description="룰셋 설명")
     rhai_script: str = Field(..., description="Rhai 스크립트 코드")
+    test_cases: List[RulesetTestCase] = Field(default_factory=list, description="단위 테스트 케이스")
 
 
 class RulesetUpdate(BaseModel):
     name: Optional[str] = Field(None, description="룰셋 이름")
     description: Optional[str] = Field(None, description="룰셋 설명")
     rhai_script: Optional[str] = Field(None, description="Rhai 스크립트 코드")
+    test_cases: Optional[List[RulesetTestCase]] = Field(None, description="단위 테스트 케이스")
     is_active: Optional[bool] = Field(None, description="활성 상태")
 
 
@@ -37,6 +47,7 @@ class RulesetResponse(BaseModel):
     name: str
     description: Optional[str]
     rhai_script: str
+    test_cases: List[RulesetTestCase] = []
     version: str
     is_active: bool
     created_at: datetime
@@ -80,6 +91,7 @@ def _ruleset_to_response(ruleset: Ruleset) -> RulesetResponse:
         name=ruleset.name,
         description=ruleset.description,
         rhai_script=ruleset.rhai_script,
+        test_cases=ruleset.test_cases or [],
         version=ruleset.version,
         is_active=ruleset.is_active,
         created_at=ruleset.created_at,
@@ -89,6 +101,23 @@ def _ruleset_to_response(ruleset: Ruleset) -> RulesetResponse:
 
 
 
+def _check_script(script: str) -> None:
+    """
+    저장 전 스크립트 정적 검증 (오류가 있으면 422와 진단 목록 반환)
+
+    네이티브 엔진이 없으면 검증하지 않는다.
+    """
+    report = diagnose_rhai(script)
+    if report is not None and not report["valid"]:
+        raise HTTPException(
+            status_code=422,
+            detail={
+                "message": "Invalid Rhai script",
+                "diagnostics": report["diagnostics"],
+            },
+        )
+
+
 def _get_or_create_tenant(db: Session) -> Tenant:
     """MVP용 기본 tenant 조회 또는 생성"""
     tenant = db.query(Tenant).first()
@@ -175,6 +204,21 @@ let severity = if defect_rate > 10.0 {
     "threshold": threshold,
     "requires_action": defect_rate > threshold
 }
+''',
+    "process_capability": '''
+// 공정능력 판정 룰 (도메인 함수 사용)
+let values = input.measurements;
+let lsl = input.lsl;
+let usl = input.usl;
+let cpk_value = cpk(values, lsl, usl);
+let rate = defect_rate(input.defect_count, input.production_count);
+
+#{
+    "status": if cpk_value < 1.0 || rate > 0.05 { "WARNING" } else { "NORMAL" },
+    "cpk": cpk_value,
+    "defect_rate": rate,
+    "out_of_spec": values.filter(|v| out_of_range(v, lsl, usl)).len()
+}
 '''
 }
 
@@ -266,6 +310,7 @@ async def create_ruleset(
     """
     새 룰셋 생성
     """
+    _check_script(ruleset.rhai_script)
     tenant = _get_or_create_tenant(db)
 
     new_ruleset = Ruleset(
@@ -273,6 +318,7 @@ async def create_ruleset(
         name=ruleset.name,
         description=ruleset.description,
         rhai_script=ruleset.rhai_script,
+        test_cases=[case.model_dump(exclude_none=True) for case in ruleset.test_cases],
         version="1.0.0",
         is_active=True,
     )
@@ -308,11 +354,16 @@ async def update_ruleset(
     if update_data.description is not None:
         ruleset.description = update_data.description
     if update_data.rhai_script is not None:
+        _check_script(update_data.rhai_script)
         ruleset.rhai_script = update_data.rhai_script
         # 스크립트 변경 시 버전 증가
         current_version = ruleset.version.split('.')
         current_version[-1] = str(int(current_version[-1]) + 1)
         ruleset.version = '.'.join(current_version)
+        # 이전 버전의 컴파일 캐시 제거
+        invalidate_rhai_cache(ruleset_id)
+    if update_data.test_cases is not None:
+        ruleset.test_cases = [case.model_dump(exclude_none=True) for case in update_data.test_cases]
     if update_data.is_active is not None:
         ruleset.is_active = update_data.is_active
 
@@ -2,16 +2,23 @@
 Rhai Rule Engine Python 바인딩
 Rust 기반 경량 스크립팅 엔진
 
-Note: 현재는 Python eval 대신 안전한 인터페이스로 Mock 구현.
-향후 PyO3를 사용한 Rust 바인딩으로 교체 예정.
+Rust 바인딩(crates/triflow-rules-py)이 설치되어 있으면 `RhaiEngine`은 네이티브
+엔진이고, 없으면 아래 Mock 구현(`_MockRhaiEngine`)으로 대체한다.
+
+설치: cd crates/triflow-rules-py && maturin develop --release
 """
-from typing import Any, Dict
+from typing import Any, Dict, List, Optional
 import json
 
+try:
+    from triflow_rules import RhaiEngine as NativeRhaiEngine
+except ImportError:
+    NativeRhaiEngine = None
+
 
-class RhaiEngine:
+class _MockRhaiEngine:
     """
-    Rhai 스크립팅 엔진 래퍼
+    Rhai 스크립팅 엔진 Mock (Rust 바인딩이 없을 때 대체 구현)
 
     MVP에서는 제한된 Python 표현식을 안전하게 실행하는 간단한 구현 제공.
     Production에서는 Rust Rhai 엔진과 PyO3 바인딩으로 교체 예정.
@@ -209,6 +216,10 @@ class RhaiEngine:
         return True
 
 
+# 공개 엔진: Rust 바인딩이 있으면 네이티브 엔진, 없으면 Mock
+RhaiEngine = NativeRhaiEngine if NativeRhaiEngine is not None else _MockRhaiEngine
+
+
 class RhaiEnginePool:
     """
     Rhai 엔진 풀 (재사용을 위한 간단한 풀링)
@@ -220,35 +231,87 @@ class RhaiEnginePool:
             pool_size: 풀에 유지할 엔진 인스턴스 수
         """
         self.pool_size = pool_size
-        self._engines = [RhaiEngine() for _ in range(pool_size)]
+        if NativeRhaiEngine is not None:
+            # Rust 엔진은 스레드 안전하므로 AST 캐시를 공유하도록 인스턴스 하나만 사용
+            shared = RhaiEngine()
+            self._engines = [shared] * pool_size
+        else:
+            self._engines = [_MockRhaiEngine() for _ in range(pool_size)]
         self._current = 0
 
-    def get_engine(self) -> RhaiEngine:
+    def get_engine(self) -> "RhaiEngine":
         """풀에서 엔진 가져오기 (라운드 로빈)"""
         engine = self._engines[self._current]
         self._current = (self._current + 1) % self.pool_size
         return engine
 
+    def invalidate(self, ruleset_id: str) -> None:
+        """룰셋 AST 캐시 무효화 (Rust 엔진 사용 시에만 의미 있음)"""
+        for engine in {id(e): e for e in self._engines}.values():
+            if hasattr(engine, "invalidate"):
+                engine.invalidate(ruleset_id)
+
 
 # 전역 엔진 풀 인스턴스
 _engine_pool = RhaiEnginePool()
 
 
-def execute_rhai(script: str, context: Dict[str, Any]) -> Any:
+def execute_rhai(
+    script: str,
+    context: Dict[str, Any],
+    ruleset_id: Optional[str] = None,
+    version: Optional[str] = None,
+) -> Any:
     """
     Rhai 스크립트 실행 헬퍼 함수
 
     Args:
         script: Rhai 스크립트 코드
         context: 스크립트 컨텍스트
+        ruleset_id: 룰셋 ID (version과 함께 주면 컴파일 결과 캐시)
+        version: 룰셋 버전
 
     Returns:
         실행 결과
     """
     engine = _engine_pool.get_engine()
+    if NativeRhaiEngine is not None and ruleset_id and version:
+        return engine.execute(script, context, ruleset_id=ruleset_id, version=version)
     return engine.execute(script, context)
 
 
+def invalidate_rhai_cache(ruleset_id: str) -> None:
+    """
+    룰셋 스크립트 변경 시 컴파일 캐시 무효화
+
+    Args:
+        ruleset_id: 룰셋 ID
+    """
+    _engine_pool.invalidate(ruleset_id)
+
+
+def diff_rhai(
+    old_script: str,
+    new_script: str,
+    samples: Optional[List[Dict[str, Any]]] = None,
+) -> Dict[str, Any]:
+    """
+    룰셋 두 버전의 의미 비교 (임계값/조건/변수 이름 변경 + 샘플 입력 status 변화)
+
+    Args:
+        old_script: 이전 버전 스크립트
+        new_script: 새 버전 스크립트
+        samples: status 변화를 확인할 입력 데이터 목록
+
+    Returns:
+        비교 결과 (thresholds, conditions, renamed_variables, samples, status_changes)
+    """
+    if NativeRhaiEngine is None:
+        raise RuntimeError("Ruleset diff requires the native triflow_rules engine")
+    engine = _engine_pool.get_engine()
+    return engine.diff(old_script, new_script, samples or [])
+
+
 def validate_rhai(script: str) -> bool:
     """
     Rhai 스크립트 검증 헬퍼 함수
@@ -261,3 +324,19 @@ def validate_rhai(script: str) -> bool:
     """
     engine = _engine_pool.get_engine()
     return engine.validate(script)
+
+
+def diagnose_rhai(script: str) -> Optional[Dict[str, Any]]:
+    """
+    Rhai 스크립트 정적 진단 (실행하지 않음)
+
+    Args:
+        script: 검증할 스크립트
+
+    Returns:
+        진단 결과 (valid, diagnostics, input_fields), 네이티브 엔진이 없으면 None
+    """
+    if NativeRhaiEngine is None:
+        return None
+    engine = _engine_pool.get_engine()
+    return engine.diagnose(script)
@@ -34,6 +34,7 @@ CREATE TABLE IF NOT EXISTS rulesets (
     name VARCHAR(255) NOT NULL,
     description TEXT,
     rhai_code TEXT NOT NULL,
+    test_cases JSONB DEFAULT '[]',
     version VARCHAR(50) NOT NULL,
     is_active BOOLEAN DEFAULT false,
     created_by UUID REFERENCES users(user_id),
@@ -0,0 +1,235 @@
+//! TriFlow AI 룰 엔진 Python 바인딩
+//!
+//! `backend/app/tools/rhai.py`의 `RhaiEngine` 인터페이스를 그대로 제공한다.
+//!
+//! ```python
+//! from triflow_rules import RhaiEngine
+//!
+//! engine = RhaiEngine()
+//! result = engine.execute(script, {"input": {"temperature": 75.0}})
+//! ```
+
+use std::sync::Arc;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::Value;
+use triflow_rules::{RuleEngine, RuleError};
+
+/// Rhai 스크립팅 엔진 (Rust 구현)
+#[pyclass(name = "RhaiEngine", module = "triflow_rules", frozen)]
+struct PyRhaiEngine {
+    engine: RuleEngine,
+}
+
+#[pymethods]
+impl PyRhaiEngine {
+    #[new]
+    fn new() -> Self {
+        Self {
+            engine: RuleEngine::new(),
+        }
+    }
+
+    /// Rhai 스크립트 실행
+    ///
+    /// `context["input"]`이 스크립트의 `input` 변수로 전달된다.
+    /// `ruleset_id`와 `version`이 주어지면 컴파일된 AST를 캐시해 재사용한다.
+    /// 컴파일/실행 오류 시 `ValueError`를 발생시킨다.
+    #[pyo3(signature = (script, context, ruleset_id=None, version=None))]
+    fn execute(
+        &self,
+        py: Python<'_>,
+        script: &str,
+        context: &Bound<'_, PyDict>,
+        ruleset_id: Option<&str>,
+        version: Option<&str>,
+    ) -> PyResult<PyObject> {
+        let input = context_input(py, context)?;
+
+        let output = py
+            .allow_threads(|| match (ruleset_id, version) {
+                (Some(id), Some(version)) => {
+                    let ast = self.engine.compile_cached(id, version, script)?;
+                    self.engine.execute_ast(&ast, &input)
+                }
+                _ => self.engine.execute(script, &input),
+            })
+            .map_err(execution_error)?;
+
+        let value =
+            serde_json::to_value(output).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        json_to_py(py, &value)
+    }
+
+    /// 여러 입력 행에 대해 같은 룰셋을 병렬 평가
+    ///
+    /// `rows`는 dict 리스트 또는 `pyarrow.RecordBatch`(arrow 기능 활성화 시).
+    /// 행별 오류는 예외 대신 결과의 `rows[i]["error"]`로 반환된다.
+    #[pyo3(signature = (script, rows, ruleset_id=None, version=None))]
+    fn execute_batch(
+        &self,
+        py: Python<'_>,
+        script: &str,
+        rows: &Bound<'_, PyAny>,
+        ruleset_id: Option<&str>,
+        version: Option<&str>,
+    ) -> PyResult<PyObject> {
+        let rows = batch_rows(py, rows)?;
+
+        let result = py
+            .allow_threads(|| {
+                let ast = match (ruleset_id, version) {
+                    (Some(id), Some(version)) => self.engine.compile_cached(id, version, script)?,
+                    _ => Arc::new(self.engine.compile(script)?),
+                };
+                Ok(self.engine.execute_batch(&ast, &rows))
+            })
+            .map_err(execution_error)?;
+
+        let value =
+            serde_json::to_value(result).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        json_to_py(py, &value)
+    }
+
+    /// 룰셋 AST 캐시 무효화 (제거된 항목 수 반환)
+    fn invalidate(&self, ruleset_id: &str) -> usize {
+        self.engine.invalidate(ruleset_id)
+    }
+
+    /// AST 캐시 통계 (`{"hits", "misses", "entries", "capacity"}`)
+    fn cache_stats(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let stats = self.engine.cache_stats();
+        let dict = PyDict::new(py);
+        dict.set_item("hits", stats.hits)?;
+        dict.set_item("misses", stats.misses)?;
+        dict.set_item("entries", stats.entries)?;
+        dict.set_item("capacity", stats.capacity)?;
+        Ok(dict.into_any().unbind())
+    }
+
+    /// Rhai 스크립트 실행 + 실행 경로 추적
+    ///
+    /// `(result, trace)` 튜플을 반환한다. `trace`는 분기/바인딩/체크 이벤트 목록.
+    fn execute_with_trace(
+        &self,
+        py: Python<'_>,
+        script: &str,
+        context: &Bound<'_, PyDict>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let input = context_input(py, context)?;
+
+        let (output, trace) = py
+            .allow_threads(|| self.engine.execute_traced(script, &input))
+            .map_err(execution_error)?;
+
+        let output =
+            serde_json::to_value(output).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let trace =
+            serde_json::to_value(trace).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok((json_to_py(py, &output)?, json_to_py(py, &trace)?))
+    }
+
+    /// Rhai 스크립트 문법 검증 (컴파일 성공 여부)
+    fn validate(&self, script: &str) -> bool {
+        self.engine.compile(script).is_ok()
+    }
+
+    /// Rhai 스크립트 정적 진단
+    ///
+    /// `{"valid", "diagnostics": [{kind, severity, line, column, message}], "input_fields"}`
+    /// 형태의 dict를 반환한다.
+    #[pyo3(signature = (script, input_fields=None))]
+    fn diagnose(
+        &self,
+        py: Python<'_>,
+        script: &str,
+        input_fields: Option<Vec<String>>,
+    ) -> PyResult<PyObject> {
+        let report = self.engine.validate(script, input_fields.as_deref());
+        let value =
+            serde_json::to_value(report).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        json_to_py(py, &value)
+    }
+
+    /// 룰셋 두 버전의 의미 비교
+    ///
+    /// `{"thresholds", "conditions", "renamed_variables", "samples", "status_changes"}`
+    /// 형태의 dict를 반환한다. `samples`는 `status` 변화를 확인할 입력 dict 목록.
+    #[pyo3(signature = (old_script, new_script, samples=None))]
+    fn diff(
+        &self,
+        py: Python<'_>,
+        old_script: &str,
+        new_script: &str,
+        samples: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let samples = match samples {
+            Some(samples) => batch_rows(py, samples)?,
+            None => Vec::new(),
+        };
+
+        let diff = py
+            .allow_threads(|| self.engine.diff_scripts(old_script, new_script, &samples))
+            .map_err(execution_error)?;
+
+        let value = serde_json::to_value(diff).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        json_to_py(py, &value)
+    }
+}
+
+/// `context["input"]` 추출 (없으면 빈 객체)
+fn context_input(py: Python<'_>, context: &Bound<'_, PyDict>) -> PyResult<Value> {
+    match context.get_item("input")? {
+        Some(input) => py_to_json(py, &input),
+        None => Ok(Value::Object(Default::default())),
+    }
+}
+
+/// 배치 입력 → 행 목록 (dict 리스트 또는 pyarrow.RecordBatch)
+fn batch_rows(py: Python<'_>, rows: &Bound<'_, PyAny>) -> PyResult<Vec<Value>> {
+    #[cfg(feature = "arrow")]
+    {
+        use arrow::pyarrow::FromPyArrow;
+        if let Ok(batch) = arrow::record_batch::RecordBatch::from_pyarrow_bound(rows) {
+            return triflow_rules::record_batch_to_rows(&batch)
+                .map_err(|e| PyValueError::new_err(e.to_string()));
+        }
+    }
+
+    match py_to_json(py, rows)? {
+        Value::Array(rows) => Ok(rows),
+        _ => Err(PyValueError::new_err(
+            "rows must be a list of dicts or a pyarrow.RecordBatch",
+        )),
+    }
+}
+
+fn execution_error(err: RuleError) -> PyErr {
+    PyValueError::new_err(format!("Rhai script execution failed: {err}"))
+}
+
+/// Python 객체 → JSON (json.dumps 경유, datetime 등은 문자열로 변환)
+fn py_to_json(py: Python<'_>, obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let json = py.import("json")?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("default", py.import("builtins")?.getattr("str")?)?;
+    let text: String = json
+        .call_method("dumps", (obj,), Some(&kwargs))?
+        .extract()?;
+    serde_json::from_str(&text).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// JSON → Python 객체 (json.loads 경유)
+fn json_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    let text = value.to_string();
+    Ok(py.import("json")?.call_method1("loads", (text,))?.unbind())
+}
+
+#[pymodule]
+#[pyo3(name = "triflow_rules")]
+fn triflow_rules_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyRhaiEngine>()?;
+    Ok(())
+}
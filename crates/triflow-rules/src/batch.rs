@@ -0,0 +1,217 @@
+use std::time::Instant;
+
+use rayon::prelude::*;
+use rhai::AST;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::engine::RuleEngine;
+use crate::error::RuleError;
+use crate::output::RuleOutput;
+
+/// 배치 평가의 행별 결과 (`output`과 `error` 중 하나만 존재)
+#[derive(Debug, Serialize)]
+pub struct BatchRow {
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<RuleOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RuleError>,
+}
+
+/// 배치 평가 결과
+#[derive(Debug, Serialize)]
+pub struct BatchResult {
+    pub total: usize,
+    pub failed: usize,
+    pub execution_time_ms: u64,
+    /// 입력 순서와 동일
+    pub rows: Vec<BatchRow>,
+}
+
+impl RuleEngine {
+    /// 하나의 컴파일된 룰셋을 여러 입력 행에 대해 병렬 평가
+    ///
+    /// 한 행의 오류는 해당 행의 `error`로만 기록되고 나머지 행은 계속 평가된다.
+    pub fn execute_batch(&self, ast: &AST, rows: &[Value]) -> BatchResult {
+        let started = Instant::now();
+
+        let rows: Vec<BatchRow> = rows
+            .par_iter()
+            .enumerate()
+            .map(|(index, input)| match self.execute_ast(ast, input) {
+                Ok(output) => BatchRow {
+                    index,
+                    output: Some(output),
+                    error: None,
+                },
+                Err(err) => BatchRow {
+                    index,
+                    output: None,
+                    error: Some(err),
+                },
+            })
+            .collect();
+
+        BatchResult {
+            total: rows.len(),
+            failed: rows.iter().filter(|r| r.error.is_some()).count(),
+            execution_time_ms: started.elapsed().as_millis() as u64,
+            rows,
+        }
+    }
+
+    /// Arrow RecordBatch의 각 행을 `input` 맵으로 변환해 병렬 평가
+    #[cfg(feature = "arrow")]
+    pub fn execute_record_batch(
+        &self,
+        ast: &AST,
+        batch: &arrow_array::RecordBatch,
+    ) -> Result<BatchResult, RuleError> {
+        Ok(self.execute_batch(ast, &record_batch_to_rows(batch)?))
+    }
+}
+
+/// RecordBatch → 행 단위 JSON 객체 목록
+///
+/// null 값도 `null` 필드로 남겨 모든 행이 같은 키를 갖도록 한다
+/// (arrow_json 기본값은 null 필드를 생략).
+#[cfg(feature = "arrow")]
+pub fn record_batch_to_rows(batch: &arrow_array::RecordBatch) -> Result<Vec<Value>, RuleError> {
+    let mut writer = arrow_json::WriterBuilder::new()
+        .with_explicit_nulls(true)
+        .build::<_, arrow_json::writer::JsonArray>(Vec::new());
+    writer
+        .write(batch)
+        .and_then(|_| writer.finish())
+        .map_err(|e| RuleError::InvalidInput(e.to_string()))?;
+
+    let buf = writer.into_inner();
+    if buf.is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_slice(&buf).map_err(|e| RuleError::InvalidInput(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SCRIPT: &str = r#"
+        if input.value < 0 { throw "negative value"; }
+        if input.value > 10 { #{status: "WARNING"} } else { #{status: "NORMAL"} }
+    "#;
+
+    fn statuses(result: &BatchResult) -> Vec<Option<&str>> {
+        result
+            .rows
+            .iter()
+            .map(|row| row.output.as_ref().map(|o| o.status.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn bad_row_fails_only_itself() {
+        let engine = RuleEngine::new();
+        let ast = engine.compile(SCRIPT).unwrap();
+        let rows = vec![
+            json!({"value": 1}),
+            json!({"value": -1}),
+            json!({"value": 20}),
+        ];
+
+        let result = engine.execute_batch(&ast, &rows);
+        assert_eq!((result.total, result.failed), (3, 1));
+        assert_eq!(
+            statuses(&result),
+            vec![Some("NORMAL"), None, Some("WARNING")]
+        );
+        assert!(result.rows[1].error.is_some());
+    }
+
+    #[test]
+    fn output_order_matches_input_order() {
+        let engine = RuleEngine::new();
+        let ast = engine.compile(SCRIPT).unwrap();
+        let rows: Vec<Value> = (0..200).map(|i| json!({"value": i % 20})).collect();
+
+        let result = engine.execute_batch(&ast, &rows);
+        assert_eq!(result.failed, 0);
+        for (i, row) in result.rows.iter().enumerate() {
+            assert_eq!(row.index, i);
+            let expected = if i % 20 > 10 { "WARNING" } else { "NORMAL" };
+            assert_eq!(row.output.as_ref().unwrap().status, expected);
+        }
+    }
+
+    #[cfg(feature = "arrow")]
+    mod arrow {
+        use std::sync::Arc;
+
+        use arrow_array::{BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+
+        use super::*;
+
+        fn sample_batch() -> RecordBatch {
+            RecordBatch::try_from_iter(vec![
+                (
+                    "line",
+                    Arc::new(StringArray::from(vec![Some("L1"), None])) as _,
+                ),
+                (
+                    "count",
+                    Arc::new(Int64Array::from(vec![Some(3), None])) as _,
+                ),
+                (
+                    "value",
+                    Arc::new(Float64Array::from(vec![Some(12.5), Some(4.0)])) as _,
+                ),
+                (
+                    "active",
+                    Arc::new(BooleanArray::from(vec![Some(true), None])) as _,
+                ),
+            ])
+            .unwrap()
+        }
+
+        #[test]
+        fn converts_typed_columns_and_keeps_nulls() {
+            let rows = record_batch_to_rows(&sample_batch()).unwrap();
+            assert_eq!(
+                rows,
+                vec![
+                    json!({"line": "L1", "count": 3, "value": 12.5, "active": true}),
+                    json!({"line": null, "count": null, "value": 4.0, "active": null}),
+                ]
+            );
+        }
+
+        #[test]
+        fn null_fields_are_unit_in_scripts() {
+            let engine = RuleEngine::new();
+            let ast = engine
+                .compile(
+                    r#"
+                    let status = if input.value > 10.0 { "WARNING" } else { "NORMAL" };
+                    #{status: status, checks: [type_of(input.line), "count" in input]}
+                "#,
+                )
+                .unwrap();
+
+            let result = engine.execute_record_batch(&ast, &sample_batch()).unwrap();
+            assert_eq!(result.failed, 0);
+            assert_eq!(statuses(&result), vec![Some("WARNING"), Some("NORMAL")]);
+            assert_eq!(
+                result.rows[1].output.as_ref().unwrap().checks,
+                vec![json!("()"), json!(true)]
+            );
+        }
+
+        #[test]
+        fn empty_batch_has_no_rows() {
+            let batch = sample_batch().slice(0, 0);
+            assert!(record_batch_to_rows(&batch).unwrap().is_empty());
+        }
+    }
+}
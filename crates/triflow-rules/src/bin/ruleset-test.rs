@@ -0,0 +1,118 @@
+//! 룰셋 테스트 CLI
+//!
+//! 사용법: `ruleset-test <script.rhai> <tests.json>`
+//!
+//! `tests.json`은 `RulesetTestCase` 배열이다. 하나라도 실패하면 종료 코드 1.
+
+use std::process::ExitCode;
+use std::{env, fs};
+
+use triflow_rules::{RuleEngine, RulesetTestCase};
+
+/// 입력 오류 (인자, 파일 읽기/파싱 실패)
+const EXIT_USAGE: u8 = 2;
+/// 테스트 실패
+const EXIT_FAILED: u8 = 1;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    ExitCode::from(run(&args))
+}
+
+fn run(args: &[String]) -> u8 {
+    if args.len() != 3 {
+        eprintln!("usage: {} <script.rhai> <tests.json>", args[0]);
+        return EXIT_USAGE;
+    }
+
+    let script = match fs::read_to_string(&args[1]) {
+        Ok(script) => script,
+        Err(e) => {
+            eprintln!("failed to read {}: {e}", args[1]);
+            return EXIT_USAGE;
+        }
+    };
+    let cases: Vec<RulesetTestCase> = match fs::read_to_string(&args[2])
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+    {
+        Ok(cases) => cases,
+        Err(e) => {
+            eprintln!("failed to load {}: {e}", args[2]);
+            return EXIT_USAGE;
+        }
+    };
+
+    let report = RuleEngine::new().run_tests(&script, &cases);
+
+    for case in &report.cases {
+        let mark = if case.passed { "PASS" } else { "FAIL" };
+        println!("[{mark}] {}", case.name);
+        if let Some(error) = &case.error {
+            println!("       error: {error}");
+        }
+        for m in &case.mismatches {
+            println!(
+                "       {}: expected {}, got {}",
+                m.path, m.expected, m.actual
+            );
+        }
+    }
+    println!(
+        "\n{} passed, {} failed ({} total)",
+        report.passed, report.failed, report.total
+    );
+
+    if report.all_passed() {
+        0
+    } else {
+        EXIT_FAILED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = r#"
+        if input.temperature > 80.0 { #{status: "WARNING"} } else { #{status: "NORMAL"} }
+    "#;
+
+    /// 임시 디렉터리에 파일을 만들어 CLI 실행 (`script`가 None이면 스크립트 파일 없음)
+    fn run_files(name: &str, script: Option<&str>, tests: &str) -> u8 {
+        let dir = env::temp_dir().join(format!("ruleset-test-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let script_path = dir.join("script.rhai");
+        let tests_path = dir.join("tests.json");
+        if let Some(script) = script {
+            fs::write(&script_path, script).unwrap();
+        }
+        fs::write(&tests_path, tests).unwrap();
+
+        let code = run(&[
+            "ruleset-test".to_string(),
+            script_path.display().to_string(),
+            tests_path.display().to_string(),
+        ]);
+        fs::remove_dir_all(&dir).unwrap();
+        code
+    }
+
+    #[test]
+    fn exit_code_reflects_test_results() {
+        let passing =
+            r#"[{"name": "hot", "input": {"temperature": 90.0}, "expected_status": "WARNING"}]"#;
+        assert_eq!(run_files("pass", Some(SCRIPT), passing), 0);
+
+        let failing =
+            r#"[{"name": "hot", "input": {"temperature": 90.0}, "expected_status": "NORMAL"}]"#;
+        assert_eq!(run_files("fail", Some(SCRIPT), failing), EXIT_FAILED);
+    }
+
+    #[test]
+    fn exit_code_reports_usage_errors() {
+        assert_eq!(run(&["ruleset-test".to_string()]), EXIT_USAGE);
+        assert_eq!(run_files("invalid", Some(SCRIPT), "{not json"), EXIT_USAGE);
+        assert_eq!(run_files("missing", None, "[]"), EXIT_USAGE);
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use lru::LruCache;
+use rhai::AST;
+use serde::Serialize;
+
+/// 기본 캐시 용량 (룰셋 버전 수)
+pub const DEFAULT_CACHE_CAPACITY: usize = 256;
+
+/// 캐시 키: (ruleset_id, version, 스크립트 해시)
+///
+/// 같은 버전 문자열로 스크립트만 바뀐 경우에도 해시가 달라 재컴파일된다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    ruleset_id: String,
+    version: String,
+    script_hash: u64,
+}
+
+/// 캐시 통계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    pub capacity: usize,
+}
+
+/// 컴파일된 AST LRU 캐시
+///
+/// 센서 이벤트마다 같은 룰셋을 다시 파싱하지 않도록 한다.
+pub struct AstCache {
+    entries: Mutex<LruCache<CacheKey, Arc<AST>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl AstCache {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// 캐시된 AST 반환, 없으면 `compile`로 생성 후 저장
+    ///
+    /// 컴파일 중에는 잠금을 잡지 않으므로 동시에 같은 키를 요청하면
+    /// 중복 컴파일될 수 있다 (결과는 동일).
+    pub fn get_or_insert<E>(
+        &self,
+        ruleset_id: &str,
+        version: &str,
+        script: &str,
+        compile: impl FnOnce() -> Result<AST, E>,
+    ) -> Result<Arc<AST>, E> {
+        let key = CacheKey {
+            ruleset_id: ruleset_id.to_string(),
+            version: version.to_string(),
+            script_hash: hash_script(script),
+        };
+
+        if let Some(ast) = self.lock().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(Arc::clone(ast));
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let ast = Arc::new(compile()?);
+        self.lock().put(key, Arc::clone(&ast));
+        Ok(ast)
+    }
+
+    /// 룰셋의 모든 버전 제거 (제거된 항목 수 반환)
+    pub fn invalidate(&self, ruleset_id: &str) -> usize {
+        let mut entries = self.lock();
+        let stale: Vec<CacheKey> = entries
+            .iter()
+            .filter(|(key, _)| key.ruleset_id == ruleset_id)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &stale {
+            entries.pop(key);
+        }
+        stale.len()
+    }
+
+    /// 전체 캐시 비우기 (통계는 유지)
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.lock();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: entries.len(),
+            capacity: entries.cap().get(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LruCache<CacheKey, Arc<AST>>> {
+        // 캐시는 재생성 가능한 데이터이므로 poison 상태여도 그대로 사용
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for AstCache {
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(DEFAULT_CACHE_CAPACITY).expect("capacity is non-zero"))
+    }
+}
+
+fn hash_script(script: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    script.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(capacity: usize) -> AstCache {
+        AstCache::new(NonZeroUsize::new(capacity).unwrap())
+    }
+
+    fn get(cache: &AstCache, ruleset_id: &str, version: &str, script: &str) -> bool {
+        let mut compiled = false;
+        cache
+            .get_or_insert(ruleset_id, version, script, || {
+                compiled = true;
+                rhai::Engine::new().compile(script)
+            })
+            .unwrap();
+        compiled
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let cache = cache(4);
+        assert!(get(&cache, "r1", "1.0", "1 + 1"));
+        assert!(!get(&cache, "r1", "1.0", "1 + 1"));
+        assert!(!get(&cache, "r1", "1.0", "1 + 1"));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 1,
+                entries: 1,
+                capacity: 4
+            }
+        );
+    }
+
+    #[test]
+    fn new_version_or_script_misses() {
+        let cache = cache(4);
+        get(&cache, "r1", "1.0", "1 + 1");
+        assert!(get(&cache, "r1", "1.1", "1 + 1"));
+        assert!(get(&cache, "r1", "1.0", "2 + 2"));
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (0, 3, 3));
+    }
+
+    #[test]
+    fn invalidate_removes_every_version() {
+        let cache = cache(4);
+        get(&cache, "r1", "1.0", "1");
+        get(&cache, "r1", "1.1", "2");
+        get(&cache, "r2", "1.0", "3");
+        assert_eq!(cache.invalidate("r1"), 2);
+        assert_eq!(cache.stats().entries, 1);
+        assert!(get(&cache, "r1", "1.0", "1"));
+        assert!(!get(&cache, "r2", "1.0", "3"));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = cache(2);
+        get(&cache, "a", "1", "1");
+        get(&cache, "b", "1", "2");
+        // a를 다시 사용해 b가 가장 오래된 항목이 된다
+        get(&cache, "a", "1", "1");
+        get(&cache, "c", "1", "3");
+        assert_eq!(cache.stats().entries, 2);
+        assert!(!get(&cache, "a", "1", "1"));
+        assert!(get(&cache, "b", "1", "2"));
+    }
+
+    #[test]
+    fn compile_error_is_not_cached() {
+        let cache = cache(2);
+        let result = cache.get_or_insert("r1", "1.0", "let x = ;", || {
+            rhai::Engine::new().compile("let x = ;")
+        });
+        assert!(result.is_err());
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
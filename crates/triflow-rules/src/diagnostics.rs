@@ -0,0 +1,318 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use rhai::{ASTNode, Expr, Position, Stmt, AST};
+use serde::Serialize;
+
+use crate::engine::RuleEngine;
+use crate::error::RuleError;
+
+/// 진단 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// 진단 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// 문법 오류
+    Syntax,
+    /// 실행 제한 초과 (표현식 중첩 등)
+    Limit,
+    /// 선언되지 않은 변수 사용
+    UndefinedVariable,
+    /// 입력 스키마에 없는 `input` 필드 참조
+    UnknownInputField,
+    /// 허용되지 않는 모듈 import
+    Import,
+}
+
+/// 스크립트 진단 항목
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub severity: Severity,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// 스크립트 정적 검증 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationReport {
+    /// 오류 수준 진단이 없으면 true
+    pub valid: bool,
+    pub diagnostics: Vec<Diagnostic>,
+    /// 스크립트가 참조하는 `input` 필드 (정렬됨)
+    pub input_fields: Vec<String>,
+}
+
+impl RuleEngine {
+    /// 스크립트를 실행하지 않고 정적으로 검증
+    ///
+    /// `known_fields`가 주어지면 그 목록에 없는 `input` 필드 참조를 경고로 보고한다.
+    pub fn validate(&self, script: &str, known_fields: Option<&[String]>) -> ValidationReport {
+        let ast = match self.compile(script) {
+            Ok(ast) => ast,
+            Err(err) => return ValidationReport::from_error(err),
+        };
+
+        let usage = collect_usage(&ast);
+        let mut diagnostics = Vec::new();
+
+        for (pos, name) in &usage.imports {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Import,
+                Severity::Error,
+                *pos,
+                format!("module import is not allowed: '{name}'"),
+            ));
+        }
+
+        for (name, pos) in &usage.variables {
+            if name != "input" && !usage.declared.contains(name) {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::UndefinedVariable,
+                    Severity::Error,
+                    *pos,
+                    format!("variable '{name}' is not defined"),
+                ));
+            }
+        }
+
+        if let Some(known) = known_fields {
+            let known: HashSet<&str> = known.iter().map(String::as_str).collect();
+            for (field, pos) in &usage.input_fields {
+                if !known.contains(field.as_str()) {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::UnknownInputField,
+                        Severity::Warning,
+                        *pos,
+                        format!("input field '{field}' is not defined in the input schema"),
+                    ));
+                }
+            }
+        }
+
+        diagnostics.sort_by_key(|d| (d.line, d.column));
+
+        ValidationReport {
+            valid: diagnostics.iter().all(|d| d.severity != Severity::Error),
+            diagnostics,
+            input_fields: usage.input_fields.into_keys().collect(),
+        }
+    }
+}
+
+impl ValidationReport {
+    fn from_error(err: RuleError) -> Self {
+        let diagnostic = match err {
+            RuleError::Compile {
+                line,
+                column,
+                message,
+            } => Diagnostic {
+                kind: DiagnosticKind::Syntax,
+                severity: Severity::Error,
+                line,
+                column,
+                message,
+            },
+            other => Diagnostic {
+                kind: DiagnosticKind::Limit,
+                severity: Severity::Error,
+                line: 0,
+                column: 0,
+                message: other.to_string(),
+            },
+        };
+
+        Self {
+            valid: false,
+            diagnostics: vec![diagnostic],
+            input_fields: Vec::new(),
+        }
+    }
+}
+
+impl Diagnostic {
+    fn new(kind: DiagnosticKind, severity: Severity, pos: Position, message: String) -> Self {
+        Self {
+            kind,
+            severity,
+            line: pos.line().unwrap_or(0),
+            column: pos.position().unwrap_or(0),
+            message,
+        }
+    }
+}
+
+/// AST에서 수집한 변수/필드 사용 정보 (이름별 첫 위치)
+#[derive(Default)]
+struct Usage {
+    declared: BTreeSet<String>,
+    variables: BTreeMap<String, Position>,
+    input_fields: BTreeMap<String, Position>,
+    imports: Vec<(Position, String)>,
+}
+
+/// 변수 선언/참조와 `input.xxx` 필드 참조 수집
+///
+/// 선언 위치와 사용 순서는 구분하지 않는다 (스크립트 어딘가에서
+/// 선언된 이름이면 정의된 것으로 본다).
+fn collect_usage(ast: &AST) -> Usage {
+    let mut usage = Usage::default();
+
+    for f in ast.iter_functions() {
+        usage
+            .declared
+            .extend(f.params.iter().map(|p| p.to_string()));
+    }
+
+    ast.walk(&mut |path: &[ASTNode]| {
+        match path.last() {
+            Some(ASTNode::Stmt(Stmt::Var(x, ..))) => {
+                usage.declared.insert(x.0.name.to_string());
+            }
+            Some(ASTNode::Stmt(Stmt::For(x, ..))) => {
+                usage.declared.insert(x.0.name.to_string());
+                if let Some(counter) = &x.1 {
+                    usage.declared.insert(counter.name.to_string());
+                }
+            }
+            Some(ASTNode::Stmt(Stmt::TryCatch(x, ..))) => {
+                if let Expr::Variable(v, ..) = &x.expr {
+                    usage.declared.insert(v.1.to_string());
+                }
+            }
+            Some(ASTNode::Stmt(Stmt::Import(x, pos))) => {
+                usage.declared.insert(x.1.name.to_string());
+                let name = match &x.0 {
+                    Expr::StringConstant(s, ..) => s.to_string(),
+                    _ => "<expr>".to_string(),
+                };
+                usage.imports.push((*pos, name));
+            }
+            Some(ASTNode::Expr(Expr::Variable(v, _, pos))) => {
+                usage.variables.entry(v.1.to_string()).or_insert(*pos);
+            }
+            Some(ASTNode::Expr(Expr::Dot(x, ..) | Expr::Index(x, ..))) => {
+                if matches!(&x.lhs, Expr::Variable(v, ..) if v.1 == "input") {
+                    if let Some((field, pos)) = first_segment(&x.rhs) {
+                        usage.input_fields.entry(field).or_insert(pos);
+                    }
+                }
+            }
+            _ => (),
+        }
+        true
+    });
+
+    usage
+}
+
+/// `a.b.c` / `a["b"]` 체인에서 첫 번째 속성 이름
+fn first_segment(expr: &Expr) -> Option<(String, Position)> {
+    match expr {
+        Expr::Property(x, pos) => Some((x.2.to_string(), *pos)),
+        Expr::StringConstant(s, pos) => Some((s.to_string(), *pos)),
+        Expr::Dot(x, ..) | Expr::Index(x, ..) => first_segment(&x.lhs),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn reports_syntax_error_position() {
+        let engine = RuleEngine::new();
+        let report = engine.validate("let x = 1;\nlet y = (x + ;\n#{status: \"NORMAL\"}", None);
+        assert!(!report.valid);
+        assert_eq!(
+            report.diagnostics,
+            vec![Diagnostic {
+                kind: DiagnosticKind::Syntax,
+                severity: Severity::Error,
+                line: 2,
+                column: 14,
+                message: "Unexpected ';'".into(),
+            }]
+        );
+        assert!(report.input_fields.is_empty());
+    }
+
+    #[test]
+    fn reports_undefined_variable() {
+        let engine = RuleEngine::new();
+        let script = "let t = input.temperature;\n\
+                      if t > limit { #{status: \"WARNING\"} } else { #{status: \"NORMAL\"} }";
+        let report = engine.validate(script, None);
+        assert!(!report.valid);
+        assert_eq!(
+            report.diagnostics,
+            vec![Diagnostic {
+                kind: DiagnosticKind::UndefinedVariable,
+                severity: Severity::Error,
+                line: 2,
+                column: 8,
+                message: "variable 'limit' is not defined".into(),
+            }]
+        );
+        assert_eq!(report.input_fields, fields(&["temperature"]));
+    }
+
+    #[test]
+    fn unknown_input_field_is_a_warning_only_with_known_fields() {
+        let engine = RuleEngine::new();
+        let script = "let t = input.temperature;\n\
+                      if t > 80.0 { #{status: \"WARNING\"} } else { #{status: \"NORMAL\"} }";
+
+        let report = engine.validate(script, None);
+        assert!(report.valid && report.diagnostics.is_empty());
+
+        let report = engine.validate(script, Some(&fields(&["temperature"])));
+        assert!(report.valid && report.diagnostics.is_empty());
+
+        let report = engine.validate(script, Some(&fields(&["humidity"])));
+        assert!(report.valid);
+        assert_eq!(
+            report.diagnostics,
+            vec![Diagnostic {
+                kind: DiagnosticKind::UnknownInputField,
+                severity: Severity::Warning,
+                line: 1,
+                column: 15,
+                message: "input field 'temperature' is not defined in the input schema".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn clean_script_has_no_diagnostics() {
+        let engine = RuleEngine::new();
+        let script = r#"
+            fn level(value, limit) { if value > limit { "WARNING" } else { "NORMAL" } }
+            let rate = input.defect_count / input["total_count"];
+            for check in [0.05, 0.1] { rate += 0.0 * check; }
+            #{status: level(rate, 0.05), checks: []}
+        "#;
+        let report = engine.validate(script, Some(&fields(&["defect_count", "total_count"])));
+        assert_eq!(
+            report,
+            ValidationReport {
+                valid: true,
+                diagnostics: vec![],
+                input_fields: fields(&["defect_count", "total_count"]),
+            }
+        );
+    }
+}
@@ -0,0 +1,588 @@
+//! 룰셋 버전 간 의미 비교
+//!
+//! 스크립트를 텍스트가 아닌 AST 수준에서 비교해 임계값 변경, 조건 추가/삭제,
+//! 변수 이름 변경을 보고하고, 샘플 입력으로 두 버전을 실행해 `status`가
+//! 달라지는 입력을 찾는다.
+
+use std::collections::{HashMap, HashSet};
+
+use rhai::{ASTNode, Expr, Stmt, AST};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::engine::RuleEngine;
+use crate::error::RuleError;
+
+/// 임계값 변경 (`input.temperature > 80.0` → `> 85.0`)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThresholdChange {
+    /// 비교 대상 (이전 버전 변수 이름 기준)
+    pub subject: String,
+    /// 비교 연산자 (대상이 왼쪽에 오도록 정규화)
+    pub operator: String,
+    pub old_value: f64,
+    pub new_value: f64,
+    pub old_line: usize,
+    pub new_line: usize,
+}
+
+/// 조건 변경 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+}
+
+/// `if` 조건 추가/삭제
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConditionChange {
+    pub kind: ChangeKind,
+    pub condition: String,
+    /// 추가는 새 버전, 삭제는 이전 버전의 줄 번호
+    pub line: usize,
+}
+
+/// 변수 이름 변경 (초기화 식이 같은 `let` 선언)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VariableRename {
+    pub old_name: String,
+    pub new_name: String,
+    pub line: usize,
+}
+
+/// AST 수준 비교 결과
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScriptDiff {
+    pub thresholds: Vec<ThresholdChange>,
+    pub conditions: Vec<ConditionChange>,
+    pub renamed_variables: Vec<VariableRename>,
+}
+
+impl ScriptDiff {
+    pub fn is_empty(&self) -> bool {
+        self.thresholds.is_empty()
+            && self.conditions.is_empty()
+            && self.renamed_variables.is_empty()
+    }
+}
+
+/// 샘플 입력 실행 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SampleOutcome {
+    Status(String),
+    Error(String),
+}
+
+/// 두 버전의 결과가 달라진 샘플 입력
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatusChange {
+    /// 샘플 목록에서의 위치
+    pub index: usize,
+    pub input: Value,
+    pub old: SampleOutcome,
+    pub new: SampleOutcome,
+}
+
+/// 룰셋 버전 비교 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RulesetDiff {
+    #[serde(flatten)]
+    pub script: ScriptDiff,
+    /// 실행한 샘플 수
+    pub samples: usize,
+    pub status_changes: Vec<StatusChange>,
+}
+
+impl RuleEngine {
+    /// 두 스크립트를 AST 수준에서 비교하고 샘플 입력으로 `status` 변화 확인
+    pub fn diff_scripts(
+        &self,
+        old_script: &str,
+        new_script: &str,
+        samples: &[Value],
+    ) -> Result<RulesetDiff, RuleError> {
+        let old_ast = self.compile(old_script)?;
+        let new_ast = self.compile(new_script)?;
+
+        let status_changes = samples
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| {
+                let old = self.outcome(&old_ast, input);
+                let new = self.outcome(&new_ast, input);
+                (old != new).then(|| StatusChange {
+                    index,
+                    input: input.clone(),
+                    old,
+                    new,
+                })
+            })
+            .collect();
+
+        Ok(RulesetDiff {
+            script: diff_ast(&old_ast, &new_ast),
+            samples: samples.len(),
+            status_changes,
+        })
+    }
+
+    fn outcome(&self, ast: &AST, input: &Value) -> SampleOutcome {
+        match self.execute_ast(ast, input) {
+            Ok(output) => SampleOutcome::Status(output.status),
+            Err(err) => SampleOutcome::Error(err.to_string()),
+        }
+    }
+}
+
+/// 컴파일된 두 스크립트의 구조 비교
+pub fn diff_ast(old: &AST, new: &AST) -> ScriptDiff {
+    let old_facts = Facts::collect(old);
+    let new_facts = Facts::collect(new);
+
+    // 새 이름 → 이전 이름. 이후 비교는 모두 이전 이름 기준으로 렌더링한다.
+    let (renames, renamed_variables) =
+        match_renames(&old_facts.declarations, &new_facts.declarations);
+    let none = HashMap::new();
+
+    let mut diff = ScriptDiff {
+        renamed_variables,
+        ..ScriptDiff::default()
+    };
+
+    // 임계값: (대상, 연산자)가 같은 비교를 등장 순서대로 짝지음
+    let mut old_thresholds: HashMap<(String, String), Vec<(f64, usize)>> = HashMap::new();
+    for t in &old_facts.thresholds {
+        let (subject, op, value) = t.describe(&none);
+        old_thresholds
+            .entry((subject, op))
+            .or_default()
+            .push((value, t.line));
+    }
+    for t in &new_facts.thresholds {
+        let (subject, op, new_value) = t.describe(&renames);
+        let key = (subject, op);
+        let Some(candidates) = old_thresholds.get_mut(&key) else {
+            continue;
+        };
+        if candidates.is_empty() {
+            continue;
+        }
+        let (old_value, old_line) = candidates.remove(0);
+        if old_value != new_value {
+            diff.thresholds.push(ThresholdChange {
+                subject: key.0,
+                operator: key.1,
+                old_value,
+                new_value,
+                old_line,
+                new_line: t.line,
+            });
+        }
+    }
+
+    // 조건: 숫자를 가린 모양으로 비교해 임계값 변경은 조건 변경으로 보지 않음
+    let mut unmatched: Vec<(String, &Condition)> = old_facts
+        .conditions
+        .iter()
+        .map(|c| (render(&c.expr, &none, true), c))
+        .collect();
+    for c in &new_facts.conditions {
+        let shape = render(&c.expr, &renames, true);
+        match unmatched.iter().position(|(s, _)| *s == shape) {
+            Some(i) => {
+                unmatched.remove(i);
+            }
+            None => diff.conditions.push(ConditionChange {
+                kind: ChangeKind::Added,
+                condition: render(&c.expr, &none, false),
+                line: c.line,
+            }),
+        }
+    }
+    diff.conditions
+        .extend(unmatched.into_iter().map(|(_, c)| ConditionChange {
+            kind: ChangeKind::Removed,
+            condition: render(&c.expr, &none, false),
+            line: c.line,
+        }));
+    diff.conditions
+        .sort_by_key(|c| (c.line, c.kind == ChangeKind::Added));
+
+    diff
+}
+
+/// `let` 선언
+struct Declaration {
+    name: String,
+    init: Expr,
+    line: usize,
+}
+
+/// `if` 조건식
+struct Condition {
+    expr: Expr,
+    line: usize,
+}
+
+/// `대상 연산자 숫자` 형태의 비교
+struct Threshold {
+    subject: Expr,
+    operator: &'static str,
+    value: f64,
+    line: usize,
+}
+
+impl Threshold {
+    fn describe(&self, renames: &HashMap<String, String>) -> (String, String, f64) {
+        (
+            render(&self.subject, renames, false),
+            self.operator.to_string(),
+            self.value,
+        )
+    }
+}
+
+/// 스크립트에서 비교에 쓰는 요소 수집
+#[derive(Default)]
+struct Facts {
+    declarations: Vec<Declaration>,
+    conditions: Vec<Condition>,
+    thresholds: Vec<Threshold>,
+}
+
+impl Facts {
+    fn collect(ast: &AST) -> Self {
+        let mut facts = Facts::default();
+
+        ast.walk(&mut |path: &[ASTNode]| {
+            match path.last() {
+                Some(ASTNode::Stmt(Stmt::Var(x, _, pos))) => facts.declarations.push(Declaration {
+                    name: x.0.name.to_string(),
+                    init: x.1.clone(),
+                    line: pos.line().unwrap_or(0),
+                }),
+                Some(ASTNode::Stmt(Stmt::If(x, pos))) => facts.conditions.push(Condition {
+                    expr: x.expr.clone(),
+                    line: pos.line().unwrap_or(0),
+                }),
+                Some(ASTNode::Expr(Expr::FnCall(f, pos)))
+                    if f.is_operator_call() && f.args.len() == 2 =>
+                {
+                    let line = pos.line().unwrap_or(0);
+                    if let Some(operator) = comparison(&f.name) {
+                        match (number(&f.args[0]), number(&f.args[1])) {
+                            (None, Some(value)) => facts.thresholds.push(Threshold {
+                                subject: f.args[0].clone(),
+                                operator,
+                                value,
+                                line,
+                            }),
+                            (Some(value), None) => facts.thresholds.push(Threshold {
+                                subject: f.args[1].clone(),
+                                operator: flip(operator),
+                                value,
+                                line,
+                            }),
+                            _ => (),
+                        }
+                    }
+                }
+                _ => (),
+            }
+            true
+        });
+
+        facts
+    }
+}
+
+/// 이전 버전에만 있는 이름과 새 버전에만 있는 이름 중 초기화 식이 같은 선언을 짝지음
+fn match_renames(
+    old: &[Declaration],
+    new: &[Declaration],
+) -> (HashMap<String, String>, Vec<VariableRename>) {
+    let old_names: HashSet<&str> = old.iter().map(|d| d.name.as_str()).collect();
+    let new_names: HashSet<&str> = new.iter().map(|d| d.name.as_str()).collect();
+    let none = HashMap::new();
+
+    let mut candidates: Vec<&Declaration> = old
+        .iter()
+        .filter(|d| !new_names.contains(d.name.as_str()))
+        .collect();
+    let mut renames = HashMap::new();
+    let mut changes = Vec::new();
+
+    for decl in new.iter().filter(|d| !old_names.contains(d.name.as_str())) {
+        if renames.contains_key(&decl.name) {
+            continue;
+        }
+        let shape = render(&decl.init, &renames, true);
+        // 블록 식은 내용을 렌더링하지 않으므로 이름 변경 판단에서 제외
+        if shape.contains(OPAQUE) {
+            continue;
+        }
+        if let Some(i) = candidates
+            .iter()
+            .position(|c| render(&c.init, &none, true) == shape)
+        {
+            let old_decl = candidates.remove(i);
+            renames.insert(decl.name.clone(), old_decl.name.clone());
+            changes.push(VariableRename {
+                old_name: old_decl.name.clone(),
+                new_name: decl.name.clone(),
+                line: decl.line,
+            });
+        }
+    }
+
+    (renames, changes)
+}
+
+/// 렌더링하지 않는 블록 식 자리 표시
+const OPAQUE: &str = "{ .. }";
+
+fn comparison(name: &str) -> Option<&'static str> {
+    Some(match name {
+        ">" => ">",
+        ">=" => ">=",
+        "<" => "<",
+        "<=" => "<=",
+        "==" => "==",
+        "!=" => "!=",
+        _ => return None,
+    })
+}
+
+/// `80 < x` → `x > 80`
+fn flip(operator: &'static str) -> &'static str {
+    match operator {
+        ">" => "<",
+        ">=" => "<=",
+        "<" => ">",
+        "<=" => ">=",
+        other => other,
+    }
+}
+
+fn number(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::IntegerConstant(n, ..) => Some(*n as f64),
+        Expr::FloatConstant(n, ..) => Some(**n),
+        _ => None,
+    }
+}
+
+/// 비교용 식 렌더링
+///
+/// 변수 이름은 `renames`로 치환하고, `mask_numbers`면 숫자 상수를 `#`으로 가린다.
+fn render(expr: &Expr, renames: &HashMap<String, String>, mask_numbers: bool) -> String {
+    let r = |e: &Expr| render(e, renames, mask_numbers);
+    let operand = |e: &Expr| match e {
+        Expr::FnCall(f, ..) if f.is_operator_call() && f.args.len() == 2 => format!("({})", r(e)),
+        Expr::And(..) | Expr::Or(..) | Expr::Coalesce(..) => format!("({})", r(e)),
+        _ => r(e),
+    };
+    let join = |items: &[Expr], sep: &str| items.iter().map(operand).collect::<Vec<_>>().join(sep);
+
+    match expr {
+        Expr::IntegerConstant(..) | Expr::FloatConstant(..) if mask_numbers => "#".to_string(),
+        Expr::IntegerConstant(n, ..) => n.to_string(),
+        Expr::FloatConstant(n, ..) => n.to_string(),
+        Expr::DynamicConstant(v, ..) => v.to_string(),
+        Expr::BoolConstant(b, ..) => b.to_string(),
+        Expr::CharConstant(c, ..) => format!("{c:?}"),
+        Expr::StringConstant(s, ..) => format!("{:?}", s.as_str()),
+        Expr::InterpolatedString(parts, ..) => {
+            let parts: Vec<String> = parts.iter().map(r).collect();
+            format!("`{}`", parts.join(""))
+        }
+        Expr::Array(items, ..) => {
+            format!("[{}]", items.iter().map(r).collect::<Vec<_>>().join(", "))
+        }
+        Expr::Map(x, ..) => {
+            let entries: Vec<String> =
+                x.0.iter()
+                    .map(|(k, v)| format!("{}: {}", k.name, r(v)))
+                    .collect();
+            format!("#{{{}}}", entries.join(", "))
+        }
+        Expr::Unit(..) => "()".to_string(),
+        Expr::Variable(v, ..) => renames
+            .get(v.1.as_str())
+            .cloned()
+            .unwrap_or_else(|| v.1.to_string()),
+        Expr::ThisPtr(..) => "this".to_string(),
+        Expr::Property(x, ..) => x.2.to_string(),
+        Expr::MethodCall(f, ..) => format!(
+            "{}({})",
+            f.name,
+            f.args.iter().map(r).collect::<Vec<_>>().join(", ")
+        ),
+        Expr::FnCall(f, ..) if f.is_operator_call() && f.args.len() == 2 => {
+            format!("{} {} {}", operand(&f.args[0]), f.name, operand(&f.args[1]))
+        }
+        Expr::FnCall(f, ..) if f.is_operator_call() && f.args.len() == 1 => {
+            format!("{}{}", f.name, operand(&f.args[0]))
+        }
+        Expr::FnCall(f, ..) => format!(
+            "{}({})",
+            f.name,
+            f.args.iter().map(r).collect::<Vec<_>>().join(", ")
+        ),
+        Expr::Dot(x, ..) => format!("{}.{}", r(&x.lhs), r(&x.rhs)),
+        Expr::Index(x, ..) => format!("{}[{}]", r(&x.lhs), r(&x.rhs)),
+        Expr::And(items, ..) => join(items, " && "),
+        Expr::Or(items, ..) => join(items, " || "),
+        Expr::Coalesce(items, ..) => join(items, " ?? "),
+        _ => OPAQUE.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn diff(old: &str, new: &str) -> ScriptDiff {
+        let engine = RuleEngine::new();
+        diff_ast(&engine.compile(old).unwrap(), &engine.compile(new).unwrap())
+    }
+
+    #[test]
+    fn reports_changed_threshold_not_condition() {
+        let result = diff(
+            r#"let status = "NORMAL";
+if input.temperature > 80.0 { status = "WARNING"; }
+#{status: status}"#,
+            r#"let status = "NORMAL";
+if input.temperature > 85.0 { status = "WARNING"; }
+#{status: status}"#,
+        );
+        assert_eq!(
+            result.thresholds,
+            vec![ThresholdChange {
+                subject: "input.temperature".into(),
+                operator: ">".into(),
+                old_value: 80.0,
+                new_value: 85.0,
+                old_line: 2,
+                new_line: 2,
+            }]
+        );
+        assert!(result.conditions.is_empty());
+        assert!(result.renamed_variables.is_empty());
+    }
+
+    #[test]
+    fn normalizes_operand_order() {
+        let result = diff(
+            "let t = input.temperature; if 80.0 < t { return #{status: \"WARNING\"}; } #{}",
+            "let t = input.temperature; if t > 90.0 { return #{status: \"WARNING\"}; } #{}",
+        );
+        assert_eq!(result.thresholds.len(), 1);
+        assert_eq!(result.thresholds[0].subject, "t");
+        assert_eq!(result.thresholds[0].operator, ">");
+        assert_eq!(
+            (
+                result.thresholds[0].old_value,
+                result.thresholds[0].new_value
+            ),
+            (80.0, 90.0)
+        );
+    }
+
+    #[test]
+    fn detects_renamed_variable_by_initializer() {
+        let result = diff(
+            r#"let rate = input.defect_count / input.production_count;
+if rate > 0.05 { return #{status: "CRITICAL"}; }
+#{status: "NORMAL"}"#,
+            r#"let defect_rate = input.defect_count / input.production_count;
+if defect_rate > 0.03 { return #{status: "CRITICAL"}; }
+#{status: "NORMAL"}"#,
+        );
+        assert_eq!(
+            result.renamed_variables,
+            vec![VariableRename {
+                old_name: "rate".into(),
+                new_name: "defect_rate".into(),
+                line: 1,
+            }]
+        );
+        // 이름이 바뀌어도 같은 비교로 짝지어 임계값 변경으로 보고
+        assert_eq!(result.thresholds.len(), 1);
+        assert_eq!(result.thresholds[0].subject, "rate");
+        assert_eq!(result.thresholds[0].new_value, 0.03);
+        assert!(result.conditions.is_empty());
+    }
+
+    #[test]
+    fn reports_added_and_removed_conditions() {
+        let result = diff(
+            r#"let status = "NORMAL";
+if input.pressure < 2.0 { status = "WARNING"; }
+#{status: status}"#,
+            r#"let status = "NORMAL";
+if input.humidity > 70.0 { status = "WARNING"; }
+#{status: status}"#,
+        );
+        let kinds: Vec<_> = result.conditions.iter().map(|c| (c.kind, c.line)).collect();
+        assert_eq!(
+            kinds,
+            vec![(ChangeKind::Removed, 2), (ChangeKind::Added, 2)]
+        );
+        assert!(result.conditions[0].condition.contains("input.pressure"));
+        assert!(result.conditions[1].condition.contains("input.humidity"));
+        // 대상이 다른 비교는 임계값 변경이 아님
+        assert!(result.thresholds.is_empty());
+    }
+
+    #[test]
+    fn pairs_thresholds_on_same_subject_in_order() {
+        let result = diff(
+            r#"let status = "NORMAL";
+if input.temperature > 90.0 { status = "CRITICAL"; }
+else if input.temperature > 80.0 { status = "WARNING"; }
+#{status: status}"#,
+            r#"let status = "NORMAL";
+if input.temperature > 95.0 { status = "CRITICAL"; }
+else if input.temperature > 80.0 { status = "WARNING"; }
+#{status: status}"#,
+        );
+        assert_eq!(result.thresholds.len(), 1);
+        let change = &result.thresholds[0];
+        assert_eq!((change.old_value, change.new_value), (90.0, 95.0));
+        assert_eq!((change.old_line, change.new_line), (2, 2));
+        assert!(result.conditions.is_empty());
+    }
+
+    #[test]
+    fn identical_scripts_have_no_diff() {
+        let script = r#"if input.temperature > 80.0 { return #{status: "WARNING"}; } #{}"#;
+        assert!(diff(script, script).is_empty());
+    }
+
+    #[test]
+    fn samples_report_status_changes() {
+        let engine = RuleEngine::new();
+        let result = engine
+            .diff_scripts(
+                r#"if input.t > 80.0 { return #{status: "WARNING"}; } #{status: "NORMAL"}"#,
+                r#"if input.t > 85.0 { return #{status: "WARNING"}; } #{status: "NORMAL"}"#,
+                &[json!({"t": 70.0}), json!({"t": 82.0}), json!({"t": 90.0})],
+            )
+            .unwrap();
+        assert_eq!(result.samples, 3);
+        assert_eq!(
+            result.status_changes,
+            vec![StatusChange {
+                index: 1,
+                input: json!({"t": 82.0}),
+                old: SampleOutcome::Status("WARNING".into()),
+                new: SampleOutcome::Status("NORMAL".into()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Dynamic, Engine, Scope, AST};
+use serde_json::Value;
+
+use crate::cache::{AstCache, CacheStats};
+use crate::error::RuleError;
+use crate::functions;
+use crate::limits::{DeadlineGuard, ExecutionLimits};
+use crate::output::RuleOutput;
+use crate::trace::{self, Trace, TraceGuard};
+
+/// Rhai 룰 엔진
+///
+/// 내부 `rhai::Engine`은 `Send + Sync`이므로 하나의 인스턴스를
+/// 여러 스레드에서 공유해 사용할 수 있다.
+pub struct RuleEngine {
+    engine: Engine,
+    limits: ExecutionLimits,
+    cache: AstCache,
+}
+
+impl RuleEngine {
+    /// 기본 실행 제한으로 엔진 생성
+    pub fn new() -> Self {
+        Self::with_limits(ExecutionLimits::default())
+    }
+
+    /// 지정한 실행 제한으로 엔진 생성
+    pub fn with_limits(limits: ExecutionLimits) -> Self {
+        let mut engine = Engine::new();
+        // 룰셋에서 파일 모듈을 불러오지 못하도록 차단
+        engine.set_module_resolver(DummyModuleResolver::new());
+        limits.apply(&mut engine);
+        functions::register(&mut engine);
+        trace::install(&mut engine);
+        Self {
+            engine,
+            limits,
+            cache: AstCache::default(),
+        }
+    }
+
+    /// 적용된 실행 제한
+    pub fn limits(&self) -> &ExecutionLimits {
+        &self.limits
+    }
+
+    /// 스크립트 컴파일
+    pub fn compile(&self, script: &str) -> Result<AST, RuleError> {
+        Ok(self.engine.compile(script)?)
+    }
+
+    /// 조건식 컴파일 ([`RuleEngine::evaluate_condition`]과 같이 식 하나만 허용)
+    pub fn compile_expression(&self, expression: &str) -> Result<AST, RuleError> {
+        Ok(self.engine.compile_expression(expression)?)
+    }
+
+    /// 룰셋 버전별로 캐시된 AST 반환 (없으면 컴파일 후 캐시)
+    pub fn compile_cached(
+        &self,
+        ruleset_id: &str,
+        version: &str,
+        script: &str,
+    ) -> Result<Arc<AST>, RuleError> {
+        self.cache
+            .get_or_insert(ruleset_id, version, script, || self.compile(script))
+    }
+
+    /// 룰셋 스크립트 변경 시 캐시 무효화 (제거된 항목 수 반환)
+    pub fn invalidate(&self, ruleset_id: &str) -> usize {
+        self.cache.invalidate(ruleset_id)
+    }
+
+    /// AST 캐시 적중/미스 통계
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache.stats()
+    }
+
+    /// 스크립트를 컴파일 후 `input`에 대해 실행
+    pub fn execute(&self, script: &str, input: &Value) -> Result<RuleOutput, RuleError> {
+        let ast = self.compile(script)?;
+        self.execute_ast(&ast, input)
+    }
+
+    /// 컴파일된 AST를 `input`에 대해 실행
+    pub fn execute_ast(&self, ast: &AST, input: &Value) -> Result<RuleOutput, RuleError> {
+        let mut scope = Scope::new();
+        scope.push("input", input_to_dynamic(input)?);
+
+        let _deadline = DeadlineGuard::start(self.limits.timeout());
+        let result: Dynamic = self.engine.eval_ast_with_scope(&mut scope, ast)?;
+        dynamic_to_output(result)
+    }
+
+    /// 조건식 평가 (워크플로우 조건 노드용)
+    ///
+    /// `input`의 최상위 필드를 변수로도 노출하므로 `input.defect_rate > 0.05`와
+    /// `defect_rate > 0.05` 모두 쓸 수 있다. 결과가 bool이 아니면 오류.
+    pub fn evaluate_condition(&self, expression: &str, input: &Value) -> Result<bool, RuleError> {
+        let ast = self.compile_expression(expression)?;
+
+        let input_value = input_to_dynamic(input)?;
+
+        let mut scope = Scope::new();
+        if let Value::Object(fields) = input {
+            for (name, value) in fields {
+                let value = rhai::serde::to_dynamic(value)
+                    .map_err(|e| RuleError::InvalidInput(e.to_string()))?;
+                scope.push_constant_dynamic(name.as_str(), value);
+            }
+        }
+        scope.push_constant_dynamic("input", input_value);
+
+        let _deadline = DeadlineGuard::start(self.limits.timeout());
+        let result: Dynamic = self.engine.eval_ast_with_scope(&mut scope, &ast)?;
+        result.as_bool().map_err(|type_name| {
+            RuleError::InvalidOutput(format!("expected bool condition, got {type_name}"))
+        })
+    }
+
+    /// 실행 경로(분기, `let` 값, `checks` 추가 위치)를 함께 기록하며 실행
+    pub fn execute_traced(
+        &self,
+        script: &str,
+        input: &Value,
+    ) -> Result<(RuleOutput, Trace), RuleError> {
+        let ast = self.compile(script)?;
+        let (output, mut trace) = self.execute_ast_traced(&ast, input)?;
+        trace.attach_source(script);
+        Ok((output, trace))
+    }
+
+    /// 컴파일된 AST를 추적 모드로 실행
+    pub fn execute_ast_traced(
+        &self,
+        ast: &AST,
+        input: &Value,
+    ) -> Result<(RuleOutput, Trace), RuleError> {
+        let mut scope = Scope::new();
+        scope.push("input", input_to_dynamic(input)?);
+
+        let _deadline = DeadlineGuard::start(self.limits.timeout());
+        let recording = TraceGuard::start();
+        let result: Dynamic = self.engine.eval_ast_with_scope(&mut scope, ast)?;
+        let trace = recording.finish(&scope);
+        Ok((dynamic_to_output(result)?, trace))
+    }
+}
+
+impl Default for RuleEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn input_to_dynamic(input: &Value) -> Result<Dynamic, RuleError> {
+    if !input.is_object() {
+        return Err(RuleError::InvalidInput(format!(
+            "expected JSON object, got {}",
+            json_type_name(input)
+        )));
+    }
+    rhai::serde::to_dynamic(input).map_err(|e| RuleError::InvalidInput(e.to_string()))
+}
+
+fn dynamic_to_output(result: Dynamic) -> Result<RuleOutput, RuleError> {
+    if !result.is_map() {
+        return Err(RuleError::InvalidOutput(format!(
+            "expected map, got {}",
+            result.type_name()
+        )));
+    }
+    let value: Value =
+        rhai::serde::from_dynamic(&result).map_err(|e| RuleError::InvalidOutput(e.to_string()))?;
+    serde_json::from_value(value).map_err(|e| RuleError::InvalidOutput(e.to_string()))
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
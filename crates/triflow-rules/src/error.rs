@@ -0,0 +1,175 @@
+use rhai::{EvalAltResult, ParseErrorType};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::limits::{Limit, TIMEOUT_TOKEN};
+
+/// 룰 엔진 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록
+/// `{"kind": ..., "detail": ...}` 형태로 직렬화된다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum RuleError {
+    /// 스크립트 문법 오류
+    #[error("compile error at line {line}, column {column}: {message}")]
+    Compile {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
+    /// 스크립트 실행 중 오류
+    #[error("runtime error: {0}")]
+    Runtime(String),
+
+    /// 실행 제한 초과
+    #[error("limit exceeded: {0}")]
+    LimitExceeded(Limit),
+
+    /// 입력 데이터가 JSON 객체가 아님
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+
+    /// 스크립트 반환값이 판단 결과 맵이 아님
+    #[error("invalid output: {0}")]
+    InvalidOutput(String),
+}
+
+impl From<rhai::ParseError> for RuleError {
+    fn from(err: rhai::ParseError) -> Self {
+        if matches!(*err.0, ParseErrorType::ExprTooDeep) {
+            return RuleError::LimitExceeded(Limit::ExprDepth);
+        }
+        RuleError::Compile {
+            line: err.1.line().unwrap_or(0),
+            column: err.1.position().unwrap_or(0),
+            message: err.0.to_string(),
+        }
+    }
+}
+
+impl From<Box<EvalAltResult>> for RuleError {
+    fn from(err: Box<EvalAltResult>) -> Self {
+        match exceeded_limit(&err) {
+            Some(limit) => RuleError::LimitExceeded(limit),
+            None => RuleError::Runtime(err.to_string()),
+        }
+    }
+}
+
+/// 함수 호출 래핑을 벗겨 제한 초과 오류인지 판별
+fn exceeded_limit(err: &EvalAltResult) -> Option<Limit> {
+    match err {
+        EvalAltResult::ErrorInFunctionCall(.., inner, _)
+        | EvalAltResult::ErrorInModule(_, inner, _) => exceeded_limit(inner),
+        EvalAltResult::ErrorParsing(ParseErrorType::ExprTooDeep, _) => Some(Limit::ExprDepth),
+        EvalAltResult::ErrorTooManyOperations(_) => Some(Limit::Operations),
+        EvalAltResult::ErrorStackOverflow(_) => Some(Limit::CallDepth),
+        EvalAltResult::ErrorDataTooLarge(kind, _) => Some(data_limit(kind)),
+        EvalAltResult::ErrorTerminated(token, _)
+            if token
+                .clone()
+                .into_immutable_string()
+                .is_ok_and(|t| t == TIMEOUT_TOKEN) =>
+        {
+            Some(Limit::Timeout)
+        }
+        _ => None,
+    }
+}
+
+/// `ErrorDataTooLarge`의 항목 이름으로 제한 구분
+///
+/// Rhai는 "Length of string", "Size of array/BLOB", "Size of object map"으로 구분한다.
+fn data_limit(kind: &str) -> Limit {
+    if kind.starts_with("Length of string") {
+        Limit::StringSize
+    } else if kind.starts_with("Size of object map") {
+        Limit::MapSize
+    } else {
+        Limit::ArraySize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExecutionLimits, RuleEngine};
+    use serde_json::json;
+
+    #[test]
+    fn oversized_data_names_the_exceeded_limit() {
+        let engine = RuleEngine::with_limits(ExecutionLimits {
+            max_string_size: 8,
+            max_array_size: 4,
+            max_map_size: 2,
+            ..ExecutionLimits::default()
+        });
+        for (script, limit) in [
+            (
+                r#"let s = "abcdefgh"; s += "ij"; #{status: "NORMAL"}"#,
+                Limit::StringSize,
+            ),
+            (
+                "let a = [1]; a.push(2); a.push(3); a.push(4); a.push(5); #{status: \"NORMAL\"}",
+                Limit::ArraySize,
+            ),
+            (
+                "let m = #{a: 1}; m.b = 2; m.c = 3; #{status: \"NORMAL\"}",
+                Limit::MapSize,
+            ),
+        ] {
+            let err = engine.execute(script, &json!({})).unwrap_err();
+            assert!(
+                matches!(err, RuleError::LimitExceeded(l) if l == limit),
+                "{script}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn operation_limit_stops_infinite_loop() {
+        let engine = RuleEngine::with_limits(ExecutionLimits {
+            max_operations: 1_000,
+            timeout_ms: 60_000,
+            ..ExecutionLimits::default()
+        });
+        let err = engine.execute("loop {}", &json!({})).unwrap_err();
+        assert!(
+            matches!(err, RuleError::LimitExceeded(Limit::Operations)),
+            "{err}"
+        );
+        assert_eq!(err.to_string(), "limit exceeded: max_operations");
+    }
+
+    #[test]
+    fn call_depth_limit_stops_recursion() {
+        let engine = RuleEngine::with_limits(ExecutionLimits {
+            max_call_levels: 8,
+            ..ExecutionLimits::default()
+        });
+        let script = "fn down(n) { down(n + 1) } down(0)";
+        let err = engine.execute(script, &json!({})).unwrap_err();
+        assert!(
+            matches!(err, RuleError::LimitExceeded(Limit::CallDepth)),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn timeout_stops_long_running_script() {
+        let engine = RuleEngine::with_limits(ExecutionLimits {
+            max_operations: 0,
+            timeout_ms: 50,
+            ..ExecutionLimits::default()
+        });
+        let started = std::time::Instant::now();
+        let err = engine.execute("loop {}", &json!({})).unwrap_err();
+        assert!(
+            matches!(err, RuleError::LimitExceeded(Limit::Timeout)),
+            "{err}"
+        );
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,315 @@
+//! 제조 도메인 함수 라이브러리
+//!
+//! 룰 스크립트에서 반복 구현하던 공정 지표 계산을 네이티브 함수로 제공한다.
+//!
+//! | 함수 | 설명 |
+//! |------|------|
+//! | `defect_rate(defects, total)` | 불량률 (0.0 ~ 1.0) |
+//! | `mean(values)` | 평균 |
+//! | `std_dev(values)` | 표본 표준편차 (n-1) |
+//! | `moving_average(values, window)` | 단순 이동평균 배열 |
+//! | `cpk(values, lsl, usl)` | 공정능력지수 (이동범위 기반 군내 산포) |
+//! | `ppk(values, lsl, usl)` | 공정성능지수 (전체 표준편차) |
+//! | `in_range(value, min, max)` / `out_of_range(value, min, max)` | 범위 판정 |
+//! | `c_to_f`, `f_to_c` | 온도 단위 변환 (°C ↔ °F) |
+//! | `bar_to_psi`, `psi_to_bar` | 압력 단위 변환 (bar ↔ psi) |
+
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Position, INT};
+
+/// 1 bar = 14.5037738 psi
+pub const PSI_PER_BAR: f64 = 14.503_773_8;
+
+/// 이동범위(n=2) 관리도 상수 d2
+const D2_MOVING_RANGE: f64 = 1.128;
+
+/// 불량률 (생산수량이 0이면 0.0)
+pub fn defect_rate(defects: f64, total: f64) -> f64 {
+    if total <= 0.0 {
+        0.0
+    } else {
+        defects / total
+    }
+}
+
+/// 평균
+pub fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// 표본 표준편차 (n-1)
+pub fn std_dev(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let avg = mean(values)?;
+    let var = values.iter().map(|v| (v - avg).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    Some(var.sqrt())
+}
+
+/// 단순 이동평균 (결과 길이 = len - window + 1)
+pub fn moving_average(values: &[f64], window: usize) -> Option<Vec<f64>> {
+    if window == 0 || window > values.len() {
+        return None;
+    }
+    Some(
+        values
+            .windows(window)
+            .map(|w| w.iter().sum::<f64>() / window as f64)
+            .collect(),
+    )
+}
+
+/// 공정능력지수 Cpk (군내 산포 σ = 평균 이동범위 / d2)
+pub fn cpk(values: &[f64], lsl: f64, usl: f64) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let mr_bar =
+        values.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<f64>() / (values.len() - 1) as f64;
+    capability(mean(values)?, mr_bar / D2_MOVING_RANGE, lsl, usl)
+}
+
+/// 공정성능지수 Ppk (전체 표본 표준편차)
+pub fn ppk(values: &[f64], lsl: f64, usl: f64) -> Option<f64> {
+    capability(mean(values)?, std_dev(values)?, lsl, usl)
+}
+
+fn capability(avg: f64, sigma: f64, lsl: f64, usl: f64) -> Option<f64> {
+    if sigma <= 0.0 || lsl >= usl {
+        return None;
+    }
+    Some(((usl - avg) / (3.0 * sigma)).min((avg - lsl) / (3.0 * sigma)))
+}
+
+/// 값이 [min, max] 범위 안에 있는지
+pub fn in_range(value: f64, min: f64, max: f64) -> bool {
+    (min..=max).contains(&value)
+}
+
+/// °C → °F
+pub fn c_to_f(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// °F → °C
+pub fn f_to_c(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+/// bar → psi
+pub fn bar_to_psi(bar: f64) -> f64 {
+    bar * PSI_PER_BAR
+}
+
+/// psi → bar
+pub fn psi_to_bar(psi: f64) -> f64 {
+    psi / PSI_PER_BAR
+}
+
+type FnResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// 엔진에 도메인 함수 등록
+pub(crate) fn register(engine: &mut Engine) {
+    engine
+        .register_fn("defect_rate", |d: Dynamic, t: Dynamic| -> FnResult<f64> {
+            Ok(defect_rate(num(&d)?, num(&t)?))
+        })
+        .register_fn("mean", |values: Array| -> FnResult<f64> {
+            require("mean", mean(&nums(&values)?), "at least 1 value")
+        })
+        .register_fn("std_dev", |values: Array| -> FnResult<f64> {
+            require("std_dev", std_dev(&nums(&values)?), "at least 2 values")
+        })
+        .register_fn(
+            "moving_average",
+            |values: Array, window: INT| -> FnResult<Array> {
+                let window = usize::try_from(window).unwrap_or(0);
+                let result = require(
+                    "moving_average",
+                    moving_average(&nums(&values)?, window),
+                    "1 <= window <= len(values)",
+                )?;
+                Ok(result.into_iter().map(Dynamic::from_float).collect())
+            },
+        )
+        .register_fn(
+            "cpk",
+            |values: Array, lsl: Dynamic, usl: Dynamic| -> FnResult<f64> {
+                let (lsl, usl) = (num(&lsl)?, num(&usl)?);
+                require(
+                    "cpk",
+                    cpk(&nums(&values)?, lsl, usl),
+                    "at least 2 values with variation and lsl < usl",
+                )
+            },
+        )
+        .register_fn(
+            "ppk",
+            |values: Array, lsl: Dynamic, usl: Dynamic| -> FnResult<f64> {
+                let (lsl, usl) = (num(&lsl)?, num(&usl)?);
+                require(
+                    "ppk",
+                    ppk(&nums(&values)?, lsl, usl),
+                    "at least 2 values with variation and lsl < usl",
+                )
+            },
+        )
+        .register_fn(
+            "in_range",
+            |v: Dynamic, min: Dynamic, max: Dynamic| -> FnResult<bool> {
+                Ok(in_range(num(&v)?, num(&min)?, num(&max)?))
+            },
+        )
+        .register_fn(
+            "out_of_range",
+            |v: Dynamic, min: Dynamic, max: Dynamic| -> FnResult<bool> {
+                Ok(!in_range(num(&v)?, num(&min)?, num(&max)?))
+            },
+        )
+        .register_fn("c_to_f", |v: Dynamic| -> FnResult<f64> {
+            Ok(c_to_f(num(&v)?))
+        })
+        .register_fn("f_to_c", |v: Dynamic| -> FnResult<f64> {
+            Ok(f_to_c(num(&v)?))
+        })
+        .register_fn("bar_to_psi", |v: Dynamic| -> FnResult<f64> {
+            Ok(bar_to_psi(num(&v)?))
+        })
+        .register_fn("psi_to_bar", |v: Dynamic| -> FnResult<f64> {
+            Ok(psi_to_bar(num(&v)?))
+        });
+}
+
+/// INT/FLOAT 값을 f64로 변환
+fn num(value: &Dynamic) -> FnResult<f64> {
+    value
+        .as_float()
+        .or_else(|_| value.as_int().map(|i| i as f64))
+        .map_err(|typ| {
+            EvalAltResult::ErrorMismatchDataType("number".into(), typ.into(), Position::NONE).into()
+        })
+}
+
+fn nums(values: &Array) -> FnResult<Vec<f64>> {
+    values.iter().map(num).collect()
+}
+
+fn require<T>(name: &str, value: Option<T>, requirement: &str) -> FnResult<T> {
+    value.ok_or_else(|| format!("{name}() requires {requirement}").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RuleEngine;
+    use serde_json::json;
+
+    const EPS: f64 = 1e-6;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < EPS
+    }
+
+    #[test]
+    fn defect_rate_divides_and_handles_zero_production() {
+        assert!(approx(defect_rate(3.0, 100.0), 0.03));
+        assert_eq!(defect_rate(3.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn mean_and_std_dev() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert!(approx(mean(&values).unwrap(), 5.0));
+        assert!(approx(std_dev(&values).unwrap(), 2.138_089_935));
+        assert_eq!(mean(&[]), None);
+        assert_eq!(std_dev(&[1.0]), None);
+    }
+
+    #[test]
+    fn moving_average_windows() {
+        let result = moving_average(&[1.0, 2.0, 3.0, 4.0, 5.0], 3).unwrap();
+        assert_eq!(result, vec![2.0, 3.0, 4.0]);
+        assert_eq!(moving_average(&[1.0, 2.0], 3), None);
+        assert_eq!(moving_average(&[1.0, 2.0], 0), None);
+    }
+
+    #[test]
+    fn cpk_uses_moving_range_sigma() {
+        // 평균 10, 평균 이동범위 1 → σ = 1 / 1.128
+        let values = [9.5, 10.5, 9.5, 10.5];
+        let sigma = 1.0 / D2_MOVING_RANGE;
+        let expected = (13.0 - 10.0) / (3.0 * sigma);
+        assert!(approx(cpk(&values, 7.0, 13.0).unwrap(), expected));
+    }
+
+    #[test]
+    fn ppk_uses_overall_sigma_and_nearest_limit() {
+        let values = [9.0, 10.0, 11.0];
+        // 평균 10, σ = 1, USL 쪽이 더 가까움
+        assert!(approx(ppk(&values, 4.0, 12.0).unwrap(), 2.0 / 3.0));
+        assert_eq!(ppk(&[5.0, 5.0], 0.0, 10.0), None);
+        assert_eq!(ppk(&values, 12.0, 4.0), None);
+    }
+
+    #[test]
+    fn range_checks_are_inclusive() {
+        assert!(in_range(2.0, 2.0, 8.0));
+        assert!(in_range(8.0, 2.0, 8.0));
+        assert!(!in_range(8.1, 2.0, 8.0));
+    }
+
+    #[test]
+    fn unit_conversions() {
+        assert!(approx(c_to_f(100.0), 212.0));
+        assert!(approx(f_to_c(-40.0), -40.0));
+        assert!(approx(bar_to_psi(1.0), 14.503_773_8));
+        assert!(approx(psi_to_bar(bar_to_psi(6.5)), 6.5));
+    }
+
+    #[test]
+    fn functions_are_callable_from_scripts() {
+        let engine = RuleEngine::new();
+        let script = r#"
+            let rate = defect_rate(input.defect_count, input.production_count);
+            #{
+                status: if rate > 0.05 { "CRITICAL" } else { "NORMAL" },
+                rate: rate,
+                avg: moving_average(input.temps, 2),
+                pressure_ok: in_range(input.pressure, 2, 8.0),
+                temp_f: c_to_f(input.temps[0])
+            }
+        "#;
+        let input = json!({
+            "defect_count": 6,
+            "production_count": 100,
+            "temps": [20, 30.0, 40],
+            "pressure": 5
+        });
+
+        let output = engine.execute(script, &input).unwrap();
+        assert_eq!(output.status, "CRITICAL");
+        assert_eq!(output.extra["rate"], json!(0.06));
+        assert_eq!(output.extra["avg"], json!([25.0, 35.0]));
+        assert_eq!(output.extra["pressure_ok"], json!(true));
+        assert_eq!(output.extra["temp_f"], json!(68.0));
+    }
+
+    #[test]
+    fn invalid_arguments_raise_runtime_errors() {
+        let engine = RuleEngine::new();
+        let err = engine
+            .execute("std_dev([1.0]); #{}", &json!({}))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("std_dev() requires at least 2 values"));
+
+        let err = engine
+            .execute(r#"c_to_f("hot"); #{}"#, &json!({}))
+            .unwrap_err();
+        assert!(err.to_string().contains("number"));
+    }
+}
@@ -0,0 +1,39 @@
+//! TriFlow AI 판단 룰 엔진
+//!
+//! `core.rulesets.rhai_code`에 저장된 Rhai 스크립트를 실행한다.
+//! 데스크톱(Tauri)과 서버(PyO3 바인딩)가 같은 엔진을 사용하므로
+//! 룰셋은 어디서 실행하든 동일한 결과를 낸다.
+//!
+//! 스크립트에는 입력 데이터가 `input` 맵으로 주어지고,
+//! `#{status, confidence, checks}` 형태의 맵을 반환해야 한다.
+
+mod batch;
+mod cache;
+mod diagnostics;
+mod diff;
+mod engine;
+mod error;
+pub mod functions;
+mod limits;
+mod output;
+pub mod testing;
+mod trace;
+
+#[cfg(feature = "arrow")]
+pub use batch::record_batch_to_rows;
+pub use batch::{BatchResult, BatchRow};
+pub use cache::{AstCache, CacheStats, DEFAULT_CACHE_CAPACITY};
+pub use diagnostics::{Diagnostic, DiagnosticKind, Severity, ValidationReport};
+pub use diff::{
+    diff_ast, ChangeKind, ConditionChange, RulesetDiff, SampleOutcome, ScriptDiff, StatusChange,
+    ThresholdChange, VariableRename,
+};
+pub use engine::RuleEngine;
+pub use error::RuleError;
+pub use limits::{ExecutionLimits, Limit};
+pub use output::RuleOutput;
+pub use testing::{RulesetTestCase, TestReport};
+pub use trace::{Trace, TraceEvent};
+
+/// 컴파일된 스크립트
+pub use rhai::AST;
@@ -0,0 +1,116 @@
+use std::cell::Cell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use rhai::{Dynamic, Engine};
+use serde::{Deserialize, Serialize};
+
+/// 스크립트 1회 실행당 자원 제한
+///
+/// 룰셋은 테넌트 사용자와 LearningAgent가 작성하므로
+/// 무한 루프나 과도한 메모리 사용을 엔진 차원에서 차단한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExecutionLimits {
+    /// 최대 연산 횟수
+    pub max_operations: u64,
+    /// 최대 함수 호출 깊이
+    pub max_call_levels: usize,
+    /// 최대 표현식 중첩 깊이 (컴파일 시 검사)
+    pub max_expr_depth: usize,
+    /// 문자열 최대 길이
+    pub max_string_size: usize,
+    /// 배열 최대 크기
+    pub max_array_size: usize,
+    /// 맵 최대 크기
+    pub max_map_size: usize,
+    /// 최대 실행 시간 (ms)
+    pub timeout_ms: u64,
+}
+
+impl Default for ExecutionLimits {
+    fn default() -> Self {
+        Self {
+            max_operations: 100_000,
+            max_call_levels: 32,
+            max_expr_depth: 64,
+            max_string_size: 10_000,
+            max_array_size: 10_000,
+            max_map_size: 1_000,
+            timeout_ms: 1_000,
+        }
+    }
+}
+
+/// 초과된 제한 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Limit {
+    Operations,
+    CallDepth,
+    ExprDepth,
+    StringSize,
+    ArraySize,
+    MapSize,
+    Timeout,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Limit::Operations => "max_operations",
+            Limit::CallDepth => "max_call_levels",
+            Limit::ExprDepth => "max_expr_depth",
+            Limit::StringSize => "max_string_size",
+            Limit::ArraySize => "max_array_size",
+            Limit::MapSize => "max_map_size",
+            Limit::Timeout => "timeout_ms",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 실행 시간 초과 시 `on_progress`가 반환하는 종료 토큰
+pub(crate) const TIMEOUT_TOKEN: &str = "triflow:timeout";
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+impl ExecutionLimits {
+    /// 엔진에 제한 적용
+    pub(crate) fn apply(&self, engine: &mut Engine) {
+        engine
+            .set_max_operations(self.max_operations)
+            .set_max_call_levels(self.max_call_levels)
+            .set_max_expr_depths(self.max_expr_depth, self.max_expr_depth)
+            .set_max_string_size(self.max_string_size)
+            .set_max_array_size(self.max_array_size)
+            .set_max_map_size(self.max_map_size);
+
+        engine.on_progress(|_| {
+            let expired = DEADLINE.with(|d| d.get().is_some_and(|t| Instant::now() >= t));
+            expired.then(|| Dynamic::from(TIMEOUT_TOKEN))
+        });
+    }
+
+    pub(crate) fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+/// 현재 스레드의 실행 마감 시각 설정 (drop 시 해제)
+pub(crate) struct DeadlineGuard(Option<Instant>);
+
+impl DeadlineGuard {
+    pub(crate) fn start(timeout: Duration) -> Self {
+        let previous = DEADLINE.with(|d| d.replace(Some(Instant::now() + timeout)));
+        Self(previous)
+    }
+}
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        DEADLINE.with(|d| d.set(self.0));
+    }
+}
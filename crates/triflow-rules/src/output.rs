@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// 룰셋 실행 결과 (`#{status, confidence, checks}`)
+///
+/// 스크립트가 반환한 맵의 나머지 키는 `extra`에 그대로 보존된다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleOutput {
+    /// 판단 상태 (NORMAL / WARNING / CRITICAL 등)
+    #[serde(default = "default_status")]
+    pub status: String,
+
+    /// 판단 신뢰도 (0.0 ~ 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+
+    /// 개별 체크 항목 목록
+    #[serde(default)]
+    pub checks: Vec<Value>,
+
+    /// 기타 반환 키
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+fn default_status() -> String {
+    "NORMAL".to_string()
+}
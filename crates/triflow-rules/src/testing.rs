@@ -0,0 +1,263 @@
+//! 룰셋 단위 테스트 하네스
+//!
+//! 룰셋마다 이름 붙은 입력과 기대 결과(`status`, `checks`)를 저장해 두고,
+//! `is_active`를 켜기 전에 변경된 스크립트가 기대대로 동작하는지 확인한다.
+//!
+//! ```json
+//! [
+//!   {
+//!     "name": "고온 경고",
+//!     "input": { "temperature": 82.0 },
+//!     "expected_status": "WARNING",
+//!     "expected_checks": [{ "type": "temperature", "status": "HIGH" }]
+//!   }
+//! ]
+//! ```
+//!
+//! `expected_checks`는 순서대로 비교하며, 각 항목은 명시한 키만 비교한다
+//! (메시지 문구 등은 고정하지 않아도 된다).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::engine::RuleEngine;
+use crate::output::RuleOutput;
+
+/// 룰셋 테스트 케이스
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesetTestCase {
+    pub name: String,
+    pub input: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_checks: Option<Vec<Value>>,
+}
+
+/// 기대값과 실제값 차이
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Mismatch {
+    /// 예: `status`, `checks.len`, `checks[0].status`
+    pub path: String,
+    pub expected: Value,
+    pub actual: Value,
+}
+
+/// 케이스별 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TestCaseResult {
+    pub name: String,
+    pub passed: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mismatches: Vec<Mismatch>,
+    /// 스크립트 실행 자체가 실패한 경우
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 전체 테스트 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TestReport {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub cases: Vec<TestCaseResult>,
+}
+
+impl TestReport {
+    pub fn all_passed(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+impl RuleEngine {
+    /// 스크립트를 한 번 컴파일해 모든 테스트 케이스 실행
+    ///
+    /// 컴파일 오류는 모든 케이스의 실패로 보고된다.
+    pub fn run_tests(&self, script: &str, cases: &[RulesetTestCase]) -> TestReport {
+        let ast = self.compile(script);
+
+        let cases: Vec<TestCaseResult> = cases
+            .iter()
+            .map(|case| {
+                let result = ast.as_ref().map_err(|e| e.to_string()).and_then(|ast| {
+                    self.execute_ast(ast, &case.input)
+                        .map_err(|e| e.to_string())
+                });
+                match result {
+                    Ok(output) => {
+                        let mismatches = compare(case, &output);
+                        TestCaseResult {
+                            name: case.name.clone(),
+                            passed: mismatches.is_empty(),
+                            mismatches,
+                            error: None,
+                        }
+                    }
+                    Err(error) => TestCaseResult {
+                        name: case.name.clone(),
+                        passed: false,
+                        mismatches: Vec::new(),
+                        error: Some(error),
+                    },
+                }
+            })
+            .collect();
+
+        let passed = cases.iter().filter(|c| c.passed).count();
+        TestReport {
+            total: cases.len(),
+            passed,
+            failed: cases.len() - passed,
+            cases,
+        }
+    }
+}
+
+fn compare(case: &RulesetTestCase, output: &RuleOutput) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+
+    if let Some(expected) = &case.expected_status {
+        if *expected != output.status {
+            mismatches.push(Mismatch {
+                path: "status".to_string(),
+                expected: Value::from(expected.as_str()),
+                actual: Value::from(output.status.as_str()),
+            });
+        }
+    }
+
+    if let Some(expected) = &case.expected_checks {
+        if expected.len() != output.checks.len() {
+            mismatches.push(Mismatch {
+                path: "checks.len".to_string(),
+                expected: expected.len().into(),
+                actual: output.checks.len().into(),
+            });
+        }
+        for (i, (exp, act)) in expected.iter().zip(&output.checks).enumerate() {
+            compare_partial(&format!("checks[{i}]"), exp, act, &mut mismatches);
+        }
+    }
+
+    mismatches
+}
+
+/// 기대값에 명시된 키만 비교 (객체는 재귀, 그 외는 동등 비교)
+fn compare_partial(path: &str, expected: &Value, actual: &Value, out: &mut Vec<Mismatch>) {
+    match (expected, actual) {
+        (Value::Object(exp), Value::Object(act)) => {
+            for (key, exp_value) in exp {
+                let act_value = act.get(key).unwrap_or(&Value::Null);
+                compare_partial(&format!("{path}.{key}"), exp_value, act_value, out);
+            }
+        }
+        (Value::Number(a), Value::Number(b)) if a.as_f64() == b.as_f64() => (),
+        _ if expected == actual => (),
+        _ => out.push(Mismatch {
+            path: path.to_string(),
+            expected: expected.clone(),
+            actual: actual.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const SCRIPT: &str = r#"
+        let t = input.temperature;
+        let status = if t > 80.0 { "WARNING" } else { "NORMAL" };
+        #{
+            status: status,
+            checks: [#{type: "temperature", status: if t > 80.0 { "HIGH" } else { "OK" }, value: t}]
+        }
+    "#;
+
+    fn case(input: Value, status: &str, checks: Option<Value>) -> RulesetTestCase {
+        RulesetTestCase {
+            name: "case".into(),
+            input,
+            expected_status: Some(status.into()),
+            expected_checks: checks.map(|c| serde_json::from_value(c).unwrap()),
+        }
+    }
+
+    #[test]
+    fn passes_when_output_matches() {
+        let cases = [case(
+            json!({"temperature": 82}),
+            "WARNING",
+            // 명시하지 않은 키(value)는 비교하지 않고, 숫자는 정수/실수 구분 없이 비교
+            Some(json!([{"type": "temperature", "status": "HIGH"}])),
+        )];
+        let report = RuleEngine::new().run_tests(SCRIPT, &cases);
+        assert!(report.all_passed());
+        assert_eq!((report.total, report.passed, report.failed), (1, 1, 0));
+        assert!(report.cases[0].mismatches.is_empty());
+    }
+
+    #[test]
+    fn reports_status_mismatch() {
+        let cases = [case(json!({"temperature": 50.0}), "WARNING", None)];
+        let report = RuleEngine::new().run_tests(SCRIPT, &cases);
+        assert!(!report.all_passed());
+        assert_eq!(
+            report.cases[0].mismatches,
+            vec![Mismatch {
+                path: "status".into(),
+                expected: json!("WARNING"),
+                actual: json!("NORMAL"),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_check_mismatches_by_path() {
+        let cases = [case(
+            json!({"temperature": 90.0}),
+            "WARNING",
+            Some(json!([
+                {"type": "temperature", "status": "OK", "value": 90},
+                {"type": "humidity"}
+            ])),
+        )];
+        let report = RuleEngine::new().run_tests(SCRIPT, &cases);
+        assert_eq!(report.failed, 1);
+        assert_eq!(
+            report.cases[0].mismatches,
+            vec![
+                Mismatch {
+                    path: "checks.len".into(),
+                    expected: json!(2),
+                    actual: json!(1),
+                },
+                Mismatch {
+                    path: "checks[0].status".into(),
+                    expected: json!("OK"),
+                    actual: json!("HIGH"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn script_errors_fail_every_case() {
+        let cases = [
+            case(json!({"temperature": 90.0}), "WARNING", None),
+            case(json!({"temperature": 50.0}), "NORMAL", None),
+        ];
+
+        let report = RuleEngine::new().run_tests("let x = ;", &cases);
+        assert_eq!((report.passed, report.failed), (0, 2));
+        assert!(report.cases.iter().all(|c| c.error.is_some()));
+
+        let report = RuleEngine::new().run_tests(r#"throw "sensor offline""#, &cases[..1]);
+        let error = report.cases[0].error.as_deref().unwrap();
+        assert!(error.contains("sensor offline"), "{error}");
+        assert!(report.cases[0].mismatches.is_empty());
+    }
+}
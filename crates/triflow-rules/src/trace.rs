@@ -0,0 +1,434 @@
+use std::cell::RefCell;
+
+use rhai::debugger::{DebuggerCommand, DebuggerEvent};
+use rhai::{
+    ASTNode, Array, Dynamic, Engine, EvalContext, Expr, FlowControl, Position, Scope, Stmt,
+    StmtBlock,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// 실행 추적 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// `if` 조건 평가 결과
+    Branch {
+        line: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
+        taken: bool,
+    },
+    /// `let` 바인딩 값
+    Binding {
+        line: usize,
+        name: String,
+        value: Value,
+    },
+    /// `checks.push(...)`로 추가된 항목
+    Check { line: usize, entry: Value },
+}
+
+/// 룰 실행 경로 (이벤트 발생 순서)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Trace {
+    pub events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    /// 스크립트 원문으로 분기 이벤트의 `source`(해당 줄) 채우기
+    pub fn attach_source(&mut self, script: &str) {
+        let lines: Vec<&str> = script.lines().collect();
+        for event in &mut self.events {
+            if let TraceEvent::Branch { line, source, .. } = event {
+                *source = lines
+                    .get(line.wrapping_sub(1))
+                    .map(|l| l.trim().to_string());
+            }
+        }
+    }
+}
+
+/// 실행 완료 후 값을 읽어야 하는 항목
+enum Pending {
+    Binding {
+        name: String,
+        line: usize,
+        level: usize,
+        index: usize,
+    },
+    Check {
+        line: usize,
+        level: usize,
+        len: usize,
+    },
+    /// 조건식은 다시 평가하지 않고, 다음에 실행되는 위치로 어느 분기를 탔는지 판단
+    Branch {
+        line: usize,
+        level: usize,
+        span: IfSpan,
+    },
+}
+
+/// 소스 위치 (줄, 열)
+type Pos = (usize, usize);
+
+fn pos(position: Position) -> Option<Pos> {
+    Some((position.line()?, position.position().unwrap_or(0)))
+}
+
+/// `if` 문의 위치 범위
+struct IfSpan {
+    start: Pos,
+    /// then 블록 (`{` ~ `}`)
+    then: (Pos, Pos),
+    then_empty: bool,
+    /// else 블록 (없거나 비어 있으면 None, `else if`는 끝까지)
+    otherwise: Option<(Pos, Pos)>,
+}
+
+/// 다음 실행 위치로 본 분기 상태
+enum Step {
+    /// 아직 조건식 평가 중
+    Condition,
+    Taken(bool),
+    /// 두 분기 모두 비어 있어 알 수 없음
+    Unknown,
+}
+
+impl IfSpan {
+    fn new(flow: &FlowControl, position: Position) -> Option<Self> {
+        let otherwise = if flow.branch.is_empty() {
+            None
+        } else {
+            Some((pos(flow.branch.position())?, pos(block_end(&flow.branch))?))
+        };
+        Some(Self {
+            start: pos(position)?,
+            then: (pos(flow.body.position())?, pos(flow.body.end_position())?),
+            then_empty: flow.body.is_empty(),
+            otherwise,
+        })
+    }
+
+    /// 같은 호출 수준에서 다음에 실행되는 위치 (`None`이면 이 `if`를 벗어남)
+    fn step(&self, at: Option<Pos>) -> Step {
+        let within = |(start, end): (Pos, Pos), p: Pos| start <= p && p <= end;
+        match at {
+            Some(p) if within(self.then, p) => Step::Taken(true),
+            Some(p) if self.otherwise.is_some_and(|range| within(range, p)) => Step::Taken(false),
+            Some(p) if self.start < p && p < self.then.0 => Step::Condition,
+            // 어느 블록에도 들어가지 않고 벗어났으면 비어 있는 쪽 분기를 탄 것
+            // (반복문에서 같은 `if`로 돌아온 경우도 여기)
+            _ => match (self.then_empty, self.otherwise.is_some()) {
+                (false, false) => Step::Taken(false),
+                (true, true) => Step::Taken(true),
+                _ => Step::Unknown,
+            },
+        }
+    }
+}
+
+/// 블록 끝 위치 (`else if`로 이어지면 마지막 블록의 끝)
+fn block_end(block: &StmtBlock) -> Position {
+    if !block.end_position().is_none() {
+        return block.end_position();
+    }
+    match block.statements() {
+        [Stmt::If(flow, ..)] if flow.branch.is_empty() => flow.body.end_position(),
+        [Stmt::If(flow, ..)] => block_end(&flow.branch),
+        _ => Position::NONE,
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    trace: Trace,
+    pending: Vec<Pending>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// 현재 스레드에서 추적 기록 시작 (drop 시 기록 종료)
+pub(crate) struct TraceGuard(());
+
+impl TraceGuard {
+    pub(crate) fn start() -> Self {
+        RECORDER.with(|r| *r.borrow_mut() = Some(Recorder::default()));
+        Self(())
+    }
+
+    /// 기록된 추적 결과 회수 (남은 바인딩은 최종 scope에서 읽음)
+    pub(crate) fn finish(self, scope: &Scope) -> Trace {
+        RECORDER.with(|r| {
+            let mut recorder = r.borrow_mut().take().unwrap_or_default();
+            recorder.resolve(scope, 0, None);
+            recorder.trace
+        })
+    }
+}
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        RECORDER.with(|r| r.borrow_mut().take());
+    }
+}
+
+/// 엔진에 추적용 디버거 등록
+///
+/// 추적 중이 아닐 때는 시작 이벤트에서 바로 `Continue`를 반환하므로
+/// 일반 실행에는 단계별 콜백 비용이 들지 않는다.
+#[allow(deprecated)] // register_debugger는 "volatile" 표시용으로 deprecated 처리되어 있음
+pub(crate) fn install(engine: &mut Engine) {
+    engine.register_debugger(
+        |_, debugger| debugger,
+        |mut context, event, node, _source, pos| {
+            let active = RECORDER.with(|r| r.borrow().is_some());
+            match (active, event) {
+                (false, _) => Ok(DebuggerCommand::Continue),
+                (true, DebuggerEvent::End) => {
+                    with_recorder(|rec| rec.resolve(context.scope(), context.call_level(), None));
+                    Ok(DebuggerCommand::Continue)
+                }
+                (true, _) => {
+                    on_step(&mut context, node, pos);
+                    Ok(DebuggerCommand::StepInto)
+                }
+            }
+        },
+    );
+}
+
+fn with_recorder(f: impl FnOnce(&mut Recorder)) {
+    RECORDER.with(|r| {
+        if let Some(rec) = r.borrow_mut().as_mut() {
+            f(rec);
+        }
+    });
+}
+
+fn on_step(context: &mut EvalContext, node: ASTNode, pos: Position) {
+    let level = context.call_level();
+    let line = pos.line().unwrap_or(0);
+    with_recorder(|rec| rec.resolve(context.scope(), level, self::pos(pos)));
+
+    match node {
+        ASTNode::Stmt(Stmt::Var(x, ..)) => {
+            let pending = Pending::Binding {
+                name: x.0.name.to_string(),
+                line,
+                level,
+                index: context.scope().len(),
+            };
+            with_recorder(|rec| rec.pending.push(pending));
+        }
+        ASTNode::Stmt(Stmt::If(flow, ..)) => {
+            if let Some(span) = IfSpan::new(flow, pos) {
+                with_recorder(|rec| rec.pending.push(Pending::Branch { line, level, span }));
+            }
+        }
+        ASTNode::Expr(Expr::Dot(x, ..)) if is_checks_push(&x.lhs, &x.rhs) => {
+            let len = checks_len(context.scope()).unwrap_or(0);
+            with_recorder(|rec| rec.pending.push(Pending::Check { line, level, len }));
+        }
+        _ => (),
+    }
+}
+
+fn is_checks_push(lhs: &Expr, rhs: &Expr) -> bool {
+    matches!(lhs, Expr::Variable(v, ..) if v.1 == "checks")
+        && matches!(rhs, Expr::MethodCall(f, ..) if f.name == "push")
+}
+
+fn checks_len(scope: &Scope) -> Option<usize> {
+    scope.get("checks")?.read_lock::<Array>().map(|a| a.len())
+}
+
+impl Recorder {
+    /// 값이 확정된 대기 항목을 이벤트로 기록 (`at`: 현재 실행 위치)
+    fn resolve(&mut self, scope: &Scope, level: usize, at: Option<Pos>) {
+        let mut events = Vec::new();
+
+        self.pending.retain(|pending| match *pending {
+            Pending::Binding {
+                ref name,
+                line,
+                level: l,
+                index,
+            } if l == level && index < scope.len() => match scope.iter().nth(index) {
+                Some((n, _, value)) if n == name => {
+                    events.push(TraceEvent::Binding {
+                        line,
+                        name: name.clone(),
+                        value: to_json(&value),
+                    });
+                    false
+                }
+                _ => true,
+            },
+            Pending::Check {
+                line,
+                level: l,
+                len,
+            } if l == level => {
+                let entry = scope
+                    .get("checks")
+                    .and_then(|c| c.read_lock::<Array>().and_then(|a| a.get(len).cloned()));
+                match entry {
+                    Some(entry) => {
+                        events.push(TraceEvent::Check {
+                            line,
+                            entry: to_json(&entry),
+                        });
+                        false
+                    }
+                    None => true,
+                }
+            }
+            Pending::Branch {
+                line,
+                level: l,
+                ref span,
+            } if l >= level => match span.step(if l == level { at } else { None }) {
+                Step::Condition => true,
+                Step::Taken(taken) => {
+                    events.push(TraceEvent::Branch {
+                        line,
+                        source: None,
+                        taken,
+                    });
+                    false
+                }
+                Step::Unknown => false,
+            },
+            // 아직 확정되지 않은 항목은 유지, 이미 빠져나온 함수 내부 항목은 폐기
+            Pending::Binding { level: l, .. }
+            | Pending::Check { level: l, .. }
+            | Pending::Branch { level: l, .. } => l <= level,
+        });
+
+        self.trace.events.extend(events);
+    }
+}
+
+fn to_json(value: &Dynamic) -> Value {
+    rhai::serde::from_dynamic(value).unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RuleEngine;
+    use serde_json::json;
+
+    fn branches(trace: &Trace) -> Vec<(usize, bool)> {
+        trace
+            .events
+            .iter()
+            .filter_map(|e| match e {
+                TraceEvent::Branch { line, taken, .. } => Some((*line, *taken)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn side_effecting_condition_runs_once() {
+        let script = r#"
+let stack = [1, 2, 9];
+let checks = [];
+if stack.pop() > 3 {
+    checks.push(#{type: "first", left: stack.len()});
+}
+if stack.pop() > 3 {
+    checks.push(#{type: "second", left: stack.len()});
+}
+#{status: "NORMAL", checks: checks, left: stack.len()}
+"#;
+        let engine = RuleEngine::new();
+        let plain = engine.execute(script, &json!({})).unwrap();
+        let (traced, trace) = engine.execute_traced(script, &json!({})).unwrap();
+
+        assert_eq!(traced, plain);
+        assert_eq!(plain.extra["left"], json!(1));
+        assert_eq!(plain.checks, vec![json!({"type": "first", "left": 2})]);
+        assert_eq!(branches(&trace), vec![(4, true), (7, false)]);
+    }
+
+    #[test]
+    fn records_else_if_chains_empty_blocks_and_loops() {
+        let script = r#"
+let checks = [];
+for v in input.values {
+    if v > 10 {
+        checks.push(#{v: v, level: "high"});
+    } else if v > 5 {
+        checks.push(#{v: v, level: "mid"});
+    } else {
+    }
+}
+if input.flag {} else { checks.push(#{v: 0, level: "flag"}); }
+if input.flag {} else {}
+#{status: "NORMAL", checks: checks}
+"#;
+        let engine = RuleEngine::new();
+        let input = json!({"values": [12, 7, 1], "flag": true});
+        let (output, trace) = engine.execute_traced(script, &input).unwrap();
+
+        assert_eq!(output, engine.execute(script, &input).unwrap());
+        assert_eq!(
+            branches(&trace),
+            vec![
+                (4, true),
+                (4, false),
+                (6, true),
+                (4, false),
+                (6, false),
+                (11, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_branch_and_function_condition() {
+        let script = r#"
+fn high(v) { v > 3 }
+let checks = [];
+if high(input.a) {
+    checks.push(#{a: true});
+    if high(input.b) {
+        checks.push(#{b: true});
+    }
+}
+#{status: "NORMAL", checks: checks}
+"#;
+        let engine = RuleEngine::new();
+        let (_, trace) = engine
+            .execute_traced(script, &json!({"a": 5, "b": 1}))
+            .unwrap();
+        assert_eq!(branches(&trace), vec![(4, true), (6, false)]);
+    }
+
+    #[test]
+    fn traced_execution_uses_same_operation_budget() {
+        // 조건식의 반복문이 제한 대부분을 쓰므로, 조건을 두 번 평가하면 제한을 넘는다
+        let script = r#"
+fn busy(n) { let total = 0; for i in 0..n { total += i; } total }
+let checks = [];
+if busy(input.n) > 0 { checks.push(#{busy: true}); }
+#{status: "NORMAL", checks: checks}
+"#;
+        let engine = RuleEngine::with_limits(crate::ExecutionLimits {
+            max_operations: 1_000,
+            ..Default::default()
+        });
+        let input = json!({"n": 200});
+        let plain = engine.execute(script, &input).unwrap();
+        let (traced, trace) = engine.execute_traced(script, &input).unwrap();
+        assert_eq!(traced, plain);
+        assert_eq!(branches(&trace), vec![(4, true)]);
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+/// 액션 실행 요청
+#[derive(Debug, Clone, Copy)]
+pub struct ActionCall<'a> {
+    pub run_id: &'a str,
+    pub node_id: &'a str,
+    pub action: &'a str,
+    pub parameters: &'a Value,
+    /// 입력 데이터와 이전 노드 출력 (`{필드..., 노드 ID: 출력...}`)
+    pub context: &'a Value,
+}
+
+/// 액션 실행 실패
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{0}")]
+pub struct ActionError(pub String);
+
+/// 액션 노드 실행기
+///
+/// 액션 이름별로 [`ActionRegistry`]에 등록한다. 클로저도 핸들러로 쓸 수 있다.
+pub trait ActionHandler: Send + Sync {
+    fn handle(&self, call: &ActionCall<'_>) -> Result<Value, ActionError>;
+}
+
+impl<F> ActionHandler for F
+where
+    F: Fn(&ActionCall<'_>) -> Result<Value, ActionError> + Send + Sync,
+{
+    fn handle(&self, call: &ActionCall<'_>) -> Result<Value, ActionError> {
+        self(call)
+    }
+}
+
+/// 실제 작업 없이 호출 내용을 그대로 돌려주는 핸들러 (데스크톱 dry-run용)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DryRunHandler;
+
+impl ActionHandler for DryRunHandler {
+    fn handle(&self, call: &ActionCall<'_>) -> Result<Value, ActionError> {
+        Ok(json!({
+            "dry_run": true,
+            "action": call.action,
+            "parameters": call.parameters,
+        }))
+    }
+}
+
+/// 액션 이름 → 핸들러
+#[derive(Clone, Default)]
+pub struct ActionRegistry {
+    handlers: HashMap<String, Arc<dyn ActionHandler>>,
+    fallback: Option<Arc<dyn ActionHandler>>,
+}
+
+impl ActionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 모든 액션을 [`DryRunHandler`]로 처리하는 레지스트리
+    pub fn dry_run() -> Self {
+        let mut registry = Self::new();
+        registry.set_fallback(DryRunHandler);
+        registry
+    }
+
+    /// 액션 핸들러 등록 (같은 이름은 교체)
+    pub fn register(
+        &mut self,
+        action: impl Into<String>,
+        handler: impl ActionHandler + 'static,
+    ) -> &mut Self {
+        self.handlers.insert(action.into(), Arc::new(handler));
+        self
+    }
+
+    /// 등록되지 않은 액션에 사용할 핸들러
+    pub fn set_fallback(&mut self, handler: impl ActionHandler + 'static) -> &mut Self {
+        self.fallback = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn get(&self, action: &str) -> Option<&Arc<dyn ActionHandler>> {
+        self.handlers.get(action).or(self.fallback.as_ref())
+    }
+}
+
+impl fmt::Debug for ActionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut actions: Vec<&str> = self.handlers.keys().map(String::as_str).collect();
+        actions.sort_unstable();
+        f.debug_struct("ActionRegistry")
+            .field("actions", &actions)
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,340 @@
+//! 승인 노드
+//!
+//! `stop_production_line`처럼 위험한 액션 앞에 두어 사람이 확인하기 전에는
+//! 실행되지 않게 한다. 승인 노드에 도달하면 실행은 `waiting_approval` 상태로
+//! 멈추고, 승인하면 `next`로 진행하며 거부하면 취소된다.
+//!
+//! ```json
+//! {
+//!   "id": "confirm_stop",
+//!   "type": "approval",
+//!   "config": { "message": "LINE_A 정지 승인", "timeout_seconds": 600, "on_timeout": "reject" },
+//!   "next": ["stop_line"]
+//! }
+//! ```
+//!
+//! 제한 시간이 지나면 `on_timeout`에 따라 승인하거나(`approve`), 실행을
+//! `timed_out` 상태로 멈춘다(`reject`, 기본값). `timed_out` 실행을 재시도하면
+//! 승인을 다시 요청한다.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::dsl::{Node, NodeType};
+use crate::executor::{NodeRecord, NodeState, WorkflowRun};
+use crate::state::{RunStatus, Transition, TransitionError};
+
+/// 제한 시간이 지났을 때의 결정
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDefault {
+    Approve,
+    #[default]
+    Reject,
+}
+
+/// 승인 노드 설정 (`config`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ApprovalConfig {
+    /// 알림에 표시할 내용
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// 승인 제한 시간 (생략하면 무기한 대기)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+    #[serde(default)]
+    pub on_timeout: ApprovalDefault,
+}
+
+impl ApprovalConfig {
+    pub fn from_node(node: &Node) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(Value::Object(node.config.clone()))
+    }
+}
+
+/// 대기 중인 승인 요청
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalRequest {
+    pub node_id: String,
+    pub message: String,
+    pub requested_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    pub on_timeout: ApprovalDefault,
+    /// 승인 시 실행할 노드
+    pub next: Vec<String>,
+}
+
+impl ApprovalRequest {
+    pub fn new(node: &Node, config: &ApprovalConfig, workflow_name: &str) -> Self {
+        let requested_at = Utc::now();
+        Self {
+            node_id: node.id.clone(),
+            message: config.message.clone().unwrap_or_else(|| {
+                format!(
+                    "'{workflow_name}' 워크플로우의 '{}' 단계 승인 필요",
+                    node.id
+                )
+            }),
+            requested_at,
+            expires_at: config
+                .timeout_seconds
+                .and_then(|secs| i64::try_from(secs).ok())
+                .and_then(Duration::try_seconds)
+                .and_then(|timeout| requested_at.checked_add_signed(timeout)),
+            on_timeout: config.on_timeout,
+            next: node.next.clone(),
+        }
+    }
+
+    /// 제한 시간이 지났는지 여부
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// 승인 결정 기록
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalRecord {
+    pub node_id: String,
+    pub approved: bool,
+    /// 결정한 사람 (제한 시간 초과로 결정되면 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decided_by: Option<String>,
+    pub decided_at: DateTime<Utc>,
+    #[serde(default)]
+    pub timed_out: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl WorkflowRun {
+    /// 승인 대기 중인 실행에 결정 적용
+    ///
+    /// 승인하면 `running`으로 돌아가 `next` 노드를 실행 대기열에 넣고, 거부하면
+    /// `cancelled`가 된다. 결정은 승인 노드의 출력과 `approvals`에 기록된다.
+    pub fn decide(
+        &mut self,
+        approved: bool,
+        decided_by: &str,
+        comment: Option<String>,
+    ) -> Result<NodeRecord, TransitionError> {
+        self.resolve(approved, Some(decided_by.to_string()), comment, false)
+    }
+
+    /// 제한 시간이 지난 승인 요청을 `on_timeout`에 따라 처리
+    ///
+    /// 대기 중인 요청이 없거나 아직 제한 시간 전이면 None.
+    pub fn expire_approval(&mut self, now: DateTime<Utc>) -> Option<NodeRecord> {
+        let request = self.approval.as_ref().filter(|r| r.is_expired(now))?;
+        let approved = request.on_timeout == ApprovalDefault::Approve;
+        self.resolve(approved, None, None, true).ok()
+    }
+
+    fn resolve(
+        &mut self,
+        approved: bool,
+        decided_by: Option<String>,
+        comment: Option<String>,
+        timed_out: bool,
+    ) -> Result<NodeRecord, TransitionError> {
+        let transition = match (approved, timed_out) {
+            (true, _) => Transition::Approve,
+            (false, false) => Transition::Reject,
+            (false, true) => Transition::Expire,
+        };
+        let from = self.status;
+        if from != RunStatus::WaitingApproval || self.approval.is_none() {
+            return Err(TransitionError { from, transition });
+        }
+        self.transition(transition)?;
+        let request = self.approval.take().expect("checked above");
+
+        let record = ApprovalRecord {
+            node_id: request.node_id.clone(),
+            approved,
+            decided_by,
+            decided_at: Utc::now(),
+            timed_out,
+            comment,
+        };
+        let output = serde_json::to_value(&record).unwrap_or(Value::Null);
+        self.outputs.insert(request.node_id.clone(), output.clone());
+
+        let error = match (approved, timed_out) {
+            (true, _) => {
+                self.queue.extend(request.next);
+                None
+            }
+            (false, false) => Some(format!(
+                "approval for node '{}' rejected by {}",
+                request.node_id,
+                record.decided_by.as_deref().unwrap_or("unknown")
+            )),
+            (false, true) => {
+                // 재시도하면 승인을 다시 요청
+                self.failed_node = Some(request.node_id.clone());
+                Some(format!("approval for node '{}' timed out", request.node_id))
+            }
+        };
+        self.error = error.clone();
+        self.approvals.push(record.clone());
+
+        Ok(NodeRecord {
+            node_id: request.node_id,
+            node_type: NodeType::Approval,
+            state: if approved {
+                NodeState::Succeeded
+            } else {
+                NodeState::Failed
+            },
+            input: None,
+            output: Some(output),
+            error,
+            started_at: record.decided_at,
+            duration_ms: (record.decided_at - request.requested_at)
+                .num_milliseconds()
+                .max(0) as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use triflow_rules::RuleEngine;
+
+    use super::*;
+    use crate::actions::ActionRegistry;
+    use crate::dsl::WorkflowDsl;
+    use crate::executor::WorkflowExecutor;
+
+    fn workflow(approval_config: Value) -> WorkflowDsl {
+        serde_json::from_value(json!({
+            "name": "line_stop",
+            "trigger": {"type": "manual"},
+            "nodes": [
+                {"id": "confirm", "type": "approval", "config": approval_config,
+                 "next": ["stop"]},
+                {"id": "stop", "type": "action", "config": {"action": "stop_production_line"}},
+            ],
+        }))
+        .unwrap()
+    }
+
+    /// 승인 노드에서 멈춘 실행
+    fn waiting_run(dsl: &WorkflowDsl) -> WorkflowRun {
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let run = WorkflowExecutor::new(&engine, &actions).run(dsl, "run-1", json!({}), |_| ());
+        assert_eq!(run.status, RunStatus::WaitingApproval);
+        run
+    }
+
+    fn resume(dsl: &WorkflowDsl, run: &mut WorkflowRun) {
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let executor = WorkflowExecutor::new(&engine, &actions);
+        while executor.step(dsl, run, &mut |_| ()) {}
+    }
+
+    #[test]
+    fn approve_continues_to_next_nodes() {
+        let dsl = workflow(json!({"message": "LINE_A 정지 승인"}));
+        let mut run = waiting_run(&dsl);
+        assert_eq!(run.approval.as_ref().unwrap().message, "LINE_A 정지 승인");
+
+        let record = run.decide(true, "kim", Some("확인함".to_string())).unwrap();
+        assert_eq!(record.node_id, "confirm");
+        assert_eq!(record.state, NodeState::Succeeded);
+        assert_eq!(run.status, RunStatus::Running);
+        assert!(run.approval.is_none());
+        assert_eq!(run.approvals.len(), 1);
+        assert_eq!(run.approvals[0].decided_by.as_deref(), Some("kim"));
+        assert_eq!(run.outputs["confirm"]["comment"], json!("확인함"));
+
+        resume(&dsl, &mut run);
+        assert_eq!(run.status, RunStatus::Completed);
+        assert!(run.outputs.contains_key("stop"));
+    }
+
+    #[test]
+    fn reject_cancels_the_run() {
+        let dsl = workflow(json!({}));
+        let mut run = waiting_run(&dsl);
+
+        let record = run.decide(false, "kim", None).unwrap();
+        assert_eq!(record.state, NodeState::Failed);
+        assert_eq!(run.status, RunStatus::Cancelled);
+        assert_eq!(
+            run.error.as_deref(),
+            Some("approval for node 'confirm' rejected by kim")
+        );
+        assert!(!run.approvals[0].approved);
+
+        resume(&dsl, &mut run);
+        assert!(!run.outputs.contains_key("stop"));
+    }
+
+    #[test]
+    fn expiry_follows_on_timeout() {
+        let dsl = workflow(json!({"timeout_seconds": 60}));
+        let mut run = waiting_run(&dsl);
+        let expires_at = run.approval.as_ref().unwrap().expires_at.unwrap();
+
+        // 제한 시간 전에는 그대로 대기
+        assert!(run
+            .expire_approval(expires_at - Duration::seconds(1))
+            .is_none());
+        assert_eq!(run.status, RunStatus::WaitingApproval);
+
+        let record = run.expire_approval(expires_at).unwrap();
+        assert_eq!(record.state, NodeState::Failed);
+        assert_eq!(run.status, RunStatus::TimedOut);
+        assert_eq!(run.failed_node.as_deref(), Some("confirm"));
+        assert!(run.approvals[0].timed_out);
+        assert_eq!(run.approvals[0].decided_by, None);
+
+        let dsl = workflow(json!({"timeout_seconds": 60, "on_timeout": "approve"}));
+        let mut run = waiting_run(&dsl);
+        let expires_at = run.approval.as_ref().unwrap().expires_at.unwrap();
+        run.expire_approval(expires_at).unwrap();
+        assert_eq!(run.status, RunStatus::Running);
+        resume(&dsl, &mut run);
+        assert_eq!(run.status, RunStatus::Completed);
+    }
+
+    #[test]
+    fn without_timeout_never_expires() {
+        let dsl = workflow(json!({}));
+        let mut run = waiting_run(&dsl);
+        assert!(run
+            .expire_approval(Utc::now() + Duration::days(365))
+            .is_none());
+        assert_eq!(run.status, RunStatus::WaitingApproval);
+    }
+
+    #[test]
+    fn decision_requires_waiting_run() {
+        let dsl = workflow(json!({}));
+        let mut run = WorkflowRun::new("run-1", &dsl, json!({}));
+        let err = run.decide(true, "kim", None).unwrap_err();
+        assert_eq!(err.from, RunStatus::Pending);
+        assert_eq!(err.transition, Transition::Approve);
+        assert!(run.approvals.is_empty());
+    }
+
+    #[test]
+    fn second_decision_is_rejected() {
+        let dsl = workflow(json!({}));
+        let mut run = waiting_run(&dsl);
+        run.decide(false, "kim", None).unwrap();
+
+        let err = run.decide(true, "lee", None).unwrap_err();
+        assert_eq!(err.from, RunStatus::Cancelled);
+        assert_eq!(run.status, RunStatus::Cancelled);
+        assert_eq!(run.approvals.len(), 1);
+        assert_eq!(run.approvals[0].decided_by.as_deref(), Some("kim"));
+    }
+}
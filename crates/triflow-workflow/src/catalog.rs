@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 액션 카탈로그 (`GET /workflows/actions` 응답과 동일한 형태)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActionCatalog {
+    pub actions: Vec<ActionSpec>,
+}
+
+/// 카탈로그 항목
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionSpec {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub category: String,
+    /// 파라미터 이름 → 설명
+    #[serde(default)]
+    pub parameters: BTreeMap<String, String>,
+}
+
+impl ActionCatalog {
+    /// 액션 이름 목록으로 카탈로그 생성
+    pub fn from_names<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            actions: names
+                .into_iter()
+                .map(|name| ActionSpec {
+                    name: name.into(),
+                    description: String::new(),
+                    category: String::new(),
+                    parameters: BTreeMap::new(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ActionSpec> {
+        self.actions.iter().find(|a| a.name == name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+}
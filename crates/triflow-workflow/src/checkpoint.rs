@@ -0,0 +1,90 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::dsl::WorkflowDsl;
+use crate::executor::WorkflowRun;
+
+/// 노드 경계 체크포인트 (정의 + 실행 상태)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub dsl: WorkflowDsl,
+    pub run: WorkflowRun,
+}
+
+/// 실행 ID별 JSON 파일로 체크포인트 저장
+///
+/// 임시 파일에 쓴 뒤 이름을 바꾸므로 저장 중 종료되어도 이전 체크포인트는 남는다.
+#[derive(Debug, Clone)]
+pub struct CheckpointStore {
+    dir: PathBuf,
+}
+
+impl CheckpointStore {
+    /// 저장 디렉터리 열기 (없으면 생성)
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn save(&self, checkpoint: &Checkpoint) -> io::Result<()> {
+        let path = self.path(&checkpoint.run.run_id)?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec(checkpoint)?)?;
+        fs::rename(tmp, path)
+    }
+
+    pub fn load(&self, run_id: &str) -> io::Result<Option<Checkpoint>> {
+        match fs::read(self.path(run_id)?) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 저장된 모든 체크포인트 (읽을 수 없는 파일은 건너뜀)
+    pub fn load_all(&self) -> io::Result<Vec<Checkpoint>> {
+        let mut checkpoints = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Some(checkpoint) = fs::read(&path)
+                .ok()
+                .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            {
+                checkpoints.push(checkpoint);
+            }
+        }
+        Ok(checkpoints)
+    }
+
+    pub fn remove(&self, run_id: &str) -> io::Result<()> {
+        match fs::remove_file(self.path(run_id)?) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self, run_id: &str) -> io::Result<PathBuf> {
+        let valid = !run_id.is_empty()
+            && run_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid run id '{run_id}'"),
+            ));
+        }
+        Ok(self.dir.join(format!("{run_id}.json")))
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// 워크플로우 정의 (백엔드 `WorkflowDSL`과 동일한 형태)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowDsl {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub trigger: Trigger,
+    pub nodes: Vec<Node>,
+}
+
+/// 트리거 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerType {
+    Event,
+    Schedule,
+    Manual,
+}
+
+/// 워크플로우 트리거
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trigger {
+    #[serde(rename = "type")]
+    pub trigger_type: TriggerType,
+    #[serde(default)]
+    pub config: Map<String, Value>,
+}
+
+/// 노드 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeType {
+    /// `config.condition` 식이 참이면 `next`로 진행
+    Condition,
+    /// `config.action`을 `config.parameters`로 실행
+    Action,
+    /// 사람이 승인할 때까지 실행을 멈춤 (`config.message`, `config.timeout_seconds`, `config.on_timeout`)
+    Approval,
+    /// 지원하지 않는 노드 종류 (검증 시 오류로 보고)
+    #[serde(other)]
+    Unknown,
+}
+
+/// 워크플로우 노드
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub node_type: NodeType,
+    #[serde(default)]
+    pub config: Map<String, Value>,
+    #[serde(default)]
+    pub next: Vec<String>,
+}
+
+impl Node {
+    /// 조건 노드의 조건식 (`config.condition`)
+    pub fn condition(&self) -> Option<&str> {
+        self.config_str("condition")
+    }
+
+    /// 액션 노드의 액션 이름 (`config.action`)
+    pub fn action(&self) -> Option<&str> {
+        self.config_str("action")
+    }
+
+    /// 액션 파라미터 (`config.parameters`, 없으면 빈 객체)
+    pub fn parameters(&self) -> Value {
+        self.config
+            .get("parameters")
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Map::new()))
+    }
+
+    fn config_str(&self, key: &str) -> Option<&str> {
+        self.config
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|s| !s.trim().is_empty())
+    }
+}
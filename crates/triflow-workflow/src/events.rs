@@ -0,0 +1,486 @@
+//! 이벤트 트리거
+//!
+//! 센서 어댑터가 [`EventBus::publish`]로 보낸 센서 이벤트를 `trigger.type == "event"`
+//! 워크플로우의 필터와 비교해, 일치하면 백엔드를 거치지 않고 바로 실행한다.
+//!
+//! ```json
+//! {
+//!   "type": "event",
+//!   "config": {
+//!     "line_code": ["LINE_A", "LINE_B"],
+//!     "sensor_type": "temperature",
+//!     "filter": "value > 80.0",
+//!     "debounce_ms": 30000
+//!   }
+//! }
+//! ```
+//!
+//! `line_code`/`sensor_type`는 문자열 또는 목록이며 생략하면 모두 허용한다.
+//! `filter`는 조건 노드와 같은 식으로, 이벤트 필드를 변수로 사용할 수 있다.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use triflow_rules::RuleEngine;
+
+use crate::dsl::{TriggerType, WorkflowDsl};
+
+/// 중복 확인을 위해 기억하는 최근 이벤트 수
+pub const DEDUPE_CAPACITY: usize = 4_096;
+
+/// 센서 이벤트 (`core.sensor_data` 행과 같은 필드)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SensorEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensor_id: Option<String>,
+    pub line_code: String,
+    pub sensor_type: String,
+    pub value: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// 측정 시각 (생략하면 수신 시각)
+    #[serde(default = "Utc::now")]
+    pub recorded_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub metadata: Map<String, Value>,
+}
+
+/// 문자열 하나 또는 목록
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::One(expected) => expected == value,
+            Self::Many(expected) => expected.iter().any(|e| e == value),
+        }
+    }
+}
+
+/// 이벤트 트리거 설정 (`trigger.config`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_code: Option<OneOrMany>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensor_type: Option<OneOrMany>,
+    /// 이벤트 필드로 평가하는 조건식 (예: `value > 80.0`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// 같은 라인/센서에서 다시 실행하기까지의 최소 간격
+    #[serde(default)]
+    pub debounce_ms: u64,
+}
+
+/// 이벤트 트리거 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록
+/// `{"kind": ..., "detail": ...}` 형태로 직렬화된다.
+#[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum EventError {
+    #[error("workflow trigger is not an event")]
+    NotEventTriggered,
+
+    #[error("invalid event trigger config: {0}")]
+    InvalidConfig(String),
+
+    #[error("invalid event filter '{expression}': {message}")]
+    InvalidFilter { expression: String, message: String },
+}
+
+impl EventFilter {
+    /// 워크플로우 트리거에서 이벤트 필터 추출
+    pub fn from_dsl(dsl: &WorkflowDsl) -> Result<Self, EventError> {
+        if dsl.trigger.trigger_type != TriggerType::Event {
+            return Err(EventError::NotEventTriggered);
+        }
+        serde_json::from_value(dsl.trigger.config.clone().into())
+            .map_err(|e| EventError::InvalidConfig(e.to_string()))
+    }
+
+    /// 이벤트가 필터와 일치하는지 확인 (필터식 평가 오류는 불일치로 처리)
+    pub fn matches(&self, engine: &RuleEngine, event: &SensorEvent, fields: &Value) -> bool {
+        self.matches_source(event) && self.matches_fields(engine, fields)
+    }
+
+    /// 라인/센서 종류 조건만 확인
+    fn matches_source(&self, event: &SensorEvent) -> bool {
+        let line_ok = self
+            .line_code
+            .as_ref()
+            .is_none_or(|l| l.matches(&event.line_code));
+        let sensor_ok = self
+            .sensor_type
+            .as_ref()
+            .is_none_or(|s| s.matches(&event.sensor_type));
+        line_ok && sensor_ok
+    }
+
+    /// 필터식만 평가
+    fn matches_fields(&self, engine: &RuleEngine, fields: &Value) -> bool {
+        match &self.filter {
+            Some(filter) => engine.evaluate_condition(filter, fields).unwrap_or(false),
+            None => true,
+        }
+    }
+}
+
+/// 등록된 이벤트 구독
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventSubscription {
+    pub workflow_id: String,
+    pub dsl: WorkflowDsl,
+    pub filter: EventFilter,
+    pub last_fired: Option<DateTime<Utc>>,
+}
+
+/// 이벤트에 따른 실행 요청
+#[derive(Debug, Clone, Copy)]
+pub struct EventTrigger<'a> {
+    pub workflow_id: &'a str,
+    pub dsl: &'a WorkflowDsl,
+    pub event: &'a SensorEvent,
+}
+
+/// 동기화 중 구독하지 못한 워크플로우
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventRejection {
+    pub workflow_id: String,
+    pub error: EventError,
+}
+
+/// 이벤트 구독 동기화 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventSyncReport {
+    pub subscribed: Vec<EventSubscription>,
+    pub rejected: Vec<EventRejection>,
+}
+
+/// 이벤트 발행 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum PublishOutcome {
+    /// 이미 받은 이벤트라 무시함
+    Duplicate,
+    /// 실행을 시작한 워크플로우와 디바운스로 건너뛴 워크플로우
+    Dispatched {
+        started: Vec<String>,
+        debounced: Vec<String>,
+    },
+}
+
+type FireFn = dyn Fn(EventTrigger<'_>) + Send + Sync;
+
+/// 프로세스 내 센서 이벤트 버스
+///
+/// 발행한 스레드에서 바로 필터를 평가하고 `fire`를 호출하므로, `fire`는
+/// 실행을 다른 스레드로 넘기고 곧바로 반환해야 한다.
+#[derive(Clone)]
+pub struct EventBus {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    engine: Arc<RuleEngine>,
+    subscriptions: Mutex<BTreeMap<String, EventSubscription>>,
+    /// 디바운스 기준 시각 (워크플로우, 라인, 센서 종류별)
+    debounce: Mutex<BTreeMap<(String, String, String), DateTime<Utc>>>,
+    recent: Mutex<RecentEvents>,
+    fire: Box<FireFn>,
+}
+
+/// 최근 이벤트 키 (오래된 것부터 제거)
+#[derive(Default)]
+struct RecentEvents {
+    order: VecDeque<String>,
+    keys: HashSet<String>,
+}
+
+impl RecentEvents {
+    /// 처음 보는 키면 기억하고 true
+    fn insert(&mut self, key: String) -> bool {
+        if !self.keys.insert(key.clone()) {
+            return false;
+        }
+        self.order.push_back(key);
+        if self.order.len() > DEDUPE_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+impl EventBus {
+    pub fn new(
+        engine: Arc<RuleEngine>,
+        fire: impl Fn(EventTrigger<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                engine,
+                subscriptions: Mutex::new(BTreeMap::new()),
+                debounce: Mutex::new(BTreeMap::new()),
+                recent: Mutex::new(RecentEvents::default()),
+                fire: Box::new(fire),
+            }),
+        }
+    }
+
+    /// 워크플로우 구독 등록 또는 갱신
+    pub fn subscribe(
+        &self,
+        workflow_id: &str,
+        dsl: WorkflowDsl,
+    ) -> Result<EventSubscription, EventError> {
+        let mut subscriptions = lock(&self.inner.subscriptions);
+        self.inner.upsert(&mut subscriptions, workflow_id, dsl)
+    }
+
+    /// 구독 해제
+    pub fn unsubscribe(&self, workflow_id: &str) -> bool {
+        lock(&self.inner.subscriptions)
+            .remove(workflow_id)
+            .is_some()
+    }
+
+    /// 워크플로우 목록과 동기화
+    ///
+    /// 이벤트 트리거인 워크플로우는 구독하고, 목록에 없거나 이벤트 트리거가
+    /// 아닌 워크플로우의 구독은 해제한다.
+    pub fn sync(&self, workflows: Vec<(String, WorkflowDsl)>) -> EventSyncReport {
+        let mut report = EventSyncReport {
+            subscribed: Vec::new(),
+            rejected: Vec::new(),
+        };
+        let mut subscriptions = lock(&self.inner.subscriptions);
+        let mut keep = Vec::new();
+        for (workflow_id, dsl) in workflows {
+            match self.inner.upsert(&mut subscriptions, &workflow_id, dsl) {
+                Ok(subscription) => {
+                    keep.push(workflow_id);
+                    report.subscribed.push(subscription);
+                }
+                Err(EventError::NotEventTriggered) => (),
+                Err(error) => report.rejected.push(EventRejection { workflow_id, error }),
+            }
+        }
+        subscriptions.retain(|id, _| keep.contains(id));
+        report
+    }
+
+    /// 등록된 구독 목록
+    pub fn subscriptions(&self) -> Vec<EventSubscription> {
+        lock(&self.inner.subscriptions).values().cloned().collect()
+    }
+
+    /// 센서 이벤트 발행
+    ///
+    /// 같은 이벤트(센서, 라인, 종류, 시각, 값이 모두 같음)가 다시 들어오면 무시한다.
+    /// 필터와 일치해도 `debounce_ms` 안에 같은 라인/센서로 실행한 워크플로우는
+    /// 건너뛴다 (기준은 이벤트의 `recorded_at`).
+    pub fn publish(&self, event: SensorEvent) -> PublishOutcome {
+        if !lock(&self.inner.recent).insert(dedupe_key(&event)) {
+            return PublishOutcome::Duplicate;
+        }
+
+        let fields = serde_json::to_value(&event).unwrap_or(Value::Null);
+        // 필터식은 잠금 밖에서 평가 (평가 중에도 구독 변경/다른 발행을 막지 않도록)
+        let mut matched: Vec<EventSubscription> = lock(&self.inner.subscriptions)
+            .values()
+            .filter(|s| s.filter.matches_source(&event))
+            .cloned()
+            .collect();
+        matched.retain(|s| s.filter.matches_fields(&self.inner.engine, &fields));
+
+        let mut started = Vec::new();
+        let mut debounced = Vec::new();
+        for subscription in matched {
+            if self.inner.debounced(&subscription, &event) {
+                debounced.push(subscription.workflow_id);
+                continue;
+            }
+            if let Some(s) = lock(&self.inner.subscriptions).get_mut(&subscription.workflow_id) {
+                s.last_fired = Some(event.recorded_at);
+            }
+            (self.inner.fire)(EventTrigger {
+                workflow_id: &subscription.workflow_id,
+                dsl: &subscription.dsl,
+                event: &event,
+            });
+            started.push(subscription.workflow_id);
+        }
+        PublishOutcome::Dispatched { started, debounced }
+    }
+}
+
+impl Inner {
+    fn upsert(
+        &self,
+        subscriptions: &mut BTreeMap<String, EventSubscription>,
+        workflow_id: &str,
+        dsl: WorkflowDsl,
+    ) -> Result<EventSubscription, EventError> {
+        let filter = EventFilter::from_dsl(&dsl)?;
+        if let Some(expression) = &filter.filter {
+            self.engine
+                .compile_expression(expression)
+                .map_err(|e| EventError::InvalidFilter {
+                    expression: expression.clone(),
+                    message: e.to_string(),
+                })?;
+        }
+
+        let last_fired = subscriptions.get(workflow_id).and_then(|s| s.last_fired);
+        let subscription = EventSubscription {
+            workflow_id: workflow_id.to_string(),
+            dsl,
+            filter,
+            last_fired,
+        };
+        subscriptions.insert(workflow_id.to_string(), subscription.clone());
+        Ok(subscription)
+    }
+
+    /// 디바운스 간격 안이면 true, 아니면 기준 시각 갱신
+    fn debounced(&self, subscription: &EventSubscription, event: &SensorEvent) -> bool {
+        if subscription.filter.debounce_ms == 0 {
+            return false;
+        }
+        let key = (
+            subscription.workflow_id.clone(),
+            event.line_code.clone(),
+            event.sensor_type.clone(),
+        );
+        let mut debounce = lock(&self.debounce);
+        if let Some(last) = debounce.get(&key) {
+            let elapsed = event.recorded_at.signed_duration_since(*last);
+            if elapsed.num_milliseconds() < subscription.filter.debounce_ms as i64 {
+                return true;
+            }
+        }
+        debounce.insert(key, event.recorded_at);
+        false
+    }
+}
+
+fn dedupe_key(event: &SensorEvent) -> String {
+    format!(
+        "{}|{}|{}|{}|{}",
+        event.sensor_id.as_deref().unwrap_or_default(),
+        event.line_code,
+        event.sensor_type,
+        event.recorded_at.timestamp_micros(),
+        event.value.to_bits(),
+    )
+}
+
+/// poison된 잠금도 복구해서 사용
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn workflow(config: Value) -> WorkflowDsl {
+        serde_json::from_value(json!({
+            "name": "test",
+            "trigger": {"type": "event", "config": config},
+            "nodes": [{"id": "log", "type": "action", "config": {"action": "log"}}],
+        }))
+        .unwrap()
+    }
+
+    fn event(line_code: &str, value: f64, second: u32) -> SensorEvent {
+        serde_json::from_value(json!({
+            "line_code": line_code,
+            "sensor_type": "temperature",
+            "value": value,
+            "recorded_at": format!("2026-01-01T00:00:{second:02}Z"),
+        }))
+        .unwrap()
+    }
+
+    fn bus() -> (EventBus, Arc<Mutex<Vec<String>>>) {
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&fired);
+        let bus = EventBus::new(Arc::new(RuleEngine::new()), move |t: EventTrigger<'_>| {
+            lock(&sink).push(t.workflow_id.to_string())
+        });
+        (bus, fired)
+    }
+
+    #[test]
+    fn rejects_filters_that_are_not_expressions() {
+        let (bus, _) = bus();
+        for filter in ["let hot = value > 80.0; hot", "value >"] {
+            let err = bus
+                .subscribe("wf", workflow(json!({"filter": filter})))
+                .unwrap_err();
+            assert!(
+                matches!(&err, EventError::InvalidFilter { expression, .. } if expression == filter),
+                "{err:?}"
+            );
+        }
+        assert!(bus.subscriptions().is_empty());
+        assert!(bus
+            .subscribe("wf", workflow(json!({"filter": "value > 80.0"})))
+            .is_ok());
+    }
+
+    #[test]
+    fn publish_fires_matching_workflows() {
+        let (bus, fired) = bus();
+        bus.subscribe(
+            "hot",
+            workflow(
+                json!({"line_code": ["LINE_A"], "filter": "value > 80.0", "debounce_ms": 30000}),
+            ),
+        )
+        .unwrap();
+        bus.subscribe("any", workflow(json!({"sensor_type": "temperature"})))
+            .unwrap();
+
+        let outcome = bus.publish(event("LINE_A", 90.0, 0));
+        assert_eq!(
+            outcome,
+            PublishOutcome::Dispatched {
+                started: vec!["any".into(), "hot".into()],
+                debounced: vec![],
+            }
+        );
+        assert_eq!(
+            bus.publish(event("LINE_A", 90.0, 0)),
+            PublishOutcome::Duplicate
+        );
+        assert_eq!(
+            bus.publish(event("LINE_A", 95.0, 10)),
+            PublishOutcome::Dispatched {
+                started: vec!["any".into()],
+                debounced: vec!["hot".into()],
+            }
+        );
+        assert_eq!(
+            bus.publish(event("LINE_B", 95.0, 20)),
+            PublishOutcome::Dispatched {
+                started: vec!["any".into()],
+                debounced: vec![],
+            }
+        );
+        assert_eq!(lock(&fired).len(), 4);
+    }
+}
@@ -0,0 +1,554 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use triflow_rules::RuleEngine;
+
+use crate::actions::{ActionCall, ActionRegistry};
+use crate::approval::{ApprovalConfig, ApprovalRecord, ApprovalRequest};
+use crate::dsl::{Node, NodeType, WorkflowDsl};
+use crate::state::{RunStatus, Transition, TransitionError};
+
+/// 한 실행에서 처리할 수 있는 최대 노드 수 (조건 순환 방지)
+pub const DEFAULT_MAX_STEPS: usize = 1_000;
+
+/// 노드 실행 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeState {
+    Running,
+    Succeeded,
+    Failed,
+    /// 앞선 조건이 거짓이라 실행하지 않음
+    Skipped,
+    /// 승인 노드에서 결정을 기다리는 중
+    WaitingApproval,
+}
+
+/// 노드 실행 기록
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeRecord {
+    pub node_id: String,
+    pub node_type: NodeType,
+    pub state: NodeState,
+    /// 노드가 받은 변수 (실행 이력 저장용, 이벤트/체크포인트에는 포함하지 않음)
+    #[serde(skip)]
+    pub input: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+}
+
+/// 노드 진행 이벤트 (웹뷰로 전달)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeEvent<'a> {
+    pub run_id: &'a str,
+    #[serde(flatten)]
+    pub node: &'a NodeRecord,
+}
+
+/// 워크플로우 실행 인스턴스
+///
+/// 다음에 실행할 노드 큐와 노드 출력을 모두 담고 있어 직렬화해 두었다가
+/// 이어서 실행할 수 있다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub run_id: String,
+    pub workflow_name: String,
+    pub status: RunStatus,
+    pub input: Value,
+    /// 노드 ID → 출력
+    pub outputs: Map<String, Value>,
+    /// 실행 대기 중인 노드 ID
+    pub queue: VecDeque<String>,
+    pub history: Vec<NodeRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 실패한 노드 (재시도 시 다시 실행)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_node: Option<String>,
+    /// 대기 중인 승인 요청 (`waiting_approval` 상태일 때)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval: Option<ApprovalRequest>,
+    /// 승인 결정 기록 (누가, 언제)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvals: Vec<ApprovalRecord>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorkflowRun {
+    /// 첫 번째 노드부터 실행하는 인스턴스 생성
+    pub fn new(run_id: impl Into<String>, dsl: &WorkflowDsl, input: Value) -> Self {
+        Self {
+            run_id: run_id.into(),
+            workflow_name: dsl.name.clone(),
+            status: RunStatus::Pending,
+            input,
+            outputs: Map::new(),
+            queue: dsl
+                .nodes
+                .first()
+                .map(|n| n.id.clone())
+                .into_iter()
+                .collect(),
+            history: Vec::new(),
+            error: None,
+            failed_node: None,
+            approval: None,
+            approvals: Vec::new(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// 상태 전이 (허용되지 않으면 상태를 바꾸지 않고 오류)
+    pub fn transition(&mut self, transition: Transition) -> Result<RunStatus, TransitionError> {
+        self.status = self.status.apply(transition)?;
+        self.updated_at = Utc::now();
+        Ok(self.status)
+    }
+
+    /// 실패한 노드부터 다시 실행하도록 전환
+    pub fn retry(&mut self) -> Result<RunStatus, TransitionError> {
+        let status = self.transition(Transition::Retry)?;
+        if let Some(node_id) = self.failed_node.take() {
+            self.queue.push_front(node_id);
+        }
+        self.error = None;
+        Ok(status)
+    }
+
+    /// 조건식/액션에 제공하는 변수 (입력 필드 + 노드 ID별 출력)
+    pub fn context(&self) -> Value {
+        let mut context = match &self.input {
+            Value::Object(fields) => fields.clone(),
+            _ => Map::new(),
+        };
+        context.extend(self.outputs.clone());
+        Value::Object(context)
+    }
+
+    /// 실행 중인 인스턴스를 실패로 전환
+    fn fail(&mut self, node_id: Option<&str>, error: String) {
+        if self.transition(Transition::Fail).is_ok() {
+            self.failed_node = node_id.map(str::to_string);
+            self.error = Some(error);
+        }
+    }
+}
+
+/// 워크플로우 실행기
+///
+/// 조건 노드는 룰 엔진으로 평가하고, 액션 노드는 등록된 핸들러로 실행한다.
+/// 조건이 거짓이면 그 노드의 `next`는 실행하지 않는다. 승인 노드에 도달하면
+/// `waiting_approval` 상태로 멈추며, [`WorkflowRun::decide`] 후 다시 진행한다.
+pub struct WorkflowExecutor<'a> {
+    engine: &'a RuleEngine,
+    actions: &'a ActionRegistry,
+    max_steps: usize,
+}
+
+impl<'a> WorkflowExecutor<'a> {
+    pub fn new(engine: &'a RuleEngine, actions: &'a ActionRegistry) -> Self {
+        Self {
+            engine,
+            actions,
+            max_steps: DEFAULT_MAX_STEPS,
+        }
+    }
+
+    /// 최대 실행 노드 수 지정
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// 처음부터 끝까지 실행
+    pub fn run(
+        &self,
+        dsl: &WorkflowDsl,
+        run_id: impl Into<String>,
+        input: Value,
+        mut on_event: impl FnMut(&NodeEvent<'_>),
+    ) -> WorkflowRun {
+        let mut run = WorkflowRun::new(run_id, dsl, input);
+        while self.step(dsl, &mut run, &mut on_event) {}
+        run
+    }
+
+    /// 대기 중인 노드 하나 실행 (계속 호출해야 하면 true)
+    ///
+    /// `pending` 상태면 먼저 `running`으로 전환한다. 노드 경계마다 호출되므로
+    /// 호출 사이에 실행 상태를 체크포인트로 저장할 수 있다. 큐가 비어 있으면
+    /// `completed`로 전환하고 false를 반환한다.
+    pub fn step(
+        &self,
+        dsl: &WorkflowDsl,
+        run: &mut WorkflowRun,
+        on_event: &mut impl FnMut(&NodeEvent<'_>),
+    ) -> bool {
+        if run.status == RunStatus::Pending {
+            let _ = run.transition(Transition::Start);
+        }
+        if run.status != RunStatus::Running {
+            return false;
+        }
+        // 완료도 노드 경계로 다룬다: 마지막 노드 실행 중에 요청된 일시정지/취소가
+        // 완료보다 먼저 적용될 수 있도록 한 번 더 호출되어야 완료된다
+        let Some(node_id) = run.queue.pop_front() else {
+            let _ = run.transition(Transition::Complete);
+            return false;
+        };
+        let Some(node) = dsl.nodes.iter().find(|n| n.id == node_id) else {
+            run.fail(None, format!("node '{node_id}' does not exist"));
+            return false;
+        };
+        let executed = run
+            .history
+            .iter()
+            .filter(|r| r.state != NodeState::Skipped)
+            .count();
+        if executed >= self.max_steps {
+            run.queue.push_front(node_id);
+            run.fail(None, format!("step limit exceeded ({})", self.max_steps));
+            return false;
+        }
+
+        let started_at = Utc::now();
+        let mut record = NodeRecord {
+            node_id: node.id.clone(),
+            node_type: node.node_type,
+            state: NodeState::Running,
+            input: Some(run.context()),
+            output: None,
+            error: None,
+            started_at,
+            duration_ms: 0,
+        };
+        on_event(&NodeEvent {
+            run_id: &run.run_id,
+            node: &record,
+        });
+
+        let timer = Instant::now();
+        let result = self.execute_node(node, run);
+        record.duration_ms = timer.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(NodeOutcome::Continue(output)) => {
+                record.state = NodeState::Succeeded;
+                record.output = Some(output.clone());
+                if node.node_type == NodeType::Action {
+                    run.outputs.insert(node.id.clone(), output);
+                }
+                run.queue.extend(node.next.iter().cloned());
+                self.finish(run, record, on_event);
+            }
+            Ok(NodeOutcome::Stop(output)) => {
+                record.state = NodeState::Succeeded;
+                record.output = Some(output);
+                self.finish(run, record, on_event);
+                for next in &node.next {
+                    self.skip(dsl, run, next, on_event);
+                }
+            }
+            Ok(NodeOutcome::Suspend(request)) => {
+                record.state = NodeState::WaitingApproval;
+                record.output = serde_json::to_value(&request).ok();
+                run.approval = Some(request);
+                let _ = run.transition(Transition::RequestApproval);
+                self.finish(run, record, on_event);
+                return false;
+            }
+            Err(error) => {
+                record.state = NodeState::Failed;
+                record.error = Some(error.clone());
+                self.finish(run, record, on_event);
+                run.fail(
+                    Some(&node.id),
+                    format!("node '{}' failed: {error}", node.id),
+                );
+                return false;
+            }
+        }
+
+        run.updated_at = Utc::now();
+        true
+    }
+
+    fn execute_node(&self, node: &Node, run: &WorkflowRun) -> Result<NodeOutcome, String> {
+        match node.node_type {
+            NodeType::Condition => {
+                let expression = node
+                    .condition()
+                    .ok_or_else(|| "missing condition expression".to_string())?;
+                let passed = self
+                    .engine
+                    .evaluate_condition(expression, &run.context())
+                    .map_err(|e| e.to_string())?;
+                let output = json!({ "result": passed });
+                Ok(if passed {
+                    NodeOutcome::Continue(output)
+                } else {
+                    NodeOutcome::Stop(output)
+                })
+            }
+            NodeType::Action => {
+                let action = node
+                    .action()
+                    .ok_or_else(|| "missing action name".to_string())?;
+                let handler = self
+                    .actions
+                    .get(action)
+                    .ok_or_else(|| format!("no handler for action '{action}'"))?;
+                let parameters = node.parameters();
+                let context = run.context();
+                handler
+                    .handle(&ActionCall {
+                        run_id: &run.run_id,
+                        node_id: &node.id,
+                        action,
+                        parameters: &parameters,
+                        context: &context,
+                    })
+                    .map(NodeOutcome::Continue)
+                    .map_err(|e| e.to_string())
+            }
+            NodeType::Approval => {
+                let config = ApprovalConfig::from_node(node)
+                    .map_err(|e| format!("invalid approval config: {e}"))?;
+                Ok(NodeOutcome::Suspend(ApprovalRequest::new(
+                    node,
+                    &config,
+                    &run.workflow_name,
+                )))
+            }
+            NodeType::Unknown => Err("unsupported node type".to_string()),
+        }
+    }
+
+    fn skip(
+        &self,
+        dsl: &WorkflowDsl,
+        run: &mut WorkflowRun,
+        node_id: &str,
+        on_event: &mut impl FnMut(&NodeEvent<'_>),
+    ) {
+        let node_type = dsl
+            .nodes
+            .iter()
+            .find(|n| n.id == node_id)
+            .map_or(NodeType::Unknown, |n| n.node_type);
+        let record = NodeRecord {
+            node_id: node_id.to_string(),
+            node_type,
+            state: NodeState::Skipped,
+            input: None,
+            output: None,
+            error: None,
+            started_at: Utc::now(),
+            duration_ms: 0,
+        };
+        self.finish(run, record, on_event);
+    }
+
+    fn finish(
+        &self,
+        run: &mut WorkflowRun,
+        record: NodeRecord,
+        on_event: &mut impl FnMut(&NodeEvent<'_>),
+    ) {
+        on_event(&NodeEvent {
+            run_id: &run.run_id,
+            node: &record,
+        });
+        run.history.push(record);
+    }
+}
+
+enum NodeOutcome {
+    /// `next` 노드로 진행
+    Continue(Value),
+    /// 조건 거짓: `next` 노드는 건너뜀
+    Stop(Value),
+    /// 승인 대기: 결정이 내려질 때까지 실행을 멈춤
+    Suspend(ApprovalRequest),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::ActionError;
+
+    fn workflow(nodes: Value) -> WorkflowDsl {
+        serde_json::from_value(json!({
+            "name": "test",
+            "trigger": {"type": "manual"},
+            "nodes": nodes,
+        }))
+        .unwrap()
+    }
+
+    fn states(run: &WorkflowRun) -> Vec<(&str, NodeState)> {
+        run.history
+            .iter()
+            .map(|r| (r.node_id.as_str(), r.state))
+            .collect()
+    }
+
+    fn branching() -> WorkflowDsl {
+        workflow(json!([
+            {"id": "check", "type": "condition", "config": {"condition": "temperature > 80"},
+             "next": ["alert"]},
+            {"id": "alert", "type": "action",
+             "config": {"action": "notify", "parameters": {"channel": "ops"}},
+             "next": ["log"]},
+            {"id": "log", "type": "action", "config": {"action": "notify"}},
+        ]))
+    }
+
+    #[test]
+    fn true_condition_runs_next_nodes() {
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let mut events = Vec::new();
+        let run = WorkflowExecutor::new(&engine, &actions).run(
+            &branching(),
+            "run-1",
+            json!({"temperature": 90}),
+            |e| events.push((e.node.node_id.clone(), e.node.state)),
+        );
+
+        assert_eq!(run.status, RunStatus::Completed);
+        assert_eq!(
+            states(&run),
+            vec![
+                ("check", NodeState::Succeeded),
+                ("alert", NodeState::Succeeded),
+                ("log", NodeState::Succeeded),
+            ]
+        );
+        assert_eq!(run.history[0].output, Some(json!({"result": true})));
+        assert_eq!(
+            run.outputs["alert"]["parameters"],
+            json!({"channel": "ops"})
+        );
+        // 노드마다 시작/완료 이벤트
+        assert_eq!(events.len(), 6);
+        assert_eq!(events[0], ("check".to_string(), NodeState::Running));
+    }
+
+    #[test]
+    fn false_condition_skips_next_nodes() {
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let run = WorkflowExecutor::new(&engine, &actions).run(
+            &branching(),
+            "run-2",
+            json!({"temperature": 50}),
+            |_| (),
+        );
+
+        assert_eq!(run.status, RunStatus::Completed);
+        assert_eq!(
+            states(&run),
+            vec![
+                ("check", NodeState::Succeeded),
+                ("alert", NodeState::Skipped),
+            ]
+        );
+        assert!(run.outputs.is_empty());
+    }
+
+    #[test]
+    fn action_outputs_are_visible_to_later_conditions() {
+        let engine = RuleEngine::new();
+        let mut actions = ActionRegistry::new();
+        actions.register("measure", |_: &ActionCall<'_>| Ok(json!({"value": 7})));
+        actions.register("notify", |call: &ActionCall<'_>| Ok(call.context.clone()));
+        let dsl = workflow(json!([
+            {"id": "measure", "type": "action", "config": {"action": "measure"},
+             "next": ["check"]},
+            {"id": "check", "type": "condition", "config": {"condition": "measure.value > 5"},
+             "next": ["notify"]},
+            {"id": "notify", "type": "action", "config": {"action": "notify"}},
+        ]));
+        let run = WorkflowExecutor::new(&engine, &actions).run(&dsl, "run-3", json!({}), |_| ());
+
+        assert_eq!(run.status, RunStatus::Completed);
+        assert_eq!(run.outputs["notify"]["measure"], json!({"value": 7}));
+    }
+
+    #[test]
+    fn failed_action_fails_run_and_can_retry() {
+        let engine = RuleEngine::new();
+        let mut actions = ActionRegistry::new();
+        actions.register("notify", |_: &ActionCall<'_>| {
+            Err(ActionError("slack unavailable".into()))
+        });
+        let dsl = branching();
+        let executor = WorkflowExecutor::new(&engine, &actions);
+        let mut run = executor.run(&dsl, "run-4", json!({"temperature": 90}), |_| ());
+
+        assert_eq!(run.status, RunStatus::Failed);
+        assert_eq!(run.failed_node.as_deref(), Some("alert"));
+        assert_eq!(
+            run.error.as_deref(),
+            Some("node 'alert' failed: slack unavailable")
+        );
+        assert_eq!(
+            states(&run),
+            vec![
+                ("check", NodeState::Succeeded),
+                ("alert", NodeState::Failed),
+            ]
+        );
+        assert_eq!(run.history[1].error.as_deref(), Some("slack unavailable"));
+
+        // 재시도하면 실패한 노드부터 다시 실행
+        assert_eq!(run.retry(), Ok(RunStatus::Running));
+        assert_eq!(run.queue.front().map(String::as_str), Some("alert"));
+        assert!(!executor.step(&dsl, &mut run, &mut |_| ()));
+        assert_eq!(run.status, RunStatus::Failed);
+        assert_eq!(run.history.len(), 3);
+    }
+
+    #[test]
+    fn invalid_condition_fails_run() {
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let dsl = workflow(json!([
+            {"id": "check", "type": "condition", "config": {"condition": "temperature +"}},
+        ]));
+        let run = WorkflowExecutor::new(&engine, &actions).run(&dsl, "run-5", json!({}), |_| ());
+
+        assert_eq!(run.status, RunStatus::Failed);
+        assert_eq!(run.failed_node.as_deref(), Some("check"));
+        assert_eq!(states(&run), vec![("check", NodeState::Failed)]);
+    }
+
+    #[test]
+    fn condition_loop_stops_at_step_limit() {
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let dsl = workflow(json!([
+            {"id": "poll", "type": "action", "config": {"action": "read"}, "next": ["check"]},
+            {"id": "check", "type": "condition", "config": {"condition": "true"},
+             "next": ["poll"]},
+        ]));
+        let run = WorkflowExecutor::new(&engine, &actions).max_steps(5).run(
+            &dsl,
+            "run-6",
+            json!({}),
+            |_| (),
+        );
+
+        assert_eq!(run.status, RunStatus::Failed);
+        assert_eq!(run.history.len(), 5);
+        assert_eq!(run.error.as_deref(), Some("step limit exceeded (5)"));
+    }
+}
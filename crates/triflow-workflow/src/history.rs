@@ -0,0 +1,557 @@
+//! 실행 이력 저장소
+//!
+//! 데스크톱에서 실행한 워크플로우의 실행 기록과 노드별 시작/종료 시각, 입력,
+//! 출력, 오류를 SQLite 데이터베이스에 남긴다. [`RunObserver`]로 [`RunManager`]에
+//! 연결하면 노드 경계마다 기록되며, 워크플로우 화면은 서버 없이도 실행
+//! 타임라인을 조회할 수 있다.
+//!
+//! [`RunManager`]: crate::RunManager
+
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::types::{ToSql, Type};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::dsl::NodeType;
+use crate::executor::{NodeEvent, NodeRecord, NodeState, WorkflowRun};
+use crate::runner::RunObserver;
+use crate::state::RunStatus;
+
+/// 조회 개수를 지정하지 않았을 때의 최대 실행 수
+pub const DEFAULT_HISTORY_LIMIT: u32 = 100;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS workflow_runs (
+    run_id        TEXT PRIMARY KEY,
+    workflow_id   TEXT,
+    workflow_name TEXT NOT NULL,
+    status        TEXT NOT NULL,
+    input         TEXT NOT NULL,
+    outputs       TEXT NOT NULL,
+    error         TEXT,
+    started_at    TEXT NOT NULL,
+    updated_at    TEXT NOT NULL,
+    finished_at   TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_workflow_runs_workflow_id ON workflow_runs (workflow_id);
+CREATE INDEX IF NOT EXISTS idx_workflow_runs_workflow_name ON workflow_runs (workflow_name);
+CREATE INDEX IF NOT EXISTS idx_workflow_runs_status ON workflow_runs (status);
+CREATE INDEX IF NOT EXISTS idx_workflow_runs_started_at ON workflow_runs (started_at);
+
+CREATE TABLE IF NOT EXISTS workflow_node_runs (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id      TEXT NOT NULL,
+    node_id     TEXT NOT NULL,
+    node_type   TEXT NOT NULL,
+    state       TEXT NOT NULL,
+    input       TEXT,
+    output      TEXT,
+    error       TEXT,
+    started_at  TEXT NOT NULL,
+    finished_at TEXT NOT NULL,
+    duration_ms INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_workflow_node_runs_run_id ON workflow_node_runs (run_id);
+";
+
+/// 실행 이력 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록
+/// `{"kind": ..., "detail": ...}` 형태로 직렬화된다.
+#[derive(Debug, thiserror::Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum HistoryError {
+    #[error("history database error: {0}")]
+    Database(String),
+
+    #[error("invalid history record: {0}")]
+    InvalidRecord(String),
+}
+
+impl From<rusqlite::Error> for HistoryError {
+    fn from(err: rusqlite::Error) -> Self {
+        HistoryError::Database(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for HistoryError {
+    fn from(err: serde_json::Error) -> Self {
+        HistoryError::InvalidRecord(err.to_string())
+    }
+}
+
+/// 실행 이력 조회 조건 (모두 생략 가능, 지정한 조건은 AND)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistoryQuery {
+    /// 트리거가 넘긴 워크플로우 ID (`input.workflow_id`)
+    pub workflow_id: Option<String>,
+    pub workflow_name: Option<String>,
+    /// 이 중 하나인 실행 (비어 있으면 모든 상태)
+    pub statuses: Vec<RunStatus>,
+    /// 이 시각 이후 시작한 실행
+    pub from: Option<DateTime<Utc>>,
+    /// 이 시각 이전 시작한 실행
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// 실행 요약
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunSummary {
+    pub run_id: String,
+    pub workflow_id: Option<String>,
+    pub workflow_name: String,
+    pub status: RunStatus,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// 완료/실패/취소/시간 초과 시각 (진행 중이면 None)
+    pub finished_at: Option<DateTime<Utc>>,
+    pub node_count: u32,
+}
+
+/// 노드 실행 기록 (타임라인 한 칸)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeTiming {
+    pub node_id: String,
+    pub node_type: NodeType,
+    pub state: NodeState,
+    pub input: Option<Value>,
+    pub output: Option<Value>,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub duration_ms: u64,
+}
+
+/// 실행 타임라인
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunTimeline {
+    #[serde(flatten)]
+    pub summary: RunSummary,
+    pub input: Value,
+    pub outputs: Map<String, Value>,
+    /// 노드 기록 (끝난 순서)
+    pub nodes: Vec<NodeTiming>,
+}
+
+/// SQLite 실행 이력 저장소
+#[derive(Clone)]
+pub struct HistoryStore {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl HistoryStore {
+    /// 데이터베이스 파일 열기 (없으면 생성)
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, HistoryError> {
+        if let Some(dir) = path.as_ref().parent() {
+            std::fs::create_dir_all(dir).map_err(|e| HistoryError::Database(e.to_string()))?;
+        }
+        Self::init(Connection::open(path)?)
+    }
+
+    /// 메모리 데이터베이스 (테스트/임시 실행용)
+    pub fn in_memory() -> Result<Self, HistoryError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, HistoryError> {
+        conn.execute_batch("PRAGMA journal_mode = WAL;")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 실행 상태 저장 (처음 기록할 때의 시각을 시작 시각으로 사용)
+    pub fn record_run(&self, run: &WorkflowRun) -> Result<(), HistoryError> {
+        let workflow_id = run.input.get("workflow_id").and_then(Value::as_str);
+        let finished_at = is_finished(run.status).then(|| timestamp(&run.updated_at));
+        self.conn().execute(
+            "INSERT INTO workflow_runs
+                (run_id, workflow_id, workflow_name, status, input, outputs, error,
+                 started_at, updated_at, finished_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8, ?9)
+             ON CONFLICT (run_id) DO UPDATE SET
+                status = excluded.status,
+                outputs = excluded.outputs,
+                error = excluded.error,
+                updated_at = excluded.updated_at,
+                finished_at = excluded.finished_at",
+            params![
+                run.run_id,
+                workflow_id,
+                run.workflow_name,
+                run.status.as_str(),
+                serde_json::to_string(&run.input)?,
+                serde_json::to_string(&run.outputs)?,
+                run.error,
+                timestamp(&run.updated_at),
+                finished_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 끝난 노드 기록 추가 (`running` 기록은 무시)
+    pub fn record_node(&self, run_id: &str, record: &NodeRecord) -> Result<(), HistoryError> {
+        if record.state == NodeState::Running {
+            return Ok(());
+        }
+        let finished_at =
+            record.started_at + chrono::Duration::milliseconds(record.duration_ms as i64);
+        self.conn().execute(
+            "INSERT INTO workflow_node_runs
+                (run_id, node_id, node_type, state, input, output, error,
+                 started_at, finished_at, duration_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                run_id,
+                record.node_id,
+                enum_str(&record.node_type)?,
+                enum_str(&record.state)?,
+                record.input.as_ref().map(Value::to_string),
+                record.output.as_ref().map(Value::to_string),
+                record.error,
+                timestamp(&record.started_at),
+                timestamp(&finished_at),
+                record.duration_ms as i64,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 조건에 맞는 실행 (최근 시작 순)
+    pub fn query(&self, query: &HistoryQuery) -> Result<Vec<RunSummary>, HistoryError> {
+        let mut conditions = Vec::new();
+        let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+
+        if let Some(workflow_id) = &query.workflow_id {
+            conditions.push("r.workflow_id = ?".to_string());
+            values.push(Box::new(workflow_id.clone()));
+        }
+        if let Some(workflow_name) = &query.workflow_name {
+            conditions.push("r.workflow_name = ?".to_string());
+            values.push(Box::new(workflow_name.clone()));
+        }
+        if !query.statuses.is_empty() {
+            let placeholders = vec!["?"; query.statuses.len()].join(", ");
+            conditions.push(format!("r.status IN ({placeholders})"));
+            for status in &query.statuses {
+                values.push(Box::new(status.as_str()));
+            }
+        }
+        if let Some(from) = &query.from {
+            conditions.push("r.started_at >= ?".to_string());
+            values.push(Box::new(timestamp(from)));
+        }
+        if let Some(to) = &query.to {
+            conditions.push("r.started_at <= ?".to_string());
+            values.push(Box::new(timestamp(to)));
+        }
+        let filter = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        values.push(Box::new(query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT)));
+        values.push(Box::new(query.offset.unwrap_or(0)));
+
+        let sql = format!(
+            "SELECT {SUMMARY_COLUMNS} FROM workflow_runs r {filter}
+             ORDER BY r.started_at DESC LIMIT ? OFFSET ?"
+        );
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(values.iter()), summary_from_row)?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    /// 실행 하나의 타임라인 (없으면 None)
+    pub fn timeline(&self, run_id: &str) -> Result<Option<RunTimeline>, HistoryError> {
+        let conn = self.conn();
+        let run = conn
+            .query_row(
+                &format!(
+                    "SELECT {SUMMARY_COLUMNS}, r.input, r.outputs
+                     FROM workflow_runs r WHERE r.run_id = ?1"
+                ),
+                [run_id],
+                |row| {
+                    Ok((
+                        summary_from_row(row)?,
+                        get_json(row, 9)?,
+                        get_json(row, 10)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((summary, input, outputs)) = run else {
+            return Ok(None);
+        };
+
+        let mut stmt = conn.prepare(
+            "SELECT node_id, node_type, state, input, output, error,
+                    started_at, finished_at, duration_ms
+             FROM workflow_node_runs WHERE run_id = ?1 ORDER BY id",
+        )?;
+        let nodes = stmt
+            .query_map([run_id], |row| {
+                Ok(NodeTiming {
+                    node_id: row.get(0)?,
+                    node_type: get_enum(row, 1)?,
+                    state: get_enum(row, 2)?,
+                    input: get_json(row, 3)?,
+                    output: get_json(row, 4)?,
+                    error: row.get(5)?,
+                    started_at: get_time(row, 6)?,
+                    finished_at: get_time(row, 7)?,
+                    duration_ms: row.get::<_, i64>(8)?.max(0) as u64,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(Some(RunTimeline {
+            summary,
+            input: input.unwrap_or(Value::Null),
+            outputs: outputs.unwrap_or_default(),
+            nodes,
+        }))
+    }
+
+    /// 실행 이력 삭제 (노드 기록 포함)
+    pub fn remove(&self, run_id: &str) -> Result<bool, HistoryError> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM workflow_node_runs WHERE run_id = ?1", [run_id])?;
+        let removed = tx.execute("DELETE FROM workflow_runs WHERE run_id = ?1", [run_id])?;
+        tx.commit()?;
+        Ok(removed > 0)
+    }
+}
+
+/// 실행 진행을 이력으로 기록 (저장 실패로 실행을 멈추지는 않음)
+impl RunObserver for HistoryStore {
+    fn on_node(&self, event: &NodeEvent<'_>) {
+        let _ = self.record_node(event.run_id, event.node);
+    }
+
+    fn on_status(&self, run: &WorkflowRun) {
+        let _ = self.record_run(run);
+    }
+}
+
+const SUMMARY_COLUMNS: &str = "r.run_id, r.workflow_id, r.workflow_name, r.status, r.error,
+    r.started_at, r.updated_at, r.finished_at,
+    (SELECT COUNT(*) FROM workflow_node_runs n WHERE n.run_id = r.run_id)";
+
+fn summary_from_row(row: &Row<'_>) -> rusqlite::Result<RunSummary> {
+    Ok(RunSummary {
+        run_id: row.get(0)?,
+        workflow_id: row.get(1)?,
+        workflow_name: row.get(2)?,
+        status: get_enum(row, 3)?,
+        error: row.get(4)?,
+        started_at: get_time(row, 5)?,
+        updated_at: get_time(row, 6)?,
+        finished_at: match row.get::<_, Option<String>>(7)? {
+            Some(_) => Some(get_time(row, 7)?),
+            None => None,
+        },
+        node_count: row.get(8)?,
+    })
+}
+
+fn is_finished(status: RunStatus) -> bool {
+    matches!(
+        status,
+        RunStatus::Completed | RunStatus::Failed | RunStatus::Cancelled | RunStatus::TimedOut
+    )
+}
+
+/// 문자열 비교로 시간순 정렬되도록 고정 형식 사용
+fn timestamp(at: &DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn get_time(row: &Row<'_>, idx: usize) -> rusqlite::Result<DateTime<Utc>> {
+    let value: String = row.get(idx)?;
+    DateTime::parse_from_rfc3339(&value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| conversion_error(idx, e))
+}
+
+fn get_enum<T: DeserializeOwned>(row: &Row<'_>, idx: usize) -> rusqlite::Result<T> {
+    let value: String = row.get(idx)?;
+    serde_json::from_value(Value::String(value)).map_err(|e| conversion_error(idx, e))
+}
+
+fn get_json<T: DeserializeOwned>(row: &Row<'_>, idx: usize) -> rusqlite::Result<Option<T>> {
+    match row.get::<_, Option<String>>(idx)? {
+        Some(value) => serde_json::from_str(&value)
+            .map(Some)
+            .map_err(|e| conversion_error(idx, e)),
+        None => Ok(None),
+    }
+}
+
+fn conversion_error(
+    idx: usize,
+    err: impl std::error::Error + Send + Sync + 'static,
+) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(idx, Type::Text, Box::new(err))
+}
+
+/// snake_case로 직렬화되는 열거형을 문자열로
+fn enum_str<T: Serialize>(value: &T) -> Result<String, HistoryError> {
+    match serde_json::to_value(value)? {
+        Value::String(s) => Ok(s),
+        other => Err(HistoryError::InvalidRecord(format!(
+            "expected string enum, got {other}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+    use triflow_rules::RuleEngine;
+
+    use crate::actions::ActionRegistry;
+    use crate::dsl::WorkflowDsl;
+    use crate::executor::WorkflowExecutor;
+
+    /// dry-run으로 실행하며 노드/상태를 `store`에 기록
+    fn record(
+        store: &HistoryStore,
+        run_id: &str,
+        workflow_id: &str,
+        temperature: f64,
+    ) -> WorkflowRun {
+        let dsl: WorkflowDsl = serde_json::from_value(json!({
+            "name": "overheat",
+            "trigger": {"type": "manual"},
+            "nodes": [
+                {"id": "check", "type": "condition", "config": {"condition": "temperature > 80"},
+                 "next": ["alert"]},
+                {"id": "alert", "type": "action", "config": {"action": "notify"}},
+            ],
+        }))
+        .unwrap();
+        let engine = RuleEngine::new();
+        let actions = ActionRegistry::dry_run();
+        let input = json!({"workflow_id": workflow_id, "temperature": temperature});
+        let run = WorkflowExecutor::new(&engine, &actions)
+            .run(&dsl, run_id, input, |event| store.on_node(event));
+        store.record_run(&run).unwrap();
+        run
+    }
+
+    #[test]
+    fn records_run_and_timeline() {
+        let store = HistoryStore::in_memory().unwrap();
+        let run = record(&store, "run-1", "wf-1", 90.0);
+
+        let runs = store.query(&HistoryQuery::default()).unwrap();
+        assert_eq!(runs.len(), 1);
+        let summary = &runs[0];
+        assert_eq!(summary.run_id, "run-1");
+        assert_eq!(summary.workflow_id.as_deref(), Some("wf-1"));
+        assert_eq!(summary.workflow_name, "overheat");
+        assert_eq!(summary.status, RunStatus::Completed);
+        assert_eq!(summary.finished_at, Some(summary.updated_at));
+        assert_eq!(summary.node_count, 2);
+
+        let timeline = store.timeline("run-1").unwrap().unwrap();
+        assert_eq!(&timeline.summary, summary);
+        assert_eq!(timeline.input["temperature"], json!(90.0));
+        assert_eq!(timeline.outputs, run.outputs);
+        let nodes: Vec<(&str, NodeType, NodeState)> = timeline
+            .nodes
+            .iter()
+            .map(|n| (n.node_id.as_str(), n.node_type, n.state))
+            .collect();
+        assert_eq!(
+            nodes,
+            vec![
+                ("check", NodeType::Condition, NodeState::Succeeded),
+                ("alert", NodeType::Action, NodeState::Succeeded),
+            ]
+        );
+        assert_eq!(timeline.nodes[0].output, Some(json!({"result": true})));
+        assert!(store.timeline("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn keeps_start_time_when_status_changes() {
+        let store = HistoryStore::in_memory().unwrap();
+        let mut run = record(&store, "run-1", "wf-1", 90.0);
+        let started_at = store.query(&HistoryQuery::default()).unwrap()[0].started_at;
+
+        run.status = RunStatus::Cancelled;
+        run.updated_at = started_at + chrono::Duration::seconds(5);
+        store.record_run(&run).unwrap();
+
+        let summary = &store.query(&HistoryQuery::default()).unwrap()[0];
+        assert_eq!(summary.status, RunStatus::Cancelled);
+        assert_eq!(summary.started_at, started_at);
+        assert_eq!(summary.updated_at, run.updated_at);
+    }
+
+    #[test]
+    fn query_filters_and_pages() {
+        let store = HistoryStore::in_memory().unwrap();
+        record(&store, "run-1", "wf-1", 90.0);
+        record(&store, "run-2", "wf-2", 50.0);
+        record(&store, "run-3", "wf-1", 95.0);
+
+        let ids = |query: HistoryQuery| -> Vec<String> {
+            store
+                .query(&query)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.run_id)
+                .collect()
+        };
+        assert_eq!(
+            ids(HistoryQuery {
+                workflow_id: Some("wf-1".into()),
+                ..Default::default()
+            }),
+            vec!["run-3", "run-1"]
+        );
+        assert_eq!(
+            ids(HistoryQuery {
+                statuses: vec![RunStatus::Failed, RunStatus::Cancelled],
+                ..Default::default()
+            }),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            ids(HistoryQuery {
+                limit: Some(1),
+                offset: Some(1),
+                ..Default::default()
+            }),
+            vec!["run-2"]
+        );
+
+        assert!(store.remove("run-2").unwrap());
+        assert!(!store.remove("run-2").unwrap());
+        assert!(store.timeline("run-2").unwrap().is_none());
+        assert_eq!(ids(HistoryQuery::default()), vec!["run-3", "run-1"]);
+    }
+}
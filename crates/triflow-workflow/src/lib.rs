@@ -0,0 +1,53 @@
+//! TriFlow AI 워크플로우 DSL
+//!
+//! `core.workflows.dsl_json`에 저장되는 워크플로우 정의의 타입, 검증, 로컬 실행.
+//! 데스크톱 워크플로우 빌더는 저장 전에 [`validate`]로 정의를 확인하고,
+//! [`WorkflowExecutor`]로 서버 없이 워크플로우를 시험 실행한다.
+//! [`RunManager`]는 일시정지/재개/재시도/취소와 체크포인트 기반 재시작 복구를 담당한다.
+//! [`Scheduler`]는 스케줄 트리거 워크플로우를 cron 식에 따라, [`EventBus`]는 이벤트
+//! 트리거 워크플로우를 센서 이벤트에 따라 실행한다. 실행 이력은 [`HistoryStore`]에 남는다.
+//! [`render_plan`]은 정의를 검토용 단계 설명과 Mermaid/DOT 그래프로 바꾼다.
+
+mod actions;
+mod approval;
+mod catalog;
+mod checkpoint;
+mod dsl;
+mod events;
+mod executor;
+mod history;
+mod render;
+mod runner;
+mod schedule;
+mod simulate;
+mod state;
+mod validate;
+
+pub use actions::{ActionCall, ActionError, ActionHandler, ActionRegistry, DryRunHandler};
+pub use approval::{ApprovalConfig, ApprovalDefault, ApprovalRecord, ApprovalRequest};
+pub use catalog::{ActionCatalog, ActionSpec};
+pub use checkpoint::{Checkpoint, CheckpointStore};
+pub use dsl::{Node, NodeType, Trigger, TriggerType, WorkflowDsl};
+pub use events::{
+    EventBus, EventError, EventFilter, EventRejection, EventSubscription, EventSyncReport,
+    EventTrigger, OneOrMany, PublishOutcome, SensorEvent, DEDUPE_CAPACITY,
+};
+pub use executor::{
+    NodeEvent, NodeRecord, NodeState, WorkflowExecutor, WorkflowRun, DEFAULT_MAX_STEPS,
+};
+pub use history::{
+    HistoryError, HistoryQuery, HistoryStore, NodeTiming, RunSummary, RunTimeline,
+    DEFAULT_HISTORY_LIMIT,
+};
+pub use render::{render_plan, Language, PlanStep, WorkflowPlan};
+pub use runner::{RunError, RunManager, RunObserver};
+pub use schedule::{
+    CatchUpPolicy, ScheduleConfig, ScheduleEntry, ScheduleError, ScheduleRejection,
+    ScheduleSyncReport, ScheduleTimezone, ScheduledRun, Scheduler, MAX_CATCH_UP,
+};
+pub use simulate::{
+    simulate, FiredAction, RecordedCall, RecordingHandler, RowFailure, SimulationOptions,
+    SimulationReport,
+};
+pub use state::{RunStatus, Transition, TransitionError};
+pub use validate::{validate, ValidationError};
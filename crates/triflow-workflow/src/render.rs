@@ -0,0 +1,621 @@
+//! 워크플로우 실행 계획 렌더링
+//!
+//! WorkflowPlanner가 생성한 DSL을 검토자가 JSON을 읽지 않고 확인할 수 있도록
+//! 단계별 설명(한국어/영어)과 Mermaid, Graphviz DOT 그래프로 바꾼다.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::approval::{ApprovalConfig, ApprovalDefault};
+use crate::catalog::ActionCatalog;
+use crate::dsl::{Node, NodeType, TriggerType, WorkflowDsl};
+use crate::events::{EventFilter, OneOrMany};
+use crate::schedule::ScheduleConfig;
+
+/// 설명 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    Ko,
+    En,
+}
+
+/// 실행 계획의 한 단계
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlanStep {
+    /// 1부터 시작하는 단계 번호
+    pub number: usize,
+    pub node_id: String,
+    pub node_type: NodeType,
+    /// 이 단계에서 하는 일
+    pub summary: String,
+    /// 다음 단계 안내
+    pub then: String,
+    /// 다음 단계 번호
+    pub next: Vec<usize>,
+}
+
+/// 사람이 읽을 수 있는 실행 계획
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkflowPlan {
+    pub language: Language,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 실행 시점 설명
+    pub trigger: String,
+    pub steps: Vec<PlanStep>,
+    /// Mermaid flowchart 정의
+    pub mermaid: String,
+    /// Graphviz DOT 정의
+    pub dot: String,
+}
+
+/// 워크플로우를 실행 계획으로 렌더링
+///
+/// 검증을 통과한 정의를 기준으로 한다. 단계는 시작 노드부터 너비 우선 순서로
+/// 번호를 매기며, 시작 노드에서 닿지 않는 노드는 마지막에 붙인다.
+/// `catalog`가 있으면 액션 설명을 함께 적는다.
+pub fn render_plan(
+    dsl: &WorkflowDsl,
+    catalog: Option<&ActionCatalog>,
+    language: Language,
+) -> WorkflowPlan {
+    let order = step_order(dsl);
+    let numbers: HashMap<&str, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| (dsl.nodes[n].id.as_str(), i + 1))
+        .collect();
+    let text = Text(language);
+
+    let steps = order
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| {
+            let node = &dsl.nodes[n];
+            let next: Vec<usize> = node
+                .next
+                .iter()
+                .filter_map(|id| numbers.get(id.as_str()).copied())
+                .collect();
+            PlanStep {
+                number: i + 1,
+                node_id: node.id.clone(),
+                node_type: node.node_type,
+                summary: text.summary(node, catalog),
+                then: text.then(node.node_type, &next),
+                next,
+            }
+        })
+        .collect();
+
+    let trigger = text.trigger(dsl);
+    WorkflowPlan {
+        language,
+        title: dsl.name.clone(),
+        description: dsl.description.clone(),
+        mermaid: mermaid(dsl, &order, &text),
+        dot: dot(dsl, &order, &text),
+        trigger,
+        steps,
+    }
+}
+
+/// 노드 인덱스를 단계 순서로 (시작 노드부터 너비 우선, 이후 나머지)
+fn step_order(dsl: &WorkflowDsl) -> Vec<usize> {
+    let index: HashMap<&str, usize> = dsl
+        .nodes
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, n)| (n.id.as_str(), i))
+        .collect();
+    let mut seen = vec![false; dsl.nodes.len()];
+    let mut order = Vec::with_capacity(dsl.nodes.len());
+    let mut queue = VecDeque::new();
+
+    for start in 0..dsl.nodes.len() {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        queue.push_back(start);
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for target in &dsl.nodes[i].next {
+                if let Some(&j) = index.get(target.as_str()) {
+                    if !seen[j] {
+                        seen[j] = true;
+                        queue.push_back(j);
+                    }
+                }
+            }
+        }
+    }
+    order
+}
+
+/// 언어별 문장
+struct Text(Language);
+
+impl Text {
+    fn ko(&self) -> bool {
+        self.0 == Language::Ko
+    }
+
+    fn trigger(&self, dsl: &WorkflowDsl) -> String {
+        match dsl.trigger.trigger_type {
+            TriggerType::Manual => {
+                self.pick("사용자가 직접 실행합니다.", "Runs when started manually.")
+            }
+            TriggerType::Schedule => match ScheduleConfig::from_dsl(dsl) {
+                Ok(config) if self.ko() => format!("`{}` 일정에 따라 실행합니다.", config.cron),
+                Ok(config) => format!("Runs on the schedule `{}`.", config.cron),
+                Err(_) => self.pick("일정에 따라 실행합니다.", "Runs on a schedule."),
+            },
+            TriggerType::Event => self.event_trigger(dsl),
+        }
+    }
+
+    fn event_trigger(&self, dsl: &WorkflowDsl) -> String {
+        let Ok(filter) = EventFilter::from_dsl(dsl) else {
+            return self.pick(
+                "이벤트가 발생하면 실행합니다.",
+                "Runs when an event occurs.",
+            );
+        };
+        let event_type = dsl.trigger.config.get("event_type").and_then(Value::as_str);
+        let lines = filter.line_code.as_ref().map(|l| self.list(l));
+        let sensors = filter.sensor_type.as_ref().map(|s| self.list(s));
+
+        let mut sentence = if self.ko() {
+            let mut subject = String::new();
+            if let Some(lines) = &lines {
+                let _ = write!(subject, "{lines} 라인의 ");
+            }
+            match (&sensors, event_type) {
+                (Some(sensors), _) => {
+                    let _ = write!(subject, "{sensors} 센서 이벤트");
+                }
+                (None, Some(event_type)) => {
+                    let _ = write!(subject, "'{event_type}' 이벤트");
+                }
+                (None, None) => subject.push_str("센서 이벤트"),
+            }
+            match &filter.filter {
+                Some(expr) => format!("{subject}가 `{expr}` 조건을 만족하면 실행합니다."),
+                None => format!("{subject}가 들어오면 실행합니다."),
+            }
+        } else {
+            let mut subject = match (&sensors, event_type) {
+                (Some(sensors), _) => format!("a {sensors} sensor event"),
+                (None, Some(event_type)) => format!("a '{event_type}' event"),
+                (None, None) => "a sensor event".to_string(),
+            };
+            if let Some(lines) = &lines {
+                let _ = write!(subject, " from line {lines}");
+            }
+            match &filter.filter {
+                Some(expr) => format!("Runs when {subject} satisfies `{expr}`."),
+                None => format!("Runs when {subject} arrives."),
+            }
+        };
+        if filter.debounce_ms > 0 {
+            let seconds = filter.debounce_ms as f64 / 1000.0;
+            if self.ko() {
+                let _ = write!(
+                    sentence,
+                    " 같은 라인/센서로는 {seconds}초에 한 번만 실행합니다."
+                );
+            } else {
+                let _ = write!(
+                    sentence,
+                    " Runs at most once every {seconds}s per line and sensor."
+                );
+            }
+        }
+        sentence
+    }
+
+    fn summary(&self, node: &Node, catalog: Option<&ActionCatalog>) -> String {
+        match node.node_type {
+            NodeType::Condition => {
+                let expr = node.condition().unwrap_or("?");
+                if self.ko() {
+                    format!("`{expr}` 조건을 확인합니다.")
+                } else {
+                    format!("Check whether `{expr}`.")
+                }
+            }
+            NodeType::Action => {
+                let action = node.action().unwrap_or("?");
+                let mut sentence = if self.ko() {
+                    format!("`{action}` 액션을 실행합니다")
+                } else {
+                    format!("Run the `{action}` action")
+                };
+                if let Some(spec) = catalog.and_then(|c| c.get(action)) {
+                    if !spec.description.is_empty() {
+                        let _ = write!(sentence, " ({})", spec.description);
+                    }
+                }
+                let parameters = parameters(&node.parameters());
+                if !parameters.is_empty() {
+                    let label = self.pick("파라미터", "with");
+                    let _ = write!(sentence, ": {label} {parameters}");
+                }
+                sentence.push('.');
+                sentence
+            }
+            NodeType::Approval => {
+                let config = ApprovalConfig::from_node(node).unwrap_or_default();
+                let mut sentence = match (&config.message, self.ko()) {
+                    (Some(message), true) => format!("담당자 승인을 기다립니다: \"{message}\"."),
+                    (None, true) => "담당자 승인을 기다립니다.".to_string(),
+                    (Some(message), false) => {
+                        format!("Wait for a person to approve: \"{message}\".")
+                    }
+                    (None, false) => "Wait for a person to approve.".to_string(),
+                };
+                if let Some(seconds) = config.timeout_seconds {
+                    let approve = config.on_timeout == ApprovalDefault::Approve;
+                    let _ = match (self.ko(), approve) {
+                        (true, true) => write!(
+                            sentence,
+                            " {seconds}초 안에 응답이 없으면 승인한 것으로 봅니다."
+                        ),
+                        (true, false) => {
+                            write!(sentence, " {seconds}초 안에 응답이 없으면 실행을 멈춥니다.")
+                        }
+                        (false, true) => write!(
+                            sentence,
+                            " Approves automatically after {seconds}s without a response."
+                        ),
+                        (false, false) => write!(
+                            sentence,
+                            " Stops the run after {seconds}s without a response."
+                        ),
+                    };
+                }
+                sentence
+            }
+            NodeType::Unknown => self.pick("지원하지 않는 단계입니다.", "Unsupported step."),
+        }
+    }
+
+    fn then(&self, node_type: NodeType, next: &[usize]) -> String {
+        let steps = next
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        match (node_type, next.is_empty(), self.ko()) {
+            (NodeType::Condition, true, true) => "참이든 거짓이든 여기서 끝납니다.".to_string(),
+            (NodeType::Condition, true, false) => "Ends here either way.".to_string(),
+            (NodeType::Condition, false, true) => {
+                format!("참이면 {steps}단계로 진행하고, 거짓이면 여기서 멈춥니다.")
+            }
+            (NodeType::Condition, false, false) => {
+                format!("If true, continue to step {steps}; otherwise stop here.")
+            }
+            (NodeType::Approval, true, true) => {
+                "승인하면 끝나고, 거부하면 실행을 취소합니다.".to_string()
+            }
+            (NodeType::Approval, true, false) => {
+                "Ends when approved; rejecting cancels the run.".to_string()
+            }
+            (NodeType::Approval, false, true) => {
+                format!("승인하면 {steps}단계로 진행하고, 거부하면 실행을 취소합니다.")
+            }
+            (NodeType::Approval, false, false) => {
+                format!("If approved, continue to step {steps}; rejecting cancels the run.")
+            }
+            (_, true, true) => "워크플로우를 마칩니다.".to_string(),
+            (_, true, false) => "End of workflow.".to_string(),
+            (_, false, true) => format!("{steps}단계로 진행합니다."),
+            (_, false, false) => format!("Continue to step {steps}."),
+        }
+    }
+
+    /// 그래프 노드 라벨
+    fn label(&self, node: &Node) -> String {
+        match node.node_type {
+            NodeType::Condition => node.condition().unwrap_or(&node.id).to_string(),
+            NodeType::Action => node.action().unwrap_or(&node.id).to_string(),
+            NodeType::Approval => {
+                let config = ApprovalConfig::from_node(node).unwrap_or_default();
+                let prefix = self.pick("승인", "Approval");
+                match config.message {
+                    Some(message) => format!("{prefix}: {message}"),
+                    None => format!("{prefix}: {}", node.id),
+                }
+            }
+            NodeType::Unknown => node.id.clone(),
+        }
+    }
+
+    fn trigger_label(&self, dsl: &WorkflowDsl) -> String {
+        match dsl.trigger.trigger_type {
+            TriggerType::Manual => self.pick("수동 실행", "Manual"),
+            TriggerType::Schedule => match ScheduleConfig::from_dsl(dsl) {
+                Ok(config) => format!("{} {}", self.pick("스케줄", "Schedule"), config.cron),
+                Err(_) => self.pick("스케줄", "Schedule"),
+            },
+            TriggerType::Event => self.pick("이벤트", "Event"),
+        }
+    }
+
+    /// 다음 노드로 가는 간선 라벨 (조건/승인 노드만)
+    fn edge_label(&self, node_type: NodeType) -> Option<String> {
+        match node_type {
+            NodeType::Condition => Some(self.pick("참", "true")),
+            NodeType::Approval => Some(self.pick("승인", "approved")),
+            _ => None,
+        }
+    }
+
+    fn list(&self, values: &OneOrMany) -> String {
+        match values {
+            OneOrMany::One(value) => value.clone(),
+            OneOrMany::Many(values) => values.join(self.pick("/", " or ").as_str()),
+        }
+    }
+
+    fn pick(&self, ko: &str, en: &str) -> String {
+        if self.ko() { ko } else { en }.to_string()
+    }
+}
+
+/// `key=value` 목록 (문자열은 따옴표 없이)
+fn parameters(parameters: &Value) -> String {
+    let Value::Object(fields) = parameters else {
+        return String::new();
+    };
+    fields
+        .iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => format!("{key}={s}"),
+            other => format!("{key}={other}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn mermaid(dsl: &WorkflowDsl, order: &[usize], text: &Text) -> String {
+    // Mermaid 라벨 안의 따옴표는 엔티티로
+    let escape = |s: &str| s.replace('"', "#quot;");
+    let ids = graph_ids(dsl, order);
+
+    let mut out = String::from("flowchart TD\n");
+    let _ = writeln!(
+        out,
+        "    trigger([\"{}\"])",
+        escape(&text.trigger_label(dsl))
+    );
+    for &i in order {
+        let node = &dsl.nodes[i];
+        let label = escape(&text.label(node));
+        let id = &ids[&i];
+        let _ = match node.node_type {
+            NodeType::Condition => writeln!(out, "    {id}{{\"{label}\"}}"),
+            NodeType::Approval => writeln!(out, "    {id}{{{{\"{label}\"}}}}"),
+            _ => writeln!(out, "    {id}[\"{label}\"]"),
+        };
+    }
+    if let Some(start) = order.first() {
+        let _ = writeln!(out, "    trigger --> {}", ids[start]);
+    }
+    for_each_edge(dsl, &ids, |from, to, node_type| {
+        let _ = match text.edge_label(node_type) {
+            Some(label) => writeln!(out, "    {from} -->|{label}| {to}"),
+            None => writeln!(out, "    {from} --> {to}"),
+        };
+    });
+    out
+}
+
+fn dot(dsl: &WorkflowDsl, order: &[usize], text: &Text) -> String {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+    let ids = graph_ids(dsl, order);
+
+    let mut out = String::from("digraph workflow {\n    rankdir=TB;\n");
+    let _ = writeln!(
+        out,
+        "    trigger [label=\"{}\", shape=oval];",
+        escape(&text.trigger_label(dsl))
+    );
+    for &i in order {
+        let node = &dsl.nodes[i];
+        let shape = match node.node_type {
+            NodeType::Condition => "diamond",
+            NodeType::Approval => "hexagon",
+            _ => "box",
+        };
+        let _ = writeln!(
+            out,
+            "    {} [label=\"{}\", shape={shape}];",
+            ids[&i],
+            escape(&text.label(node))
+        );
+    }
+    if let Some(start) = order.first() {
+        let _ = writeln!(out, "    trigger -> {};", ids[start]);
+    }
+    for_each_edge(dsl, &ids, |from, to, node_type| {
+        let _ = match text.edge_label(node_type) {
+            Some(label) => writeln!(out, "    {from} -> {to} [label=\"{label}\"];"),
+            None => writeln!(out, "    {from} -> {to};"),
+        };
+    });
+    out.push_str("}\n");
+    out
+}
+
+/// 그래프용 노드 ID (`s1`, `s2`, ... 단계 번호와 같음)
+fn graph_ids(dsl: &WorkflowDsl, order: &[usize]) -> HashMap<usize, String> {
+    debug_assert_eq!(order.len(), dsl.nodes.len());
+    order
+        .iter()
+        .enumerate()
+        .map(|(step, &i)| (i, format!("s{}", step + 1)))
+        .collect()
+}
+
+fn for_each_edge(
+    dsl: &WorkflowDsl,
+    ids: &HashMap<usize, String>,
+    mut edge: impl FnMut(&str, &str, NodeType),
+) {
+    let index: HashMap<&str, usize> = dsl
+        .nodes
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, n)| (n.id.as_str(), i))
+        .collect();
+    for (i, node) in dsl.nodes.iter().enumerate() {
+        for target in &node.next {
+            if let Some(j) = index.get(target.as_str()) {
+                edge(&ids[&i], &ids[j], node.node_type);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn sample() -> WorkflowDsl {
+        serde_json::from_value(json!({
+            "name": "과열 대응",
+            "trigger": {"type": "manual"},
+            "nodes": [
+                {"id": "check", "type": "condition",
+                 "config": {"condition": "line_code == \"L1\" && temperature > 80"},
+                 "next": ["approve"]},
+                {"id": "approve", "type": "approval",
+                 "config": {"message": "Stop \"L1\"? (see C:\\logs)", "timeout_seconds": 600},
+                 "next": ["stop"]},
+                {"id": "stop", "type": "action",
+                 "config": {"action": "stop_production_line", "parameters": {"line": "L1"}}},
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_korean_plan() {
+        let plan = render_plan(&sample(), None, Language::Ko);
+        assert_eq!(plan.title, "과열 대응");
+        assert_eq!(plan.trigger, "사용자가 직접 실행합니다.");
+        let steps: Vec<(&str, &str)> = plan
+            .steps
+            .iter()
+            .map(|s| (s.summary.as_str(), s.then.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (
+                    r#"`line_code == "L1" && temperature > 80` 조건을 확인합니다."#,
+                    "참이면 2단계로 진행하고, 거짓이면 여기서 멈춥니다.",
+                ),
+                (
+                    r#"담당자 승인을 기다립니다: "Stop "L1"? (see C:\logs)". 600초 안에 응답이 없으면 실행을 멈춥니다."#,
+                    "승인하면 3단계로 진행하고, 거부하면 실행을 취소합니다.",
+                ),
+                (
+                    "`stop_production_line` 액션을 실행합니다: 파라미터 line=L1.",
+                    "워크플로우를 마칩니다.",
+                ),
+            ]
+        );
+        assert_eq!(
+            plan.mermaid,
+            r#"flowchart TD
+    trigger(["수동 실행"])
+    s1{"line_code == #quot;L1#quot; && temperature > 80"}
+    s2{{"승인: Stop #quot;L1#quot;? (see C:\logs)"}}
+    s3["stop_production_line"]
+    trigger --> s1
+    s1 -->|참| s2
+    s2 -->|승인| s3
+"#
+        );
+        assert_eq!(
+            plan.dot,
+            r#"digraph workflow {
+    rankdir=TB;
+    trigger [label="수동 실행", shape=oval];
+    s1 [label="line_code == \"L1\" && temperature > 80", shape=diamond];
+    s2 [label="승인: Stop \"L1\"? (see C:\\logs)", shape=hexagon];
+    s3 [label="stop_production_line", shape=box];
+    trigger -> s1;
+    s1 -> s2 [label="참"];
+    s2 -> s3 [label="승인"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn renders_english_plan() {
+        let plan = render_plan(&sample(), None, Language::En);
+        assert_eq!(plan.trigger, "Runs when started manually.");
+        let steps: Vec<(usize, &str, &[usize])> = plan
+            .steps
+            .iter()
+            .map(|s| (s.number, s.node_id.as_str(), s.next.as_slice()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (1, "check", &[2][..]),
+                (2, "approve", &[3][..]),
+                (3, "stop", &[][..])
+            ]
+        );
+        assert_eq!(
+            plan.steps[1].summary,
+            r#"Wait for a person to approve: "Stop "L1"? (see C:\logs)". Stops the run after 600s without a response."#
+        );
+        assert_eq!(
+            plan.steps[2].summary,
+            "Run the `stop_production_line` action: with line=L1."
+        );
+        assert_eq!(
+            plan.mermaid,
+            r#"flowchart TD
+    trigger(["Manual"])
+    s1{"line_code == #quot;L1#quot; && temperature > 80"}
+    s2{{"Approval: Stop #quot;L1#quot;? (see C:\logs)"}}
+    s3["stop_production_line"]
+    trigger --> s1
+    s1 -->|true| s2
+    s2 -->|approved| s3
+"#
+        );
+        assert_eq!(
+            plan.dot,
+            r#"digraph workflow {
+    rankdir=TB;
+    trigger [label="Manual", shape=oval];
+    s1 [label="line_code == \"L1\" && temperature > 80", shape=diamond];
+    s2 [label="Approval: Stop \"L1\"? (see C:\\logs)", shape=hexagon];
+    s3 [label="stop_production_line", shape=box];
+    trigger -> s1;
+    s1 -> s2 [label="true"];
+    s2 -> s3 [label="approved"];
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,575 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use triflow_rules::RuleEngine;
+use uuid::Uuid;
+
+use crate::actions::ActionRegistry;
+use crate::approval::ApprovalRequest;
+use crate::checkpoint::{Checkpoint, CheckpointStore};
+use crate::dsl::WorkflowDsl;
+use crate::executor::{NodeEvent, NodeRecord, WorkflowExecutor, WorkflowRun};
+use crate::state::{RunStatus, Transition, TransitionError};
+use crate::validate::{validate, ValidationError};
+
+/// 실행 관리 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록
+/// `{"kind": ..., "detail": ...}` 형태로 직렬화된다.
+#[derive(Debug, thiserror::Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum RunError {
+    #[error("workflow run '{0}' not found")]
+    NotFound(String),
+
+    #[error("invalid workflow definition ({} error(s))", .0.len())]
+    InvalidWorkflow(Vec<ValidationError>),
+
+    #[error(transparent)]
+    InvalidTransition(#[from] TransitionError),
+
+    #[error("checkpoint error: {0}")]
+    Checkpoint(String),
+}
+
+impl From<std::io::Error> for RunError {
+    fn from(err: std::io::Error) -> Self {
+        RunError::Checkpoint(err.to_string())
+    }
+}
+
+/// 실행 진행 알림 수신자
+pub trait RunObserver: Send + Sync {
+    /// 노드 시작/종료
+    fn on_node(&self, _event: &NodeEvent<'_>) {}
+
+    /// 실행 상태 변경
+    fn on_status(&self, _run: &WorkflowRun) {}
+
+    /// 승인 노드에 도달해 결정을 기다림 (앱 재시작 후 복원될 때도 다시 호출)
+    fn on_approval(&self, _run: &WorkflowRun, _request: &ApprovalRequest) {}
+}
+
+/// 알림을 받지 않는 수신자
+impl RunObserver for () {}
+
+/// 두 수신자에게 차례로 전달
+impl<A: RunObserver, B: RunObserver> RunObserver for (A, B) {
+    fn on_node(&self, event: &NodeEvent<'_>) {
+        self.0.on_node(event);
+        self.1.on_node(event);
+    }
+
+    fn on_status(&self, run: &WorkflowRun) {
+        self.0.on_status(run);
+        self.1.on_status(run);
+    }
+
+    fn on_approval(&self, run: &WorkflowRun, request: &ApprovalRequest) {
+        self.0.on_approval(run, request);
+        self.1.on_approval(run, request);
+    }
+}
+
+/// 워크플로우 실행 관리자
+///
+/// 실행마다 별도 스레드에서 노드를 하나씩 실행하고, 노드 경계마다
+/// 체크포인트를 저장한다. 일시정지/취소 요청은 다음 노드 경계에서 적용된다.
+/// 승인 노드의 제한 시간은 별도 스레드가 기다렸다가 처리한다.
+#[derive(Clone)]
+pub struct RunManager {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    engine: Arc<RuleEngine>,
+    actions: Arc<ActionRegistry>,
+    store: Option<CheckpointStore>,
+    observer: Arc<dyn RunObserver>,
+    runs: Mutex<HashMap<String, Arc<Slot>>>,
+}
+
+struct Slot {
+    dsl: WorkflowDsl,
+    state: Mutex<SlotState>,
+}
+
+struct SlotState {
+    run: WorkflowRun,
+    /// 실행 스레드 동작 여부
+    driving: bool,
+    /// 다음 노드 경계에서 적용할 전이 (실행 중일 때만)
+    requested: Option<Transition>,
+}
+
+impl RunManager {
+    /// `store`가 없으면 체크포인트를 저장하지 않는다.
+    pub fn new(
+        engine: Arc<RuleEngine>,
+        actions: Arc<ActionRegistry>,
+        store: Option<CheckpointStore>,
+        observer: impl RunObserver + 'static,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                engine,
+                actions,
+                store,
+                observer: Arc::new(observer),
+                runs: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// 새 실행 시작 (검증 실패 시 실행하지 않음)
+    pub fn start(&self, dsl: WorkflowDsl, input: Value) -> Result<WorkflowRun, RunError> {
+        let errors = validate(&dsl, None);
+        if !errors.is_empty() {
+            return Err(RunError::InvalidWorkflow(errors));
+        }
+
+        let mut run = WorkflowRun::new(Uuid::new_v4().to_string(), &dsl, input);
+        run.transition(Transition::Start)?;
+        let snapshot = run.clone();
+        let slot = self.inner.insert(dsl, run);
+        self.inner.checkpoint(&slot.dsl, &snapshot);
+        self.inner.observer.on_status(&snapshot);
+        self.spawn(slot);
+        Ok(snapshot)
+    }
+
+    /// 일시정지 (실행 중인 노드가 끝난 뒤 적용)
+    pub fn pause(&self, run_id: &str) -> Result<RunStatus, RunError> {
+        self.control(run_id, Transition::Pause)
+    }
+
+    /// 일시정지된 실행 재개
+    pub fn resume(&self, run_id: &str) -> Result<RunStatus, RunError> {
+        self.control(run_id, Transition::Resume)
+    }
+
+    /// 실패한 노드부터 다시 실행
+    pub fn retry(&self, run_id: &str) -> Result<RunStatus, RunError> {
+        self.control(run_id, Transition::Retry)
+    }
+
+    /// 실행 취소 (실행 중인 노드가 끝난 뒤 적용)
+    pub fn cancel(&self, run_id: &str) -> Result<RunStatus, RunError> {
+        self.control(run_id, Transition::Cancel)
+    }
+
+    /// 대기 중인 승인 요청 승인 (승인 노드의 `next`부터 이어서 실행)
+    pub fn approve(
+        &self,
+        run_id: &str,
+        decided_by: &str,
+        comment: Option<String>,
+    ) -> Result<RunStatus, RunError> {
+        self.decide(run_id, true, decided_by, comment)
+    }
+
+    /// 대기 중인 승인 요청 거부 (실행 취소)
+    pub fn reject(
+        &self,
+        run_id: &str,
+        decided_by: &str,
+        comment: Option<String>,
+    ) -> Result<RunStatus, RunError> {
+        self.decide(run_id, false, decided_by, comment)
+    }
+
+    pub fn get(&self, run_id: &str) -> Option<WorkflowRun> {
+        let slot = self.inner.slot(run_id)?;
+        let run = lock(&slot.state).run.clone();
+        Some(run)
+    }
+
+    /// 모든 실행 (최근 변경 순)
+    pub fn list(&self) -> Vec<WorkflowRun> {
+        let slots: Vec<Arc<Slot>> = lock(&self.inner.runs).values().cloned().collect();
+        let mut runs: Vec<WorkflowRun> = slots.iter().map(|s| lock(&s.state).run.clone()).collect();
+        runs.sort_by_key(|r| Reverse(r.updated_at));
+        runs
+    }
+
+    /// 앱 재시작 후 체크포인트에서 실행 복원
+    ///
+    /// 종료 시점에 실행 중이던 인스턴스는 마지막 노드 경계부터 이어서 실행한다
+    /// (중단된 노드는 다시 실행된다). 승인 대기 중이던 인스턴스는 승인 요청을
+    /// 다시 알리고, 그 사이 제한 시간이 지났으면 바로 처리한다.
+    /// 복원한 실행 수를 반환한다.
+    pub fn restore(&self) -> Result<usize, RunError> {
+        let Some(store) = &self.inner.store else {
+            return Ok(0);
+        };
+        let checkpoints = store.load_all()?;
+        let count = checkpoints.len();
+        for Checkpoint { dsl, run } in checkpoints {
+            if self.inner.slot(&run.run_id).is_some() {
+                continue;
+            }
+            let status = run.status;
+            let slot = self.inner.insert(dsl, run);
+            match status {
+                RunStatus::Running => self.spawn(slot),
+                RunStatus::WaitingApproval => self.inner.await_approval(slot),
+                _ => (),
+            }
+        }
+        Ok(count)
+    }
+
+    fn control(&self, run_id: &str, transition: Transition) -> Result<RunStatus, RunError> {
+        let slot = self
+            .inner
+            .slot(run_id)
+            .ok_or_else(|| RunError::NotFound(run_id.to_string()))?;
+
+        let mut state = lock(&slot.state);
+        let target = state.run.status.apply(transition)?;
+        if state.driving {
+            // 실행 스레드가 다음 노드 경계에서 적용
+            state.requested = Some(transition);
+            return Ok(target);
+        }
+
+        match transition {
+            Transition::Retry => state.run.retry()?,
+            _ => state.run.transition(transition)?,
+        };
+        if transition == Transition::Cancel {
+            state.run.approval = None;
+        }
+        let snapshot = state.run.clone();
+        let restart = snapshot.status == RunStatus::Running;
+        if restart {
+            state.driving = true;
+        }
+        drop(state);
+
+        self.inner.checkpoint(&slot.dsl, &snapshot);
+        self.inner.observer.on_status(&snapshot);
+        if restart {
+            self.inner.drive_in_background(slot);
+        }
+        Ok(target)
+    }
+
+    fn decide(
+        &self,
+        run_id: &str,
+        approved: bool,
+        decided_by: &str,
+        comment: Option<String>,
+    ) -> Result<RunStatus, RunError> {
+        let slot = self
+            .inner
+            .slot(run_id)
+            .ok_or_else(|| RunError::NotFound(run_id.to_string()))?;
+
+        let mut state = lock(&slot.state);
+        let record = state.run.decide(approved, decided_by, comment)?;
+        let status = state.run.status;
+        self.inner.resolved(slot.clone(), state, record);
+        Ok(status)
+    }
+
+    fn spawn(&self, slot: Arc<Slot>) {
+        lock(&slot.state).driving = true;
+        self.inner.drive_in_background(slot);
+    }
+}
+
+impl Inner {
+    fn insert(&self, dsl: WorkflowDsl, run: WorkflowRun) -> Arc<Slot> {
+        let run_id = run.run_id.clone();
+        let slot = Arc::new(Slot {
+            dsl,
+            state: Mutex::new(SlotState {
+                run,
+                driving: false,
+                requested: None,
+            }),
+        });
+        lock(&self.runs).insert(run_id, Arc::clone(&slot));
+        slot
+    }
+
+    fn slot(&self, run_id: &str) -> Option<Arc<Slot>> {
+        lock(&self.runs).get(run_id).cloned()
+    }
+
+    fn drive_in_background(self: &Arc<Self>, slot: Arc<Slot>) {
+        let inner = Arc::clone(self);
+        thread::spawn(move || inner.drive(slot));
+    }
+
+    /// 실행 스레드: 노드를 하나씩 실행하며 요청된 전이를 노드 경계에서 적용
+    fn drive(self: &Arc<Self>, slot: Arc<Slot>) {
+        let executor = WorkflowExecutor::new(&self.engine, &self.actions);
+        loop {
+            let mut run = {
+                let mut state = lock(&slot.state);
+                if let Some(transition) = state.requested.take() {
+                    state.driving = false;
+                    if state.run.transition(transition).is_ok() {
+                        if transition == Transition::Cancel {
+                            state.run.approval = None;
+                        }
+                        self.checkpoint(&slot.dsl, &state.run);
+                        self.observer.on_status(&state.run);
+                    }
+                    return;
+                }
+                state.run.clone()
+            };
+
+            let before = run.status;
+            let more = executor.step(&slot.dsl, &mut run, &mut |event| {
+                self.observer.on_node(event)
+            });
+
+            let mut state = lock(&slot.state);
+            state.run = run;
+            self.checkpoint(&slot.dsl, &state.run);
+            if state.run.status != before {
+                self.observer.on_status(&state.run);
+            }
+            if !more {
+                state.driving = false;
+                // 실행 중에 받은 요청은 끝난 상태에 적용한다 (취소는 승인 대기/실패보다
+                // 우선). 적용할 수 없는 요청은 버리고, 실제 상태는 on_status로 알린다.
+                if let Some(transition) = state.requested.take() {
+                    if state.run.transition(transition).is_ok() {
+                        if transition == Transition::Cancel {
+                            state.run.approval = None;
+                        }
+                        self.checkpoint(&slot.dsl, &state.run);
+                        self.observer.on_status(&state.run);
+                    }
+                }
+                let waiting = state.run.status == RunStatus::WaitingApproval;
+                drop(state);
+                if waiting {
+                    self.await_approval(slot);
+                }
+                return;
+            }
+        }
+    }
+
+    /// 승인 요청을 알리고, 제한 시간이 있으면 만료를 기다리는 스레드 시작
+    fn await_approval(self: &Arc<Self>, slot: Arc<Slot>) {
+        let (run, request) = {
+            let state = lock(&slot.state);
+            match &state.run.approval {
+                Some(request) => (state.run.clone(), request.clone()),
+                None => return,
+            }
+        };
+        self.observer.on_approval(&run, &request);
+
+        let Some(expires_at) = request.expires_at else {
+            return;
+        };
+        let inner = Arc::clone(self);
+        thread::spawn(move || {
+            if let Ok(wait) = (expires_at - Utc::now()).to_std() {
+                thread::sleep(wait);
+            }
+            inner.expire(slot, &request.node_id, expires_at);
+        });
+    }
+
+    /// 같은 승인 요청이 아직 대기 중이면 제한 시간 초과로 처리
+    fn expire(self: &Arc<Self>, slot: Arc<Slot>, node_id: &str, expires_at: DateTime<Utc>) {
+        let mut state = lock(&slot.state);
+        let same_request = state
+            .run
+            .approval
+            .as_ref()
+            .is_some_and(|r| r.node_id == node_id && r.expires_at == Some(expires_at));
+        if !same_request {
+            return;
+        }
+        if let Some(record) = state.run.expire_approval(Utc::now()) {
+            self.resolved(slot.clone(), state, record);
+        }
+    }
+
+    /// 승인 결정 후 저장/알림, 승인되었으면 이어서 실행
+    fn resolved(
+        self: &Arc<Self>,
+        slot: Arc<Slot>,
+        mut state: MutexGuard<'_, SlotState>,
+        record: NodeRecord,
+    ) {
+        let restart = state.run.status == RunStatus::Running;
+        if restart {
+            state.driving = true;
+        }
+        let snapshot = state.run.clone();
+        drop(state);
+
+        self.checkpoint(&slot.dsl, &snapshot);
+        self.observer.on_node(&NodeEvent {
+            run_id: &snapshot.run_id,
+            node: &record,
+        });
+        self.observer.on_status(&snapshot);
+        if restart {
+            self.drive_in_background(slot);
+        }
+    }
+
+    /// 체크포인트 저장 (완료/취소된 실행은 삭제)
+    fn checkpoint(&self, dsl: &WorkflowDsl, run: &WorkflowRun) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        // 체크포인트 실패로 실행을 멈추지는 않는다 (다음 노드 경계에서 다시 저장)
+        let _ = if run.status.is_terminal() {
+            store.remove(&run.run_id)
+        } else {
+            store.save(&Checkpoint {
+                dsl: dsl.clone(),
+                run: run.clone(),
+            })
+        };
+    }
+}
+
+/// poison된 잠금도 복구해서 사용
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::time::{Duration, Instant};
+
+    use serde_json::json;
+
+    use crate::actions::{ActionCall, ActionError};
+
+    /// 마지막 노드 `slow`를 실행하는 동안 멈춰 있는 관리자
+    ///
+    /// 노드가 시작되면 `started`로 알리고, `finish`로 결과를 받을 때까지 기다린다.
+    struct Blocked {
+        manager: RunManager,
+        started: Receiver<()>,
+        finish: Sender<Result<Value, ActionError>>,
+    }
+
+    fn blocked() -> Blocked {
+        let (started_tx, started) = mpsc::channel();
+        let (finish, finish_rx) = mpsc::channel::<Result<Value, ActionError>>();
+        let started_tx = Mutex::new(started_tx);
+        let finish_rx = Mutex::new(finish_rx);
+        let mut actions = ActionRegistry::new();
+        actions.register("slow", move |_: &ActionCall<'_>| {
+            lock(&started_tx).send(()).unwrap();
+            lock(&finish_rx).recv().unwrap()
+        });
+        let manager = RunManager::new(Arc::new(RuleEngine::new()), Arc::new(actions), None, ());
+        Blocked {
+            manager,
+            started,
+            finish,
+        }
+    }
+
+    fn start(blocked: &Blocked) -> String {
+        let dsl: WorkflowDsl = serde_json::from_value(json!({
+            "name": "test",
+            "trigger": {"type": "manual"},
+            "nodes": [{"id": "slow", "type": "action", "config": {"action": "slow"}}],
+        }))
+        .unwrap();
+        let run = blocked.manager.start(dsl, json!({})).unwrap();
+        blocked
+            .started
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap();
+        run.run_id
+    }
+
+    /// 실행 스레드가 끝날 때까지 기다린 뒤 상태 반환
+    fn settled(manager: &RunManager, run_id: &str) -> WorkflowRun {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let slot = manager.inner.slot(run_id).unwrap();
+            let state = lock(&slot.state);
+            if !state.driving {
+                return state.run.clone();
+            }
+            drop(state);
+            assert!(Instant::now() < deadline, "run did not settle");
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn cancel_during_last_node_wins_over_completion() {
+        let blocked = blocked();
+        let run_id = start(&blocked);
+        assert_eq!(
+            blocked.manager.cancel(&run_id).unwrap(),
+            RunStatus::Cancelled
+        );
+        blocked.finish.send(Ok(json!({}))).unwrap();
+
+        let run = settled(&blocked.manager, &run_id);
+        assert_eq!(run.status, RunStatus::Cancelled);
+        assert_eq!(run.outputs["slow"], json!({}));
+    }
+
+    #[test]
+    fn pause_during_last_node_pauses_before_completion() {
+        let blocked = blocked();
+        let run_id = start(&blocked);
+        assert_eq!(blocked.manager.pause(&run_id).unwrap(), RunStatus::Paused);
+        blocked.finish.send(Ok(json!({}))).unwrap();
+        assert_eq!(settled(&blocked.manager, &run_id).status, RunStatus::Paused);
+
+        blocked.manager.resume(&run_id).unwrap();
+        assert_eq!(
+            settled(&blocked.manager, &run_id).status,
+            RunStatus::Completed
+        );
+    }
+
+    #[test]
+    fn pending_request_is_applied_to_failed_run() {
+        let blocked = blocked();
+        let run_id = start(&blocked);
+        blocked.manager.cancel(&run_id).unwrap();
+        blocked
+            .finish
+            .send(Err(ActionError("boom".into())))
+            .unwrap();
+        assert_eq!(
+            settled(&blocked.manager, &run_id).status,
+            RunStatus::Cancelled
+        );
+
+        // 실패한 실행에 적용할 수 없는 요청은 버린다
+        let blocked = self::blocked();
+        let run_id = start(&blocked);
+        blocked.manager.pause(&run_id).unwrap();
+        blocked
+            .finish
+            .send(Err(ActionError("boom".into())))
+            .unwrap();
+        let run = settled(&blocked.manager, &run_id);
+        assert_eq!(run.status, RunStatus::Failed);
+        assert_eq!(run.failed_node.as_deref(), Some("slow"));
+    }
+}
@@ -0,0 +1,588 @@
+//! 스케줄 트리거
+//!
+//! `trigger.type == "schedule"`인 워크플로우를 `trigger.config.cron` 식에 따라
+//! 실행한다. 다음 실행 시각은 파일에 저장되므로 앱이 꺼져 있던 동안 놓친
+//! 실행은 재시작 시 `catch_up` 정책에 따라 처리한다.
+//!
+//! ```json
+//! { "type": "schedule", "config": { "cron": "0 */10 * * * *", "catch_up": "run_once" } }
+//! ```
+//!
+//! `cron`은 초 필드를 포함한 6~7개 필드 식이며, 5개 필드 식은 0초로 간주한다.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::dsl::{TriggerType, WorkflowDsl};
+
+/// 놓친 실행으로 보지 않는 지연 허용 범위
+const GRACE: chrono::Duration = chrono::Duration::seconds(60);
+
+/// `run_all` 정책에서 한 번에 따라잡는 최대 실행 수
+pub const MAX_CATCH_UP: usize = 100;
+
+/// 다음 실행 시각이 멀어도 이 간격마다 깨어나 시계 변경을 반영
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// 앱이 꺼져 있던 동안 놓친 실행 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatchUpPolicy {
+    /// 놓친 실행은 건너뜀
+    #[default]
+    Skip,
+    /// 놓친 실행이 있으면 한 번만 실행
+    RunOnce,
+    /// 놓친 실행을 모두 실행 (최대 [`MAX_CATCH_UP`]회)
+    RunAll,
+}
+
+/// cron 식을 해석할 시간대
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleTimezone {
+    /// 데스크톱 시스템 시간대
+    #[default]
+    Local,
+    Utc,
+}
+
+/// 스케줄 트리거 설정 (`trigger.config`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleConfig {
+    pub cron: String,
+    #[serde(default)]
+    pub catch_up: CatchUpPolicy,
+    #[serde(default)]
+    pub timezone: ScheduleTimezone,
+}
+
+/// 스케줄 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록
+/// `{"kind": ..., "detail": ...}` 형태로 직렬화된다.
+#[derive(Debug, Clone, PartialEq, thiserror::Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum ScheduleError {
+    #[error("workflow trigger is not a schedule")]
+    NotScheduled,
+
+    #[error("invalid schedule config: {0}")]
+    InvalidConfig(String),
+
+    #[error("invalid cron expression '{expression}': {message}")]
+    InvalidCron { expression: String, message: String },
+
+    #[error("schedule state error: {0}")]
+    State(String),
+}
+
+impl ScheduleConfig {
+    /// 워크플로우 트리거에서 스케줄 설정 추출
+    pub fn from_dsl(dsl: &WorkflowDsl) -> Result<Self, ScheduleError> {
+        if dsl.trigger.trigger_type != TriggerType::Schedule {
+            return Err(ScheduleError::NotScheduled);
+        }
+        serde_json::from_value(dsl.trigger.config.clone().into())
+            .map_err(|e| ScheduleError::InvalidConfig(e.to_string()))
+    }
+
+    fn parse(&self) -> Result<cron::Schedule, ScheduleError> {
+        let expression = self.cron.trim();
+        let normalized = if expression.split_whitespace().count() == 5 {
+            format!("0 {expression}")
+        } else {
+            expression.to_string()
+        };
+        cron::Schedule::from_str(&normalized).map_err(|e| ScheduleError::InvalidCron {
+            expression: self.cron.clone(),
+            message: e.to_string(),
+        })
+    }
+}
+
+/// 등록된 스케줄 (파일에 저장되는 상태)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    pub workflow_id: String,
+    pub dsl: WorkflowDsl,
+    pub config: ScheduleConfig,
+    /// 다음 실행 시각 (더 이상 실행 시각이 없으면 None)
+    pub next_run: Option<DateTime<Utc>>,
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+/// 스케줄에 따른 실행 요청
+#[derive(Debug, Clone, Copy)]
+pub struct ScheduledRun<'a> {
+    pub workflow_id: &'a str,
+    pub dsl: &'a WorkflowDsl,
+    /// 원래 실행되어야 했던 시각
+    pub scheduled_for: DateTime<Utc>,
+    /// 앱이 꺼져 있던 동안 놓친 실행을 따라잡는 경우
+    pub catch_up: bool,
+}
+
+/// 동기화 중 등록하지 못한 워크플로우
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduleRejection {
+    pub workflow_id: String,
+    pub error: ScheduleError,
+}
+
+/// 스케줄 동기화 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduleSyncReport {
+    pub scheduled: Vec<ScheduleEntry>,
+    pub rejected: Vec<ScheduleRejection>,
+}
+
+type FireFn = dyn Fn(ScheduledRun<'_>) + Send + Sync;
+
+/// 실행할 시각과 따라잡기 여부
+type DueRuns = Vec<(DateTime<Utc>, bool)>;
+
+/// cron 스케줄러
+///
+/// [`Scheduler::start`]로 백그라운드 스레드를 띄우면 실행 시각마다 `fire`를
+/// 호출한다. 실제 워크플로우 실행은 `fire`에서 처리한다.
+#[derive(Clone)]
+pub struct Scheduler {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    entries: Mutex<BTreeMap<String, Entry>>,
+    wake: Condvar,
+    path: Option<PathBuf>,
+    fire: Box<FireFn>,
+    started: AtomicBool,
+    stopped: AtomicBool,
+}
+
+struct Entry {
+    state: ScheduleEntry,
+    schedule: cron::Schedule,
+}
+
+impl Scheduler {
+    /// 스케줄러 생성 (`path`가 있으면 저장된 상태를 불러오고 변경 시 저장)
+    pub fn open(
+        path: Option<PathBuf>,
+        fire: impl Fn(ScheduledRun<'_>) + Send + Sync + 'static,
+    ) -> Result<Self, ScheduleError> {
+        let mut entries = BTreeMap::new();
+        if let Some(path) = &path {
+            if path.exists() {
+                let bytes = fs::read(path).map_err(|e| ScheduleError::State(e.to_string()))?;
+                let saved: Vec<ScheduleEntry> = serde_json::from_slice(&bytes)
+                    .map_err(|e| ScheduleError::State(e.to_string()))?;
+                for state in saved {
+                    // 해석할 수 없는 항목은 버림 (다음 동기화 때 다시 등록됨)
+                    if let Ok(schedule) = state.config.parse() {
+                        entries.insert(state.workflow_id.clone(), Entry { state, schedule });
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                entries: Mutex::new(entries),
+                wake: Condvar::new(),
+                path,
+                fire: Box::new(fire),
+                started: AtomicBool::new(false),
+                stopped: AtomicBool::new(false),
+            }),
+        })
+    }
+
+    /// 백그라운드 스레드 시작 (이미 시작했으면 무시)
+    ///
+    /// 시작 직후 놓친 실행을 각 스케줄의 `catch_up` 정책에 따라 처리한다.
+    pub fn start(&self) {
+        if self.inner.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let inner = Arc::clone(&self.inner);
+        thread::spawn(move || inner.run_loop());
+    }
+
+    /// 백그라운드 스레드 종료
+    pub fn stop(&self) {
+        self.inner.stopped.store(true, Ordering::SeqCst);
+        self.inner.wake.notify_all();
+    }
+
+    /// 워크플로우 스케줄 등록 또는 갱신
+    ///
+    /// cron 식이 바뀌지 않았으면 저장된 다음 실행 시각을 유지한다.
+    pub fn register(
+        &self,
+        workflow_id: &str,
+        dsl: WorkflowDsl,
+    ) -> Result<ScheduleEntry, ScheduleError> {
+        let state = {
+            let mut entries = self.inner.lock();
+            let state = upsert(&mut entries, workflow_id, dsl)?;
+            self.inner.persist(&entries);
+            state
+        };
+        self.inner.wake.notify_all();
+        Ok(state)
+    }
+
+    /// 스케줄 제거
+    pub fn unregister(&self, workflow_id: &str) -> bool {
+        let mut entries = self.inner.lock();
+        let removed = entries.remove(workflow_id).is_some();
+        if removed {
+            self.inner.persist(&entries);
+        }
+        removed
+    }
+
+    /// 워크플로우 목록과 동기화
+    ///
+    /// 스케줄 트리거인 워크플로우는 등록하고, 목록에 없거나 스케줄 트리거가
+    /// 아닌 워크플로우의 스케줄은 제거한다.
+    pub fn sync(&self, workflows: Vec<(String, WorkflowDsl)>) -> ScheduleSyncReport {
+        let mut report = ScheduleSyncReport {
+            scheduled: Vec::new(),
+            rejected: Vec::new(),
+        };
+        {
+            let mut entries = self.inner.lock();
+            let mut keep = Vec::new();
+            for (workflow_id, dsl) in workflows {
+                match upsert(&mut entries, &workflow_id, dsl) {
+                    Ok(state) => {
+                        keep.push(workflow_id);
+                        report.scheduled.push(state);
+                    }
+                    Err(ScheduleError::NotScheduled) => (),
+                    Err(error) => report
+                        .rejected
+                        .push(ScheduleRejection { workflow_id, error }),
+                }
+            }
+            entries.retain(|id, _| keep.contains(id));
+            self.inner.persist(&entries);
+        }
+        self.inner.wake.notify_all();
+        report
+    }
+
+    /// 등록된 스케줄 (다음 실행 시각 순)
+    pub fn entries(&self) -> Vec<ScheduleEntry> {
+        let mut entries: Vec<ScheduleEntry> = self
+            .inner
+            .lock()
+            .values()
+            .map(|e| e.state.clone())
+            .collect();
+        entries.sort_by_key(|e| e.next_run);
+        entries
+    }
+}
+
+fn upsert(
+    entries: &mut BTreeMap<String, Entry>,
+    workflow_id: &str,
+    dsl: WorkflowDsl,
+) -> Result<ScheduleEntry, ScheduleError> {
+    let config = ScheduleConfig::from_dsl(&dsl)?;
+    let schedule = config.parse()?;
+
+    let (next_run, last_run) = match entries.get(workflow_id) {
+        Some(existing) if existing.state.config == config => {
+            (existing.state.next_run, existing.state.last_run)
+        }
+        existing => (
+            next_after(&schedule, config.timezone, Utc::now()),
+            existing.and_then(|e| e.state.last_run),
+        ),
+    };
+
+    let state = ScheduleEntry {
+        workflow_id: workflow_id.to_string(),
+        dsl,
+        config,
+        next_run,
+        last_run,
+    };
+    entries.insert(
+        workflow_id.to_string(),
+        Entry {
+            state: state.clone(),
+            schedule,
+        },
+    );
+    Ok(state)
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn run_loop(&self) {
+        let mut entries = self.lock();
+        while !self.stopped.load(Ordering::SeqCst) {
+            let now = Utc::now();
+            let mut due: Vec<(ScheduleEntry, DueRuns)> = Vec::new();
+
+            for entry in entries.values_mut() {
+                let Some(next) = entry.state.next_run.filter(|next| *next <= now) else {
+                    continue;
+                };
+                let fires = entry.due_runs(next, now);
+                if let Some((last, _)) = fires.last() {
+                    entry.state.last_run = Some(*last);
+                }
+                entry.state.next_run =
+                    next_after(&entry.schedule, entry.state.config.timezone, now);
+                if !fires.is_empty() {
+                    due.push((entry.state.clone(), fires));
+                }
+            }
+
+            if !due.is_empty() {
+                self.persist(&entries);
+                drop(entries);
+                for (state, fires) in &due {
+                    for &(scheduled_for, catch_up) in fires {
+                        (self.fire)(ScheduledRun {
+                            workflow_id: &state.workflow_id,
+                            dsl: &state.dsl,
+                            scheduled_for,
+                            catch_up,
+                        });
+                    }
+                }
+                entries = self.lock();
+                continue;
+            }
+
+            let sleep = entries
+                .values()
+                .filter_map(|e| e.state.next_run)
+                .min()
+                .and_then(|next| (next - Utc::now()).to_std().ok())
+                .map_or(MAX_SLEEP, |d| d.min(MAX_SLEEP));
+            entries = self
+                .wake
+                .wait_timeout(entries, sleep)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+    }
+
+    /// 상태 파일 저장 (실패해도 스케줄은 계속 동작)
+    fn persist(&self, entries: &BTreeMap<String, Entry>) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let states: Vec<&ScheduleEntry> = entries.values().map(|e| &e.state).collect();
+        let Ok(bytes) = serde_json::to_vec_pretty(&states) else {
+            return;
+        };
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let tmp = path.with_extension("json.tmp");
+        if fs::write(&tmp, bytes).is_ok() {
+            let _ = fs::rename(tmp, path);
+        }
+    }
+}
+
+impl Entry {
+    /// `first`부터 `now`까지의 실행 시각 중 실제로 실행할 것 (`(시각, 따라잡기 여부)`)
+    ///
+    /// 오래 꺼져 있었어도 가장 최근에 놓친 실행을 고르도록 `now`부터 거꾸로 찾는다.
+    fn due_runs(&self, first: DateTime<Utc>, now: DateTime<Utc>) -> DueRuns {
+        let limit = match self.state.config.catch_up {
+            CatchUpPolicy::Skip => 0,
+            CatchUpPolicy::RunOnce => 1,
+            CatchUpPolicy::RunAll => MAX_CATCH_UP,
+        };
+
+        let mut missed = Vec::new();
+        let mut on_time = Vec::new();
+        let times = prev_until(&self.schedule, self.state.config.timezone, now)
+            .take_while(|t| *t > first)
+            .chain([first]);
+        for t in times {
+            if t >= now - GRACE {
+                on_time.push(t);
+            } else if missed.len() < limit {
+                missed.push(t);
+            } else {
+                break;
+            }
+        }
+
+        missed
+            .into_iter()
+            .rev()
+            .map(|t| (t, true))
+            .chain(on_time.into_iter().rev().map(|t| (t, false)))
+            .collect()
+    }
+}
+
+fn next_after(
+    schedule: &cron::Schedule,
+    timezone: ScheduleTimezone,
+    after: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    match timezone {
+        ScheduleTimezone::Local => next_in(schedule, &Local, after),
+        ScheduleTimezone::Utc => next_in(schedule, &Utc, after),
+    }
+}
+
+/// `at` 이전(포함)의 실행 시각 (최근 순)
+fn prev_until(
+    schedule: &cron::Schedule,
+    timezone: ScheduleTimezone,
+    at: DateTime<Utc>,
+) -> Box<dyn Iterator<Item = DateTime<Utc>> + '_> {
+    match timezone {
+        ScheduleTimezone::Local => prev_in(schedule, Local, at),
+        ScheduleTimezone::Utc => prev_in(schedule, Utc, at),
+    }
+}
+
+fn prev_in<Z: TimeZone + 'static>(
+    schedule: &cron::Schedule,
+    tz: Z,
+    at: DateTime<Utc>,
+) -> Box<dyn Iterator<Item = DateTime<Utc>> + '_> {
+    // 역방향 반복은 시작 시각을 포함하지 않으므로 1초 뒤부터
+    let start = (at + chrono::Duration::seconds(1)).with_timezone(&tz);
+    Box::new(
+        schedule
+            .after_owned(start)
+            .rev()
+            .map(|t| t.with_timezone(&Utc))
+            .skip_while(move |t| *t > at),
+    )
+}
+
+fn next_in<Z: TimeZone>(
+    schedule: &cron::Schedule,
+    tz: &Z,
+    after: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    schedule
+        .after(&after.with_timezone(tz))
+        .next()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::Duration;
+    use serde_json::json;
+
+    /// 10분마다 실행하는 UTC 스케줄
+    fn entry(catch_up: CatchUpPolicy) -> Entry {
+        let config = ScheduleConfig {
+            cron: "0 */10 * * * *".into(),
+            catch_up,
+            timezone: ScheduleTimezone::Utc,
+        };
+        let dsl = serde_json::from_value(json!({
+            "name": "test",
+            "trigger": {"type": "schedule", "config": {"cron": config.cron}},
+            "nodes": [{"id": "log", "type": "action", "config": {"action": "log"}}],
+        }))
+        .unwrap();
+        Entry {
+            schedule: config.parse().unwrap(),
+            state: ScheduleEntry {
+                workflow_id: "wf".into(),
+                dsl,
+                config,
+                next_run: None,
+                last_run: None,
+            },
+        }
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn every_ten_minutes(from: DateTime<Utc>, count: i32) -> Vec<(DateTime<Utc>, bool)> {
+        (0..count)
+            .map(|i| (from + Duration::minutes(10 * i as i64), true))
+            .collect()
+    }
+
+    #[test]
+    fn on_time_run_is_not_catch_up() {
+        let first = at("2026-01-01T00:10:00Z");
+        let now = first + Duration::seconds(5);
+        for policy in [
+            CatchUpPolicy::Skip,
+            CatchUpPolicy::RunOnce,
+            CatchUpPolicy::RunAll,
+        ] {
+            assert_eq!(entry(policy).due_runs(first, now), vec![(first, false)]);
+        }
+    }
+
+    #[test]
+    fn short_downtime_follows_policy() {
+        let first = at("2026-01-01T00:10:00Z");
+        // 00:10, 00:20, 00:30 놓침 + 00:40 정시
+        let now = at("2026-01-01T00:40:30Z");
+        let on_time = (at("2026-01-01T00:40:00Z"), false);
+
+        assert_eq!(
+            entry(CatchUpPolicy::Skip).due_runs(first, now),
+            vec![on_time]
+        );
+        assert_eq!(
+            entry(CatchUpPolicy::RunOnce).due_runs(first, now),
+            vec![(at("2026-01-01T00:30:00Z"), true), on_time]
+        );
+        let mut all = every_ten_minutes(first, 3);
+        all.push(on_time);
+        assert_eq!(entry(CatchUpPolicy::RunAll).due_runs(first, now), all);
+    }
+
+    #[test]
+    fn long_downtime_catches_up_latest_runs() {
+        // 1000번 넘게 놓친 뒤 정시가 아닌 시각에 재시작
+        let first = at("2026-01-01T00:10:00Z");
+        let now = at("2026-01-10T12:05:00Z");
+        let latest = at("2026-01-10T12:00:00Z");
+
+        assert!(entry(CatchUpPolicy::Skip).due_runs(first, now).is_empty());
+        assert_eq!(
+            entry(CatchUpPolicy::RunOnce).due_runs(first, now),
+            vec![(latest, true)]
+        );
+        let oldest = latest - Duration::minutes(10 * (MAX_CATCH_UP as i64 - 1));
+        assert_eq!(
+            entry(CatchUpPolicy::RunAll).due_runs(first, now),
+            every_ten_minutes(oldest, MAX_CATCH_UP as i32)
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! 시뮬레이션(what-if) 모드
+//!
+//! 모든 액션 핸들러를 기록기로 바꾼 뒤 과거 센서 데이터 행을 하나씩 입력으로
+//! 재생해, 실제로 실행했다면 어떤 액션이 언제 호출되었을지 보고한다.
+//! `stop_production_line` 같은 액션도 안전하게 시험할 수 있다.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use triflow_rules::RuleEngine;
+
+use crate::actions::{ActionCall, ActionError, ActionHandler, ActionRegistry};
+use crate::dsl::WorkflowDsl;
+use crate::executor::{WorkflowExecutor, WorkflowRun};
+use crate::state::RunStatus;
+
+/// 기록된 액션 호출
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedCall {
+    pub run_id: String,
+    pub node_id: String,
+    pub action: String,
+    pub parameters: Value,
+}
+
+/// 호출만 기록하고 실제 작업은 하지 않는 핸들러
+#[derive(Debug, Clone, Default)]
+pub struct RecordingHandler {
+    calls: Arc<Mutex<Vec<RecordedCall>>>,
+}
+
+impl RecordingHandler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 지금까지 기록된 호출을 꺼냄
+    pub fn take(&self) -> Vec<RecordedCall> {
+        std::mem::take(&mut *self.calls.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+impl ActionHandler for RecordingHandler {
+    fn handle(&self, call: &ActionCall<'_>) -> Result<Value, ActionError> {
+        self.calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(RecordedCall {
+                run_id: call.run_id.to_string(),
+                node_id: call.node_id.to_string(),
+                action: call.action.to_string(),
+                parameters: call.parameters.clone(),
+            });
+        Ok(json!({
+            "simulated": true,
+            "action": call.action,
+            "parameters": call.parameters,
+        }))
+    }
+}
+
+/// 시뮬레이션 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SimulationOptions {
+    /// 행의 시각 필드 (`core.sensor_data.recorded_at`)
+    pub time_field: String,
+}
+
+impl Default for SimulationOptions {
+    fn default() -> Self {
+        Self {
+            time_field: "recorded_at".to_string(),
+        }
+    }
+}
+
+/// 실행되었을 액션
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FiredAction {
+    /// 입력 행 위치
+    pub row: usize,
+    /// 입력 행의 시각 (시각 필드가 없으면 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<Value>,
+    pub node_id: String,
+    pub action: String,
+    pub parameters: Value,
+}
+
+/// 실행이 실패한 입력 행
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowFailure {
+    pub row: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<Value>,
+    pub error: String,
+}
+
+/// 시뮬레이션 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimulationReport {
+    pub workflow_name: String,
+    /// 재생한 행 수
+    pub rows: usize,
+    /// 액션이 하나 이상 실행되었을 행 수
+    pub triggered_rows: usize,
+    /// 액션 호출 (입력 행 순서)
+    pub fired: Vec<FiredAction>,
+    /// 액션 이름별 호출 수
+    pub action_counts: BTreeMap<String, usize>,
+    pub failures: Vec<RowFailure>,
+}
+
+/// 과거 데이터 행을 재생하며 워크플로우 시뮬레이션
+///
+/// 행마다 별도 실행으로 처리하며, 모든 액션은 [`RecordingHandler`]가 받는다.
+/// 승인 노드는 자동으로 승인한 것으로 본다 (`decided_by: "simulation"`).
+/// 행은 주어진 순서대로 재생하므로 과거 데이터는 시각순으로 넘긴다.
+pub fn simulate(
+    engine: &RuleEngine,
+    dsl: &WorkflowDsl,
+    rows: &[Value],
+    options: &SimulationOptions,
+) -> SimulationReport {
+    let recorder = RecordingHandler::new();
+    let mut actions = ActionRegistry::new();
+    actions.set_fallback(recorder.clone());
+    let executor = WorkflowExecutor::new(engine, &actions);
+
+    let mut report = SimulationReport {
+        workflow_name: dsl.name.clone(),
+        rows: rows.len(),
+        triggered_rows: 0,
+        fired: Vec::new(),
+        action_counts: BTreeMap::new(),
+        failures: Vec::new(),
+    };
+
+    for (row, input) in rows.iter().enumerate() {
+        let at = input.get(&options.time_field).cloned();
+        let mut run = WorkflowRun::new(format!("simulation-{row}"), dsl, input.clone());
+        loop {
+            while executor.step(dsl, &mut run, &mut |_| ()) {}
+            if run.status != RunStatus::WaitingApproval
+                || run.decide(true, "simulation", None).is_err()
+            {
+                break;
+            }
+        }
+
+        let calls = recorder.take();
+        if !calls.is_empty() {
+            report.triggered_rows += 1;
+        }
+        for call in calls {
+            *report.action_counts.entry(call.action.clone()).or_default() += 1;
+            report.fired.push(FiredAction {
+                row,
+                at: at.clone(),
+                node_id: call.node_id,
+                action: call.action,
+                parameters: call.parameters,
+            });
+        }
+
+        if run.status == RunStatus::Failed {
+            report.failures.push(RowFailure {
+                row,
+                at,
+                error: run.error.unwrap_or_default(),
+            });
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workflow(nodes: Value) -> WorkflowDsl {
+        serde_json::from_value(json!({
+            "name": "overheat",
+            "trigger": {"type": "manual"},
+            "nodes": nodes,
+        }))
+        .unwrap()
+    }
+
+    fn overheat() -> WorkflowDsl {
+        workflow(json!([
+            {"id": "check", "type": "condition", "config": {"condition": "temperature > 80"},
+             "next": ["approve"]},
+            {"id": "approve", "type": "approval", "config": {"message": "라인 정지?"},
+             "next": ["stop", "notify"]},
+            {"id": "stop", "type": "action",
+             "config": {"action": "stop_production_line", "parameters": {"line": "L1"}}},
+            {"id": "notify", "type": "action", "config": {"action": "notify"}},
+        ]))
+    }
+
+    fn rows() -> Vec<Value> {
+        vec![
+            json!({"recorded_at": "2026-01-01T00:00:00Z", "temperature": 70}),
+            json!({"recorded_at": "2026-01-01T00:01:00Z", "temperature": 85}),
+            json!({"recorded_at": "2026-01-01T00:02:00Z", "humidity": 40}),
+            json!({"recorded_at": "2026-01-01T00:03:00Z", "temperature": 90}),
+        ]
+    }
+
+    #[test]
+    fn replays_rows_through_condition_and_approval() {
+        let report = simulate(
+            &RuleEngine::new(),
+            &overheat(),
+            &rows(),
+            &SimulationOptions::default(),
+        );
+
+        assert_eq!(report.workflow_name, "overheat");
+        assert_eq!(report.rows, 4);
+        assert_eq!(report.triggered_rows, 2);
+        // 승인 노드는 자동 승인되어 뒤의 액션까지 실행된다
+        assert_eq!(
+            report
+                .fired
+                .iter()
+                .map(|f| (f.row, f.action.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "stop_production_line"),
+                (1, "notify"),
+                (3, "stop_production_line"),
+                (3, "notify"),
+            ]
+        );
+        assert_eq!(report.fired[0].at, Some(json!("2026-01-01T00:01:00Z")));
+        assert_eq!(report.fired[0].node_id, "stop");
+        assert_eq!(report.fired[0].parameters, json!({"line": "L1"}));
+        assert_eq!(
+            report.action_counts,
+            BTreeMap::from([
+                ("notify".to_string(), 2),
+                ("stop_production_line".to_string(), 2),
+            ])
+        );
+    }
+
+    #[test]
+    fn failed_rows_are_reported_with_their_time() {
+        let report = simulate(
+            &RuleEngine::new(),
+            &overheat(),
+            &rows(),
+            &SimulationOptions::default(),
+        );
+
+        assert_eq!(report.failures.len(), 1);
+        let failure = &report.failures[0];
+        assert_eq!(failure.row, 2);
+        assert_eq!(failure.at, Some(json!("2026-01-01T00:02:00Z")));
+        assert!(failure.error.contains("temperature"), "{}", failure.error);
+    }
+
+    #[test]
+    fn time_field_is_configurable() {
+        let rows = vec![json!({"ts": 1700000000, "temperature": 95})];
+        let options = SimulationOptions {
+            time_field: "ts".to_string(),
+        };
+        let report = simulate(&RuleEngine::new(), &overheat(), &rows, &options);
+        assert!(report.fired.iter().all(|f| f.at == Some(json!(1700000000))));
+
+        let report = simulate(
+            &RuleEngine::new(),
+            &overheat(),
+            &rows,
+            &SimulationOptions::default(),
+        );
+        assert!(report.fired.iter().all(|f| f.at.is_none()));
+    }
+}
@@ -0,0 +1,185 @@
+//! 워크플로우 인스턴스 상태 전이
+//!
+//! `docs/specs/B-5_Workflow_State_Machine_Spec.md` 2.2절의 생명주기를 따른다.
+//! 보상(compensation) 노드는 아직 지원하지 않으므로 관련 상태는 없고,
+//! 데스크톱 실행을 위한 `paused` 상태가 추가되어 있다.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// 워크플로우 실행 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Pending,
+    Running,
+    Paused,
+    WaitingApproval,
+    Completed,
+    Failed,
+    Cancelled,
+    TimedOut,
+}
+
+/// 상태 전이 요청
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Transition {
+    Start,
+    Pause,
+    Resume,
+    RequestApproval,
+    Approve,
+    Reject,
+    Expire,
+    Complete,
+    Fail,
+    Retry,
+    Cancel,
+}
+
+/// 허용되지 않는 상태 전이
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, thiserror::Error)]
+#[error("cannot {transition} a workflow run that is {from}")]
+pub struct TransitionError {
+    pub from: RunStatus,
+    pub transition: Transition,
+}
+
+impl RunStatus {
+    /// 전이 후 상태 (허용되지 않으면 오류)
+    pub fn apply(self, transition: Transition) -> Result<RunStatus, TransitionError> {
+        use RunStatus::*;
+        use Transition::*;
+
+        let next = match (self, transition) {
+            (Pending, Start) => Running,
+            (Running, Pause) => Paused,
+            (Paused, Resume) => Running,
+            (Running, RequestApproval) => WaitingApproval,
+            (WaitingApproval, Approve) => Running,
+            (WaitingApproval, Reject) => Cancelled,
+            (WaitingApproval, Expire) => TimedOut,
+            (Running, Complete) => Completed,
+            (Running | TimedOut, Fail) => Failed,
+            (Failed | TimedOut, Retry) => Running,
+            (Pending | Running | Paused | WaitingApproval | Failed, Cancel) => Cancelled,
+            (from, transition) => return Err(TransitionError { from, transition }),
+        };
+        Ok(next)
+    }
+
+    /// 더 이상 전이할 수 없는 상태 여부 (`failed`는 재시도 가능하므로 제외)
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Completed | Self::Cancelled)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Paused => "paused",
+            Self::WaitingApproval => "waiting_approval",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+            Self::TimedOut => "timed_out",
+        }
+    }
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Transition {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Pause => "pause",
+            Self::Resume => "resume",
+            Self::RequestApproval => "request approval for",
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+            Self::Expire => "expire",
+            Self::Complete => "complete",
+            Self::Fail => "fail",
+            Self::Retry => "retry",
+            Self::Cancel => "cancel",
+        }
+    }
+}
+
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RunStatus::*;
+    use super::Transition::*;
+    use super::*;
+
+    #[test]
+    fn follows_lifecycle() {
+        let steps = [
+            (Pending, Start, Running),
+            (Running, Pause, Paused),
+            (Paused, Resume, Running),
+            (Running, RequestApproval, WaitingApproval),
+            (WaitingApproval, Approve, Running),
+            (WaitingApproval, Reject, Cancelled),
+            (WaitingApproval, Expire, TimedOut),
+            (TimedOut, Fail, Failed),
+            (TimedOut, Retry, Running),
+            (Running, Fail, Failed),
+            (Failed, Retry, Running),
+            (Running, Complete, Completed),
+        ];
+        for (from, transition, to) in steps {
+            assert_eq!(from.apply(transition), Ok(to), "{from} --{transition}-->");
+        }
+    }
+
+    #[test]
+    fn cancel_is_allowed_until_run_ends() {
+        for from in [Pending, Running, Paused, WaitingApproval, Failed] {
+            assert_eq!(from.apply(Cancel), Ok(Cancelled), "{from}");
+        }
+        for from in [Completed, Cancelled, TimedOut] {
+            assert!(from.apply(Cancel).is_err(), "{from}");
+        }
+    }
+
+    #[test]
+    fn rejects_other_transitions() {
+        let rejected = [
+            (Pending, Complete),
+            (Paused, Complete),
+            (Paused, Pause),
+            (Running, Resume),
+            (Running, Retry),
+            (Failed, Complete),
+            (Completed, Fail),
+            (Completed, Start),
+            (Cancelled, Resume),
+            (Running, Approve),
+        ];
+        for (from, transition) in rejected {
+            assert_eq!(
+                from.apply(transition),
+                Err(TransitionError { from, transition })
+            );
+        }
+        assert_eq!(
+            Completed.apply(Pause).unwrap_err().to_string(),
+            "cannot pause a workflow run that is completed"
+        );
+        assert!(Completed.is_terminal() && Cancelled.is_terminal() && !Failed.is_terminal());
+    }
+}
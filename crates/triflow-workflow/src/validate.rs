@@ -0,0 +1,362 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::approval::ApprovalConfig;
+use crate::catalog::ActionCatalog;
+use crate::dsl::{NodeType, WorkflowDsl};
+
+/// 워크플로우 정의 검증 오류
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, thiserror::Error)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ValidationError {
+    #[error("workflow has no nodes")]
+    EmptyWorkflow,
+
+    #[error("duplicate node id '{node_id}'")]
+    DuplicateNodeId { node_id: String },
+
+    #[error("node '{node_id}' has an unsupported type")]
+    UnknownNodeType { node_id: String },
+
+    #[error("condition node '{node_id}' has no condition expression")]
+    MissingCondition { node_id: String },
+
+    #[error("action node '{node_id}' has no action name")]
+    MissingAction { node_id: String },
+
+    #[error("action '{action}' in node '{node_id}' is not in the action catalog")]
+    UnknownAction { node_id: String, action: String },
+
+    #[error("approval node '{node_id}' has an invalid config: {reason}")]
+    InvalidApproval { node_id: String, reason: String },
+
+    #[error("node '{node_id}' points to missing node '{target}'")]
+    DanglingNext { node_id: String, target: String },
+
+    #[error("node '{node_id}' is not reachable from the start node")]
+    UnreachableNode { node_id: String },
+
+    /// 조건/승인 노드 없이 액션만으로 이루어진 순환
+    #[error("cycle without exit condition: {}", node_ids.join(" -> "))]
+    CycleWithoutExit { node_ids: Vec<String> },
+}
+
+impl ValidationError {
+    /// 오류와 관련된 노드 ID (빌더에서 강조 표시용)
+    pub fn node_ids(&self) -> Vec<&str> {
+        match self {
+            Self::EmptyWorkflow => Vec::new(),
+            Self::DuplicateNodeId { node_id }
+            | Self::UnknownNodeType { node_id }
+            | Self::MissingCondition { node_id }
+            | Self::MissingAction { node_id }
+            | Self::UnknownAction { node_id, .. }
+            | Self::InvalidApproval { node_id, .. }
+            | Self::DanglingNext { node_id, .. }
+            | Self::UnreachableNode { node_id } => vec![node_id.as_str()],
+            Self::CycleWithoutExit { node_ids } => node_ids.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// 워크플로우 정의 검증
+///
+/// 첫 번째 노드를 시작 노드로 본다. `catalog`가 주어지면 액션 이름이
+/// 카탈로그에 있는지도 확인한다. 오류가 없으면 빈 목록을 반환한다.
+pub fn validate(dsl: &WorkflowDsl, catalog: Option<&ActionCatalog>) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    if dsl.nodes.is_empty() {
+        errors.push(ValidationError::EmptyWorkflow);
+        return errors;
+    }
+
+    // 중복 ID는 첫 번째 노드만 그래프에 포함
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (i, node) in dsl.nodes.iter().enumerate() {
+        if index.entry(node.id.as_str()).or_insert(i) != &i {
+            errors.push(ValidationError::DuplicateNodeId {
+                node_id: node.id.clone(),
+            });
+        }
+    }
+
+    for node in &dsl.nodes {
+        let node_id = node.id.clone();
+        match node.node_type {
+            NodeType::Unknown => errors.push(ValidationError::UnknownNodeType { node_id }),
+            NodeType::Condition if node.condition().is_none() => {
+                errors.push(ValidationError::MissingCondition { node_id })
+            }
+            NodeType::Condition => (),
+            NodeType::Action => match (node.action(), catalog) {
+                (None, _) => errors.push(ValidationError::MissingAction { node_id }),
+                (Some(action), Some(catalog)) if !catalog.contains(action) => {
+                    errors.push(ValidationError::UnknownAction {
+                        node_id,
+                        action: action.to_string(),
+                    })
+                }
+                _ => (),
+            },
+            NodeType::Approval => {
+                if let Err(e) = ApprovalConfig::from_node(node) {
+                    errors.push(ValidationError::InvalidApproval {
+                        node_id,
+                        reason: e.to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    // 인접 리스트 (존재하지 않는 대상은 오류로 보고하고 제외)
+    let mut edges: Vec<Vec<usize>> = vec![Vec::new(); dsl.nodes.len()];
+    for (i, node) in dsl.nodes.iter().enumerate() {
+        for target in &node.next {
+            match index.get(target.as_str()) {
+                Some(&j) => edges[i].push(j),
+                None => errors.push(ValidationError::DanglingNext {
+                    node_id: node.id.clone(),
+                    target: target.clone(),
+                }),
+            }
+        }
+    }
+
+    let reachable = reachable_from(&edges, 0);
+    for (i, node) in dsl.nodes.iter().enumerate() {
+        if !reachable.contains(&i) && index.get(node.id.as_str()) == Some(&i) {
+            errors.push(ValidationError::UnreachableNode {
+                node_id: node.id.clone(),
+            });
+        }
+    }
+
+    for component in cycles(&edges) {
+        let has_exit = component.iter().any(|&i| {
+            matches!(
+                dsl.nodes[i].node_type,
+                NodeType::Condition | NodeType::Approval
+            )
+        });
+        if !has_exit {
+            errors.push(ValidationError::CycleWithoutExit {
+                node_ids: component.iter().map(|&i| dsl.nodes[i].id.clone()).collect(),
+            });
+        }
+    }
+
+    errors
+}
+
+fn reachable_from(edges: &[Vec<usize>], start: usize) -> HashSet<usize> {
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(i) = queue.pop_front() {
+        for &j in &edges[i] {
+            if seen.insert(j) {
+                queue.push_back(j);
+            }
+        }
+    }
+    seen
+}
+
+/// 순환을 이루는 강한 연결 요소 (Tarjan), 각 요소는 노드 순서로 정렬
+fn cycles(edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct Tarjan<'a> {
+        edges: &'a [Vec<usize>],
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        next_index: usize,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, v: usize) {
+            self.index[v] = Some(self.next_index);
+            self.low[v] = self.next_index;
+            self.next_index += 1;
+            self.stack.push(v);
+            self.on_stack[v] = true;
+
+            for &w in &self.edges[v] {
+                match self.index[w] {
+                    None => {
+                        self.visit(w);
+                        self.low[v] = self.low[v].min(self.low[w]);
+                    }
+                    Some(index) if self.on_stack[w] => self.low[v] = self.low[v].min(index),
+                    Some(_) => (),
+                }
+            }
+
+            if Some(self.low[v]) == self.index[v] {
+                let mut component = Vec::new();
+                while let Some(w) = self.stack.pop() {
+                    self.on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                let is_cycle = component.len() > 1 || self.edges[v].contains(&v);
+                if is_cycle {
+                    component.sort_unstable();
+                    self.components.push(component);
+                }
+            }
+        }
+    }
+
+    let n = edges.len();
+    let mut tarjan = Tarjan {
+        edges,
+        index: vec![None; n],
+        low: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        next_index: 0,
+        components: Vec::new(),
+    };
+    for v in 0..n {
+        if tarjan.index[v].is_none() {
+            tarjan.visit(v);
+        }
+    }
+
+    tarjan.components.sort_by_key(|c| c[0]);
+    tarjan.components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn workflow(nodes: Value) -> WorkflowDsl {
+        serde_json::from_value(json!({
+            "name": "test",
+            "trigger": {"type": "manual"},
+            "nodes": nodes,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn valid_workflow_has_no_errors() {
+        let dsl = workflow(json!([
+            {"id": "check", "type": "condition", "config": {"condition": "temperature > 80"},
+             "next": ["alert"]},
+            {"id": "alert", "type": "action", "config": {"action": "send_slack"}},
+        ]));
+        let catalog = ActionCatalog::from_names(["send_slack"]);
+        assert!(validate(&dsl, Some(&catalog)).is_empty());
+    }
+
+    #[test]
+    fn reports_cycle_without_exit() {
+        let dsl = workflow(json!([
+            {"id": "a", "type": "action", "config": {"action": "log"}, "next": ["b"]},
+            {"id": "b", "type": "action", "config": {"action": "log"}, "next": ["a"]},
+        ]));
+        assert_eq!(
+            validate(&dsl, None),
+            vec![ValidationError::CycleWithoutExit {
+                node_ids: vec!["a".into(), "b".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn cycle_through_condition_has_exit() {
+        let dsl = workflow(json!([
+            {"id": "poll", "type": "action", "config": {"action": "log"}, "next": ["check"]},
+            {"id": "check", "type": "condition", "config": {"condition": "retry"},
+             "next": ["poll"]},
+        ]));
+        assert!(validate(&dsl, None).is_empty());
+    }
+
+    #[test]
+    fn self_loop_is_a_cycle() {
+        let dsl = workflow(json!([
+            {"id": "spin", "type": "action", "config": {"action": "log"}, "next": ["spin"]},
+        ]));
+        assert_eq!(
+            validate(&dsl, None),
+            vec![ValidationError::CycleWithoutExit {
+                node_ids: vec!["spin".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_unreachable_node() {
+        let dsl = workflow(json!([
+            {"id": "start", "type": "action", "config": {"action": "log"}},
+            {"id": "orphan", "type": "action", "config": {"action": "log"}},
+        ]));
+        assert_eq!(
+            validate(&dsl, None),
+            vec![ValidationError::UnreachableNode {
+                node_id: "orphan".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_dangling_edge() {
+        let dsl = workflow(json!([
+            {"id": "start", "type": "action", "config": {"action": "log"}, "next": ["missing"]},
+        ]));
+        assert_eq!(
+            validate(&dsl, None),
+            vec![ValidationError::DanglingNext {
+                node_id: "start".into(),
+                target: "missing".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_node_config_errors() {
+        let dsl = workflow(json!([
+            {"id": "check", "type": "condition", "next": ["act", "other", "dup"]},
+            {"id": "act", "type": "action", "config": {"action": "unknown"}},
+            {"id": "other", "type": "webhook"},
+            {"id": "dup", "type": "action"},
+            {"id": "dup", "type": "action", "config": {"action": "log"}},
+        ]));
+        let catalog = ActionCatalog::from_names(["log"]);
+        assert_eq!(
+            validate(&dsl, Some(&catalog)),
+            vec![
+                ValidationError::DuplicateNodeId {
+                    node_id: "dup".into()
+                },
+                ValidationError::MissingCondition {
+                    node_id: "check".into()
+                },
+                ValidationError::UnknownAction {
+                    node_id: "act".into(),
+                    action: "unknown".into(),
+                },
+                ValidationError::UnknownNodeType {
+                    node_id: "other".into()
+                },
+                ValidationError::MissingAction {
+                    node_id: "dup".into()
+                },
+            ]
+        );
+        assert_eq!(
+            validate(&workflow(json!([])), None),
+            vec![ValidationError::EmptyWorkflow]
+        );
+    }
+}
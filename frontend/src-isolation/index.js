@@ -0,0 +1,213 @@
+/**
+ * IPC 격리(isolation) 훅
+ *
+ * 웹뷰의 모든 invoke는 Rust로 전달되기 전에 메인 창과 분리된 이 iframe을 거친다.
+ * 메인 창에 스크립트가 주입되더라도 등록되지 않은 커맨드나 형식이 잘못된 인자는
+ * 여기서 걸러진다. 창별 커맨드 허용 목록은 Rust(`security.rs`)에서 한 번 더 확인한다.
+ */
+
+/** 거부된 호출을 Rust에 알리는 커맨드 (Rust에서 오류로 응답) */
+const REJECTED_COMMAND = 'ipc_rejected';
+
+/** 인자 최대 크기 (JSON 기준) */
+const MAX_PAYLOAD_LENGTH = 8 * 1024 * 1024;
+
+const API_METHODS = ['GET', 'POST', 'PUT', 'PATCH', 'DELETE'];
+
+/** `RunStatus` 값 (실행 이력 조회 필터) */
+const RUN_STATUSES = [
+  'pending',
+  'running',
+  'paused',
+  'waiting_approval',
+  'completed',
+  'failed',
+  'cancelled',
+  'timed_out',
+];
+
+/** 워크플로우 트리거 종류 */
+const TRIGGER_TYPES = ['event', 'schedule', 'manual'];
+
+/** 실행 계획 설명 언어 */
+const PLAN_LANGUAGES = ['ko', 'en'];
+
+/** ID, 버전 등 식별자 최대 길이 */
+const MAX_ID_LENGTH = 128;
+/** 승인 코멘트 최대 길이 */
+const MAX_COMMENT_LENGTH = 2000;
+/** 워크플로우 노드 최대 수 */
+const MAX_NODES = 500;
+/** 배치 평가/시뮬레이션 입력 행 최대 수 */
+const MAX_ROWS = 100000;
+/** 목록 인자(동기화 대상 워크플로우, 테스트 케이스, 입력 필드 등) 최대 길이 */
+const MAX_ITEMS = 1000;
+
+const isObject = (value) =>
+  value !== null && typeof value === 'object' && !Array.isArray(value);
+
+const isString = (value) => typeof value === 'string';
+
+const isOptional = (value, check) => value === undefined || value === null || check(value);
+
+const isId = (value) => isString(value) && value.length > 0 && value.length <= MAX_ID_LENGTH;
+
+const isCount = (value) => Number.isInteger(value) && value >= 0;
+
+const isArrayOf = (value, max, check) =>
+  Array.isArray(value) && value.length <= max && value.every(check);
+
+/** 인자를 받지 않는 커맨드 */
+const noArgs = (args) => Object.keys(args).length === 0;
+
+/** 워크플로우 DSL (`name`, `trigger`, `nodes`; 노드 종류는 Rust 검증에서 보고) */
+const isDsl = (dsl) =>
+  isObject(dsl) &&
+  isString(dsl.name) &&
+  isObject(dsl.trigger) &&
+  TRIGGER_TYPES.includes(dsl.trigger.type) &&
+  isArrayOf(dsl.nodes, MAX_NODES, (node) => isObject(node) && isId(node.id) && isString(node.type));
+
+/** `GET /workflows/actions` 응답 */
+const isCatalog = (catalog) =>
+  isObject(catalog) && isArrayOf(catalog.actions, MAX_ITEMS, (a) => isObject(a) && isString(a.name));
+
+const isRows = (rows) => isArrayOf(rows, MAX_ROWS, isObject);
+
+/** 트리거 동기화 대상 (`{workflow_id, dsl}` 목록) */
+const isTriggeredWorkflows = (workflows) =>
+  isArrayOf(workflows, MAX_ITEMS, (w) => isObject(w) && isId(w.workflow_id) && isDsl(w.dsl));
+
+const isHistoryQuery = (query) =>
+  isObject(query) &&
+  isOptional(query.workflow_id, isId) &&
+  isOptional(query.workflow_name, isString) &&
+  isOptional(query.statuses, (statuses) =>
+    isArrayOf(statuses, RUN_STATUSES.length, (status) => RUN_STATUSES.includes(status))
+  ) &&
+  isOptional(query.from, isString) &&
+  isOptional(query.to, isString) &&
+  isOptional(query.limit, isCount) &&
+  isOptional(query.offset, isCount);
+
+const isSensorEvent = (event) =>
+  isObject(event) &&
+  isOptional(event.sensor_id, isId) &&
+  isId(event.line_code) &&
+  isId(event.sensor_type) &&
+  Number.isFinite(event.value) &&
+  isOptional(event.unit, isString) &&
+  isOptional(event.recorded_at, isString) &&
+  isOptional(event.metadata, isObject);
+
+/** 실행 제어 (일시정지/재개/재시도/취소, 조회) */
+const runControl = ({ runId, ...rest }) => isId(runId) && noArgs(rest);
+
+/** 승인/거부 (결정한 사람은 Rust가 로그인 세션에서 정함) */
+const runDecision = ({ runId, comment, ...rest }) =>
+  isId(runId) &&
+  isOptional(comment, (c) => isString(c) && c.length <= MAX_COMMENT_LENGTH) &&
+  noArgs(rest);
+
+/** 커맨드별 인자 검사 (여기 없는 앱 커맨드는 호출할 수 없음) */
+const VALIDATORS = {
+  login: ({ email, password }) => isString(email) && isString(password),
+  clear_credentials: noArgs,
+  refresh_credentials: noArgs,
+  api_request: ({ request }) =>
+    isObject(request) &&
+    API_METHODS.includes(request.method) &&
+    isString(request.path) &&
+    request.path.startsWith('/api/') &&
+    !request.path.includes('..') &&
+    isOptional(request.timeout_ms, (ms) => Number.isInteger(ms) && ms > 0),
+  start_local_backend: ({ port }) =>
+    isOptional(port, (p) => Number.isInteger(p) && p >= 1024 && p <= 65535),
+  stop_local_backend: noArgs,
+  local_backend_status: noArgs,
+  open_logs_folder: noArgs,
+  check_backend_health: noArgs,
+  execute_ruleset: ({ script, input, rulesetId, rulesetName, version, trace }) =>
+    isString(script) &&
+    isObject(input) &&
+    isOptional(rulesetId, isId) &&
+    isOptional(rulesetName, isString) &&
+    isOptional(version, isId) &&
+    isOptional(trace, (t) => typeof t === 'boolean'),
+  execute_ruleset_batch: ({ script, rows, rulesetId, version }) =>
+    isString(script) && isRows(rows) && isOptional(rulesetId, isId) && isOptional(version, isId),
+  validate_ruleset: ({ script, inputFields }) =>
+    isString(script) && isOptional(inputFields, (f) => isArrayOf(f, MAX_ITEMS, isString)),
+  test_ruleset: ({ script, tests }) =>
+    isString(script) &&
+    isArrayOf(tests, MAX_ITEMS, (t) => isObject(t) && isString(t.name) && isObject(t.input)),
+  diff_ruleset: ({ oldScript, newScript, samples }) =>
+    isString(oldScript) && isString(newScript) && isOptional(samples, isRows),
+  invalidate_ruleset_cache: ({ rulesetId }) => isId(rulesetId),
+  ruleset_cache_stats: noArgs,
+  validate_workflow: ({ dsl, catalog }) => isDsl(dsl) && isOptional(catalog, isCatalog),
+  render_workflow_plan: ({ dsl, catalog, language }) =>
+    isDsl(dsl) &&
+    isOptional(catalog, isCatalog) &&
+    isOptional(language, (l) => PLAN_LANGUAGES.includes(l)),
+  run_workflow_local: ({ dsl, input }) => isDsl(dsl) && isOptional(input, isObject),
+  simulate_workflow: ({ dsl, rows, options }) =>
+    isDsl(dsl) &&
+    isRows(rows) &&
+    isOptional(options, (o) => isObject(o) && isOptional(o.time_field, isString)),
+  start_workflow_run: ({ dsl, input }) => isDsl(dsl) && isOptional(input, isObject),
+  pause_workflow_run: runControl,
+  resume_workflow_run: runControl,
+  retry_workflow_run: runControl,
+  cancel_workflow_run: runControl,
+  approve_workflow_run: runDecision,
+  reject_workflow_run: runDecision,
+  get_workflow_run: runControl,
+  list_workflow_runs: noArgs,
+  query_workflow_history: ({ query }) => isOptional(query, isHistoryQuery),
+  get_workflow_run_timeline: runControl,
+  sync_workflow_schedules: ({ workflows }) => isTriggeredWorkflows(workflows),
+  list_workflow_schedules: noArgs,
+  sync_workflow_events: ({ workflows }) => isTriggeredWorkflows(workflows),
+  list_event_subscriptions: noArgs,
+  publish_sensor_event: ({ event }) => isSensorEvent(event),
+};
+
+/** 호출이 허용되지 않으면 이유, 허용되면 null */
+function rejection(cmd, payload) {
+  if (typeof cmd !== 'string') {
+    return 'command must be a string';
+  }
+  // 플러그인 커맨드는 capabilities 권한으로 제한된다
+  if (cmd.startsWith('plugin:')) {
+    return null;
+  }
+  if (!Object.prototype.hasOwnProperty.call(VALIDATORS, cmd)) {
+    return 'unknown command';
+  }
+  const args = payload === undefined ? {} : payload;
+  if (!isObject(args)) {
+    return 'arguments must be an object';
+  }
+  if (JSON.stringify(args).length > MAX_PAYLOAD_LENGTH) {
+    return 'arguments are too large';
+  }
+  try {
+    return VALIDATORS[cmd](args) ? null : 'invalid arguments';
+  } catch {
+    return 'invalid arguments';
+  }
+}
+
+window.__TAURI_ISOLATION_HOOK__ = (message) => {
+  const reason = rejection(message.cmd, message.payload);
+  if (reason === null) {
+    return message;
+  }
+  // 예외를 던지면 호출이 응답 없이 멈추므로, 거부 커맨드로 바꿔 오류를 돌려받게 한다
+  return {
+    ...message,
+    cmd: REJECTED_COMMAND,
+    payload: { command: String(message.cmd), reason },
+  };
+};
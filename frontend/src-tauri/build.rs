@@ -1,3 +1,45 @@
+use std::env;
+
+use serde_json::{json, Value};
+
+/// 메인 창 Content Security Policy
+///
+/// 웹뷰는 에이전트가 만든 마크다운과 차트를 그리므로 스크립트와 스타일은 앱 번들에서만
+/// 불러온다 (인라인 스크립트는 Tauri가 빌드 시 해시를 추가). 백엔드 요청은 `api_request`
+/// 프록시를 거치므로 connect-src에는 IPC만 허용한다.
+const CSP: &[(&str, &str)] = &[
+    ("default-src", "'self'"),
+    ("script-src", "'self'"),
+    ("style-src", "'self'"),
+    ("img-src", "'self' data: blob:"),
+    ("font-src", "'self' data:"),
+    // Windows/Android는 http://ipc.localhost, 그 밖에는 ipc: 스킴
+    ("connect-src", "ipc: http://ipc.localhost"),
+    ("object-src", "'none'"),
+    ("base-uri", "'none'"),
+    ("form-action", "'none'"),
+    ("frame-ancestors", "'none'"),
+];
+
 fn main() {
+    // tauri.conf.json의 CSP를 빌드 시 생성한 값으로 덮어쓴다. Tauri는 `TAURI_CONFIG`를
+    // 설정 파일에 병합하므로 CLI가 넘긴 값(`--config`)이 있으면 그 위에 추가한다.
+    let mut config = env::var("TAURI_CONFIG")
+        .map(|config| serde_json::from_str(&config).expect("TAURI_CONFIG must be JSON"))
+        .unwrap_or_else(|_| json!({}));
+    config["app"]["security"]["csp"] = Value::String(csp());
+    let config = config.to_string();
+
+    // tauri-build 검증과 generate_context! 양쪽에서 같은 설정을 보도록
+    env::set_var("TAURI_CONFIG", &config);
+    println!("cargo:rustc-env=TAURI_CONFIG={config}");
+
     tauri_build::build()
 }
+
+fn csp() -> String {
+    CSP.iter()
+        .map(|(directive, sources)| format!("{directive} {sources}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,484 @@
+//! 백엔드 API 프록시
+//!
+//! 웹뷰는 백엔드에 직접 요청하지 않고 `api_request` 커맨드를 거친다. 인증 헤더는
+//! [`TokenManager`]의 access token으로 Rust에서 붙이고, 401이면 한 번 갱신한 뒤
+//! 다시 보낸다. 시간 제한과 재시도 정책을 여기서 일괄 적용하며, 백엔드 오류는
+//! [`ApiError`]로 구분해 돌려준다.
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::State;
+use thiserror::Error;
+
+use crate::auth::{AuthError, TokenManager};
+
+/// 기본 요청 시간 제한
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// 요청별 시간 제한 상한 (에이전트 응답처럼 오래 걸리는 요청용)
+pub const MAX_TIMEOUT: Duration = Duration::from_secs(300);
+/// 일시적인 오류의 최대 재시도 횟수
+pub const MAX_RETRIES: u32 = 2;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(300);
+
+/// 프록시할 수 있는 경로 접두사
+const ALLOWED_PREFIX: &str = "/api/";
+
+/// HTTP 메서드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ApiMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl ApiMethod {
+    /// 같은 요청을 다시 보내도 안전한지 여부
+    fn is_idempotent(self) -> bool {
+        matches!(self, Self::Get | Self::Put | Self::Delete)
+    }
+}
+
+impl From<ApiMethod> for Method {
+    fn from(method: ApiMethod) -> Self {
+        match method {
+            ApiMethod::Get => Method::GET,
+            ApiMethod::Post => Method::POST,
+            ApiMethod::Put => Method::PUT,
+            ApiMethod::Patch => Method::PATCH,
+            ApiMethod::Delete => Method::DELETE,
+        }
+    }
+}
+
+/// `api_request` 요청
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiRequest {
+    pub method: ApiMethod,
+    /// `/api/`로 시작하는 경로 (쿼리 문자열 포함 가능)
+    pub path: String,
+    #[serde(default)]
+    pub body: Option<Value>,
+    /// 시간 제한 (생략하면 [`DEFAULT_TIMEOUT`], 최대 [`MAX_TIMEOUT`])
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+/// API 요청 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록 `{"kind": ..., "detail": ...}` 형태로
+/// 직렬화된다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum ApiError {
+    /// 허용되지 않은 경로 등 잘못된 요청 (전송하지 않음)
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    /// 401 (토큰 갱신 후에도 실패하거나 로그인하지 않음)
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    /// 422 요청 검증 실패 (FastAPI `detail` 그대로)
+    #[error("validation failed")]
+    Validation(Value),
+    /// 그 밖의 4xx
+    #[error("request failed ({status}): {message}")]
+    Client { status: u16, message: String },
+    /// 5xx (재시도 후에도 실패)
+    #[error("server error ({status}): {message}")]
+    Server { status: u16, message: String },
+    #[error("request timed out")]
+    Timeout,
+    /// 서버에 연결할 수 없음
+    #[error("network error: {0}")]
+    Network(String),
+    #[error("invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+/// 백엔드 API 클라이언트 (앱 전체에서 하나)
+#[derive(Clone)]
+pub struct ApiClient {
+    client: reqwest::Client,
+    base_url: Url,
+    auth: TokenManager,
+}
+
+impl ApiClient {
+    pub fn new(base_url: &str, auth: TokenManager) -> Result<Self, ApiError> {
+        let base_url = Url::parse(base_url).map_err(|e| ApiError::InvalidRequest(e.to_string()))?;
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(|e| ApiError::Network(e.to_string()))?;
+        Ok(Self {
+            client,
+            base_url,
+            auth,
+        })
+    }
+
+    /// 요청 전송 (인증, 시간 제한, 재시도 적용)
+    ///
+    /// 응답 본문은 JSON으로 돌려주며, 본문이 없으면 `null`, JSON이 아니면 문자열이다.
+    pub async fn request(&self, request: &ApiRequest) -> Result<Value, ApiError> {
+        let url = self.url(&request.path)?;
+        let timeout = request
+            .timeout_ms
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_TIMEOUT)
+            .min(MAX_TIMEOUT);
+
+        let mut refreshed = false;
+        let mut attempt = 0;
+        loop {
+            let token = self.auth.access_token();
+            let error = match self
+                .send(request, url.clone(), timeout, token.as_deref())
+                .await
+            {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+            match &error {
+                // 만료된 access token이면 한 번만 갱신하고 다시 보냄
+                Failure::Status(ApiError::Unauthorized(_)) if token.is_some() && !refreshed => {
+                    refreshed = true;
+                    match self.auth.refresh().await {
+                        Ok(_) => continue,
+                        Err(AuthError::Unavailable(e)) => return Err(ApiError::Network(e)),
+                        Err(e) => return Err(ApiError::Unauthorized(e.to_string())),
+                    }
+                }
+                failure if attempt < MAX_RETRIES && failure.is_retryable(request.method) => {
+                    tokio::time::sleep(RETRY_BASE_DELAY * 2u32.pow(attempt)).await;
+                    attempt += 1;
+                }
+                _ => return Err(error.into()),
+            }
+        }
+    }
+
+    /// 요청 경로를 백엔드 URL로 (다른 호스트나 `/api/` 밖으로 빠져나가는 경로는 거부)
+    fn url(&self, path: &str) -> Result<Url, ApiError> {
+        if !path.starts_with(ALLOWED_PREFIX) || path.contains("..") {
+            return Err(ApiError::InvalidRequest(format!(
+                "path must start with {ALLOWED_PREFIX} and must not contain '..': {path}"
+            )));
+        }
+        let url = self
+            .base_url
+            .join(path)
+            .map_err(|e| ApiError::InvalidRequest(e.to_string()))?;
+        // `%2e%2e`처럼 인코딩된 상위 경로는 join에서 정규화되므로 결과 경로를 다시 확인
+        if url.origin() != self.base_url.origin() || !url.path().starts_with(ALLOWED_PREFIX) {
+            return Err(ApiError::InvalidRequest(format!(
+                "path leaves the backend: {path}"
+            )));
+        }
+        Ok(url)
+    }
+
+    async fn send(
+        &self,
+        request: &ApiRequest,
+        url: Url,
+        timeout: Duration,
+        token: Option<&str>,
+    ) -> Result<Value, Failure> {
+        let mut builder = self
+            .client
+            .request(request.method.into(), url)
+            .timeout(timeout);
+        if let Some(token) = token {
+            builder = builder.bearer_auth(token);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.json(body);
+        }
+
+        let response = builder.send().await.map_err(Failure::Transport)?;
+        let status = response.status();
+        let text = response.text().await.map_err(Failure::Transport)?;
+        let body = if text.trim().is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_str(&text).unwrap_or(Value::String(text))
+        };
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(Failure::Status(status_error(status, body)))
+        }
+    }
+}
+
+/// 한 번의 전송 실패 (재시도 여부 판단용)
+enum Failure {
+    Transport(reqwest::Error),
+    Status(ApiError),
+}
+
+impl Failure {
+    fn is_retryable(&self, method: ApiMethod) -> bool {
+        match self {
+            // 연결 자체가 안 됐으면 요청이 전달되지 않았으므로 항상 안전
+            Self::Transport(e) if e.is_connect() => true,
+            Self::Transport(e) => e.is_timeout() && method.is_idempotent(),
+            Self::Status(ApiError::Server { status, .. }) => {
+                matches!(*status, 502..=504) && method.is_idempotent()
+            }
+            Self::Status(_) => false,
+        }
+    }
+}
+
+impl From<Failure> for ApiError {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Transport(e) if e.is_timeout() => Self::Timeout,
+            Failure::Transport(e) if e.is_decode() => Self::InvalidResponse(e.to_string()),
+            Failure::Transport(e) => Self::Network(e.to_string()),
+            Failure::Status(error) => error,
+        }
+    }
+}
+
+/// 백엔드 오류 응답을 [`ApiError`]로 (FastAPI `{"detail": ...}`)
+fn status_error(status: StatusCode, body: Value) -> ApiError {
+    let detail = match body {
+        Value::Object(mut fields) => fields.remove("detail").unwrap_or(Value::Object(fields)),
+        other => other,
+    };
+    let message = match &detail {
+        Value::String(message) => message.clone(),
+        Value::Null => status
+            .canonical_reason()
+            .unwrap_or("unknown error")
+            .to_string(),
+        other => other.to_string(),
+    };
+    match status {
+        StatusCode::UNAUTHORIZED => ApiError::Unauthorized(message),
+        StatusCode::FORBIDDEN => ApiError::Forbidden(message),
+        StatusCode::NOT_FOUND => ApiError::NotFound(message),
+        StatusCode::UNPROCESSABLE_ENTITY => ApiError::Validation(detail),
+        s if s.is_server_error() => ApiError::Server {
+            status: s.as_u16(),
+            message,
+        },
+        s => ApiError::Client {
+            status: s.as_u16(),
+            message,
+        },
+    }
+}
+
+/// 백엔드 API 요청 프록시
+#[tauri::command]
+pub async fn api_request(
+    api: State<'_, ApiClient>,
+    request: ApiRequest,
+) -> Result<Value, ApiError> {
+    api.request(&request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use serde_json::json;
+
+    /// 요청마다 `respond(request_line)`의 (status, body)를 돌려주는 로컬 백엔드
+    ///
+    /// 받은 요청 줄(`GET /api/... HTTP/1.1`)을 채널로 보낸다.
+    fn mock_backend(
+        respond: impl Fn(&str) -> (u16, String) + Send + 'static,
+    ) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (requests, received) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request_line = request_line.trim_end().to_string();
+                let (status, response) = respond(&request_line);
+                let _ = requests.send(request_line);
+                // 응답 전에 클라이언트가 시간 제한으로 끊었으면 쓰기 실패는 무시
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                );
+            }
+        });
+        (url, received)
+    }
+
+    fn client(url: &str) -> ApiClient {
+        ApiClient::new(url, TokenManager::new(url, None, |_| ())).unwrap()
+    }
+
+    fn request(method: ApiMethod, path: &str) -> ApiRequest {
+        ApiRequest {
+            method,
+            path: path.to_string(),
+            body: None,
+            timeout_ms: None,
+        }
+    }
+
+    /// 처음 `failures`번은 503, 그 뒤로는 200
+    fn flaky_backend(failures: usize) -> (String, mpsc::Receiver<String>) {
+        let count = std::sync::atomic::AtomicUsize::new(0);
+        mock_backend(move |_| {
+            if count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < failures {
+                (503, json!({ "detail": "warming up" }).to_string())
+            } else {
+                (200, json!({ "ok": true }).to_string())
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn get_is_retried_on_server_errors() {
+        let (url, requests) = flaky_backend(2);
+        let value = client(&url)
+            .request(&request(ApiMethod::Get, "/api/v1/rulesets?limit=5"))
+            .await
+            .unwrap();
+        assert_eq!(value, json!({ "ok": true }));
+        let lines: Vec<String> = requests.try_iter().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines
+            .iter()
+            .all(|l| l == "GET /api/v1/rulesets?limit=5 HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn get_gives_up_after_max_retries() {
+        let (url, requests) = flaky_backend(usize::MAX);
+        let result = client(&url)
+            .request(&request(ApiMethod::Get, "/api/v1/rulesets"))
+            .await;
+        assert!(
+            matches!(result, Err(ApiError::Server { status: 503, ref message }) if message == "warming up")
+        );
+        assert_eq!(requests.try_iter().count(), MAX_RETRIES as usize + 1);
+    }
+
+    #[tokio::test]
+    async fn post_is_not_retried() {
+        let (url, requests) = flaky_backend(1);
+        let mut post = request(ApiMethod::Post, "/api/v1/rulesets");
+        post.body = Some(json!({ "name": "temp" }));
+        let result = client(&url).request(&post).await;
+        assert!(matches!(result, Err(ApiError::Server { status: 503, .. })));
+        assert_eq!(
+            requests.try_iter().collect::<Vec<_>>(),
+            vec!["POST /api/v1/rulesets HTTP/1.1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn slow_response_times_out() {
+        let (url, _requests) = mock_backend(|_| {
+            thread::sleep(Duration::from_millis(500));
+            (200, "{}".to_string())
+        });
+        let mut post = request(ApiMethod::Post, "/api/v1/agents/chat");
+        post.timeout_ms = Some(50);
+        let result = client(&url).request(&post).await;
+        assert!(matches!(result, Err(ApiError::Timeout)), "{result:?}");
+    }
+
+    #[tokio::test]
+    async fn non_json_body_is_returned_as_string() {
+        let (url, _requests) = mock_backend(|_| (200, "plain text".to_string()));
+        let value = client(&url)
+            .request(&request(ApiMethod::Get, "/api/v1/health"))
+            .await
+            .unwrap();
+        assert_eq!(value, json!("plain text"));
+    }
+
+    #[test]
+    fn maps_status_to_error() {
+        let error =
+            |status: u16, body: Value| status_error(StatusCode::from_u16(status).unwrap(), body);
+        let detail = json!({ "detail": "nope" });
+
+        assert!(matches!(error(401, detail.clone()), ApiError::Unauthorized(m) if m == "nope"));
+        assert!(matches!(error(403, detail.clone()), ApiError::Forbidden(m) if m == "nope"));
+        assert!(matches!(error(404, Value::Null), ApiError::NotFound(m) if m == "Not Found"));
+        let fields = json!([{ "loc": ["body", "name"], "msg": "field required" }]);
+        assert!(
+            matches!(error(422, json!({ "detail": fields.clone() })), ApiError::Validation(d) if d == fields)
+        );
+        assert!(matches!(
+            error(409, detail.clone()),
+            ApiError::Client { status: 409, message } if message == "nope"
+        ));
+        assert!(matches!(
+            error(500, json!({ "error": "boom" })),
+            ApiError::Server { status: 500, message } if message == r#"{"error":"boom"}"#
+        ));
+        assert!(matches!(
+            error(502, json!("bad gateway")),
+            ApiError::Server { status: 502, message } if message == "bad gateway"
+        ));
+    }
+
+    #[test]
+    fn rejects_paths_outside_the_api() {
+        let api = client("http://127.0.0.1:8000");
+        assert_eq!(
+            api.url("/api/v1/rulesets?q=a").unwrap().as_str(),
+            "http://127.0.0.1:8000/api/v1/rulesets?q=a"
+        );
+        for path in [
+            "/health",
+            "api/v1/rulesets",
+            "/api/../admin",
+            "/api/%2e%2e/admin",
+            "/api/%2E%2E/%2e%2e/etc",
+            "//evil.example/api/x",
+            "http://evil.example/api/x",
+        ] {
+            assert!(
+                matches!(api.url(path), Err(ApiError::InvalidRequest(_))),
+                "{path} should be rejected"
+            );
+        }
+    }
+}
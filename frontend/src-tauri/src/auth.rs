@@ -0,0 +1,663 @@
+//! 토큰 갱신 관리자
+//!
+//! `POST /api/v1/auth/login`으로 받은 토큰 쌍을 Rust 쪽에서 보관하고, access token의 JWT `exp`보다
+//! [`REFRESH_MARGIN`] 먼저 백그라운드 태스크에서 `POST /api/v1/auth/refresh`로
+//! 갱신한다. 창이 숨겨져 웹뷰 타이머가 느려져도 갱신은 멈추지 않는다.
+//! 결과는 `auth://refreshed`, `auth://expired` 이벤트로 UI에 알린다.
+//! refresh token은 웹뷰로 내보내지 않는다.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Emitter, State};
+use thiserror::Error;
+use tokio::sync::Notify;
+
+use crate::credentials::{CredentialError, CredentialStore, Credentials};
+
+/// 갱신 성공 이벤트 이름 (payload: `AuthSession`)
+pub const REFRESHED_EVENT: &str = "auth://refreshed";
+/// 세션 만료 이벤트 이름 (payload: `{"reason": ...}`)
+pub const EXPIRED_EVENT: &str = "auth://expired";
+
+/// access token 만료 몇 초 전에 갱신할지
+pub const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+/// 서버에 연결하지 못했을 때 다시 시도하기까지의 대기 시간
+const RETRY_DELAY: Duration = Duration::from_secs(15);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 백엔드 주소 (`TRIFLOW_API_URL` > 빌드 시 `VITE_API_URL` > `http://localhost:8000`)
+pub fn api_base_url() -> String {
+    std::env::var("TRIFLOW_API_URL")
+        .ok()
+        .or_else(|| option_env!("VITE_API_URL").map(str::to_string))
+        .unwrap_or_else(|| "http://localhost:8000".to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// JWT의 `exp` 클레임
+///
+/// 서명은 검증하지 않는다. 갱신 시점을 정하는 데만 쓰고, 토큰의 유효성은
+/// 서버가 판단한다.
+pub fn token_expiry(token: &str) -> Option<DateTime<Utc>> {
+    let payload = token.split('.').nth(1)?;
+    let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    let claims: Value = serde_json::from_slice(&bytes).ok()?;
+    DateTime::from_timestamp(claims.get("exp")?.as_f64()? as i64, 0)
+}
+
+/// 웹뷰에 공개하는 세션 정보 (refresh token 제외)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuthSession {
+    pub access_token: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub user: Value,
+}
+
+impl From<&Credentials> for AuthSession {
+    fn from(credentials: &Credentials) -> Self {
+        Self {
+            access_token: credentials.access_token.clone(),
+            expires_at: token_expiry(&credentials.access_token),
+            user: credentials.user.clone(),
+        }
+    }
+}
+
+/// 토큰 갱신 결과 알림
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthEvent {
+    Refreshed(AuthSession),
+    /// 세션이 끝남 (refresh token 만료/거부) — 다시 로그인해야 함
+    Expired {
+        reason: String,
+    },
+}
+
+/// 인증 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록 `{"kind": ..., "detail": ...}` 형태로
+/// 직렬화된다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum AuthError {
+    #[error("not logged in")]
+    NotLoggedIn,
+    /// 로그인 요청을 서버가 거부함 (이메일/비밀번호 오류)
+    #[error("invalid credentials: {0}")]
+    InvalidCredentials(String),
+    /// 서버가 refresh token을 거부함 (세션 만료)
+    #[error("session expired: {0}")]
+    Expired(String),
+    /// 서버에 연결할 수 없거나 일시적인 서버 오류 (나중에 다시 시도)
+    #[error("auth server unavailable: {0}")]
+    Unavailable(String),
+    #[error("unexpected auth server response: {0}")]
+    InvalidResponse(String),
+    #[error(transparent)]
+    Credentials(#[from] CredentialError),
+}
+
+/// `POST /api/v1/auth/login` 응답
+#[derive(Deserialize)]
+struct LoginResponse {
+    access_token: String,
+    refresh_token: String,
+    #[serde(default)]
+    user: Value,
+}
+
+/// `POST /api/v1/auth/refresh` 응답
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    /// 서버가 refresh token을 교체하면 새 값
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+type Listener = Box<dyn Fn(AuthEvent) + Send + Sync>;
+
+/// 토큰 쌍 보관 및 백그라운드 갱신
+///
+/// 복제해도 같은 세션을 공유한다. [`TokenManager::run`]을 async 런타임에서 실행해야
+/// 백그라운드 갱신이 동작한다.
+#[derive(Clone)]
+pub struct TokenManager {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    client: reqwest::Client,
+    login_url: String,
+    refresh_url: String,
+    /// None이면 메모리에만 보관
+    store: Option<CredentialStore>,
+    session: Mutex<Option<Credentials>>,
+    /// 갱신 요청을 하나씩 보내기 위한 잠금
+    refreshing: tokio::sync::Mutex<()>,
+    /// 세션이 바뀌면 백그라운드 태스크가 갱신 시점을 다시 계산
+    wake: Notify,
+    listener: Listener,
+}
+
+impl TokenManager {
+    pub fn new(
+        base_url: &str,
+        store: Option<CredentialStore>,
+        listener: impl Fn(AuthEvent) + Send + Sync + 'static,
+    ) -> Self {
+        let base_url = base_url.trim_end_matches('/');
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            inner: Arc::new(Inner {
+                client,
+                login_url: format!("{base_url}/api/v1/auth/login"),
+                refresh_url: format!("{base_url}/api/v1/auth/refresh"),
+                store,
+                session: Mutex::new(None),
+                refreshing: tokio::sync::Mutex::new(()),
+                wake: Notify::new(),
+                listener: Box::new(listener),
+            }),
+        }
+    }
+
+    /// 현재 세션 (메모리에 없으면 저장소에서 불러옴)
+    pub fn load(&self) -> Result<Option<AuthSession>, AuthError> {
+        if let Some(session) = self.session() {
+            return Ok(Some(session));
+        }
+        let Some(store) = &self.inner.store else {
+            return Ok(None);
+        };
+        let Some(credentials) = store.load()? else {
+            return Ok(None);
+        };
+        let session = AuthSession::from(&credentials);
+        *self.inner.lock() = Some(credentials);
+        self.inner.wake.notify_one();
+        Ok(Some(session))
+    }
+
+    /// 로그인하고 받은 토큰 쌍 보관
+    pub async fn login(&self, email: &str, password: &str) -> Result<AuthSession, AuthError> {
+        let response = self
+            .inner
+            .client
+            .post(&self.inner.login_url)
+            .json(&json!({ "email": email, "password": password }))
+            .send()
+            .await
+            .map_err(|e| AuthError::Unavailable(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_client_error() {
+            return Err(AuthError::InvalidCredentials(error_detail(response).await));
+        }
+        if !status.is_success() {
+            return Err(AuthError::Unavailable(status.to_string()));
+        }
+        let tokens: LoginResponse = response
+            .json()
+            .await
+            .map_err(|e| AuthError::InvalidResponse(e.to_string()))?;
+        self.set(Credentials {
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            user: tokens.user,
+        })
+    }
+
+    /// 로그인한 토큰 쌍 보관
+    pub fn set(&self, credentials: Credentials) -> Result<AuthSession, AuthError> {
+        if let Some(store) = &self.inner.store {
+            store.store(&credentials)?;
+        }
+        let session = AuthSession::from(&credentials);
+        *self.inner.lock() = Some(credentials);
+        self.inner.wake.notify_one();
+        Ok(session)
+    }
+
+    /// 메모리에 있는 현재 세션
+    pub fn session(&self) -> Option<AuthSession> {
+        self.inner.lock().as_ref().map(AuthSession::from)
+    }
+
+    /// 로그인한 사용자 식별자 (`user.email`, 없으면 `user.user_id`)
+    ///
+    /// 승인 기록의 `decided_by`처럼 웹뷰가 보낸 값을 그대로 믿을 수 없는 곳에 쓴다.
+    pub fn current_user(&self) -> Option<String> {
+        let inner = self.inner.lock();
+        let user = &inner.as_ref()?.user;
+        ["email", "user_id"]
+            .iter()
+            .find_map(|key| user.get(key).and_then(Value::as_str))
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+    }
+
+    /// 현재 access token
+    pub fn access_token(&self) -> Option<String> {
+        self.inner.lock().as_ref().map(|c| c.access_token.clone())
+    }
+
+    /// 세션 삭제 (로그아웃)
+    pub fn clear(&self) -> Result<(), AuthError> {
+        self.inner.lock().take();
+        self.inner.wake.notify_one();
+        if let Some(store) = &self.inner.store {
+            store.clear()?;
+        }
+        Ok(())
+    }
+
+    /// 지금 바로 갱신
+    ///
+    /// 다른 갱신이 진행 중이면 끝날 때까지 기다렸다가 그 결과를 쓴다. 서버가
+    /// refresh token을 거부하면 세션을 지우고 `auth://expired`를 보낸다.
+    pub async fn refresh(&self) -> Result<AuthSession, AuthError> {
+        let before = self.access_token().ok_or(AuthError::NotLoggedIn)?;
+        let _guard = self.inner.refreshing.lock().await;
+
+        let current = self.inner.lock().clone().ok_or(AuthError::NotLoggedIn)?;
+        if current.access_token != before {
+            // 기다리는 동안 다른 갱신이 끝남
+            return Ok(AuthSession::from(&current));
+        }
+        if token_expiry(&current.refresh_token).is_some_and(|exp| exp <= Utc::now()) {
+            return Err(self.expire(&current, "refresh token expired".to_string()));
+        }
+
+        let response = self
+            .inner
+            .client
+            .post(&self.inner.refresh_url)
+            .json(&json!({ "refresh_token": current.refresh_token }))
+            .send()
+            .await
+            .map_err(|e| AuthError::Unavailable(e.to_string()))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            let reason = error_detail(response).await;
+            return Err(self.expire(&current, reason));
+        }
+        if !status.is_success() {
+            return Err(AuthError::Unavailable(status.to_string()));
+        }
+        let tokens: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| AuthError::InvalidResponse(e.to_string()))?;
+
+        let refreshed = Credentials {
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token.unwrap_or(current.refresh_token),
+            user: current.user,
+        };
+        {
+            let mut session = self.inner.lock();
+            // 요청 중에 로그아웃하거나 다시 로그인했으면 결과를 버림
+            if session.as_ref().map(|c| &c.access_token) != Some(&before) {
+                return session
+                    .as_ref()
+                    .map(AuthSession::from)
+                    .ok_or(AuthError::NotLoggedIn);
+            }
+            *session = Some(refreshed.clone());
+        }
+        if let Some(store) = &self.inner.store {
+            store.store(&refreshed)?;
+        }
+        self.inner.wake.notify_one();
+
+        let session = AuthSession::from(&refreshed);
+        (self.inner.listener)(AuthEvent::Refreshed(session.clone()));
+        Ok(session)
+    }
+
+    /// 백그라운드 갱신 루프 (앱이 끝날 때까지 실행)
+    ///
+    /// 저장된 세션은 먼저 [`TokenManager::load`]로 불러 둔다.
+    pub async fn run(self) {
+        loop {
+            let due = self
+                .inner
+                .lock()
+                .as_ref()
+                .and_then(|c| token_expiry(&c.access_token));
+            let Some(due) = due else {
+                // 로그인 전이거나 만료 시각이 없는 토큰
+                self.inner.wake.notified().await;
+                continue;
+            };
+            let wait = (due - Utc::now())
+                .to_std()
+                .unwrap_or_default()
+                .saturating_sub(REFRESH_MARGIN);
+
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = self.inner.wake.notified() => continue,
+            }
+            match self.refresh().await {
+                Ok(_) | Err(AuthError::NotLoggedIn | AuthError::Expired(_)) => {}
+                Err(e) => {
+                    // 오프라인일 수 있으므로 세션은 유지하고 잠시 뒤 다시 시도
+                    log::warn!("token refresh failed: {e}");
+                    tokio::select! {
+                        _ = tokio::time::sleep(RETRY_DELAY) => {}
+                        _ = self.inner.wake.notified() => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// 세션을 지우고 만료 알림
+    fn expire(&self, current: &Credentials, reason: String) -> AuthError {
+        {
+            let mut session = self.inner.lock();
+            if session.as_ref().map(|c| &c.refresh_token) == Some(&current.refresh_token) {
+                session.take();
+            }
+        }
+        if let Some(store) = &self.inner.store {
+            if let Err(e) = store.clear() {
+                log::warn!("failed to clear credentials: {e}");
+            }
+        }
+        (self.inner.listener)(AuthEvent::Expired {
+            reason: reason.clone(),
+        });
+        AuthError::Expired(reason)
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, Option<Credentials>> {
+        self.session.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 오류 응답의 `detail` (없으면 상태 코드)
+async fn error_detail(response: reqwest::Response) -> String {
+    let status = response.status();
+    response
+        .json::<Value>()
+        .await
+        .ok()
+        .and_then(|body| body.get("detail")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| status.to_string())
+}
+
+/// 갱신 결과를 UI 이벤트로 보내는 리스너
+pub fn emit_events(app: AppHandle) -> impl Fn(AuthEvent) + Send + Sync + 'static {
+    move |event| {
+        let _ = match event {
+            AuthEvent::Refreshed(session) => app.emit(REFRESHED_EVENT, session),
+            AuthEvent::Expired { reason } => app.emit(EXPIRED_EVENT, json!({ "reason": reason })),
+        };
+    }
+}
+
+/// 로그인 (토큰 쌍은 Rust에 보관하고 refresh token은 웹뷰에 돌려주지 않음)
+#[tauri::command]
+pub async fn login(
+    auth: State<'_, TokenManager>,
+    email: String,
+    password: String,
+) -> Result<AuthSession, AuthError> {
+    auth.login(&email, &password).await
+}
+
+/// 세션 삭제 (로그아웃)
+#[tauri::command(async)]
+pub fn clear_credentials(auth: State<'_, TokenManager>) -> Result<(), AuthError> {
+    auth.clear()
+}
+
+/// access token 즉시 갱신 (앱 시작 시 자동 로그인, API가 401을 반환했을 때)
+#[tauri::command]
+pub async fn refresh_credentials(auth: State<'_, TokenManager>) -> Result<AuthSession, AuthError> {
+    auth.refresh().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    fn jwt(exp: DateTime<Utc>) -> String {
+        let claims = json!({ "sub": "user-1", "exp": exp.timestamp() }).to_string();
+        format!("e30.{}.sig", URL_SAFE_NO_PAD.encode(claims))
+    }
+
+    /// `POST {path}` 요청마다 `respond(body)`의 (status, body)를 돌려주는 로컬 인증 서버
+    fn mock_auth_server(
+        path: &'static str,
+        respond: impl Fn(&Value) -> (u16, Value) + Send + 'static,
+    ) -> (String, mpsc::Receiver<Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (requests, received) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                assert!(request_line.starts_with(&format!("POST {path} ")));
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let body: Value = serde_json::from_slice(&body).unwrap();
+                let (status, response) = respond(&body);
+                requests.send(body).unwrap();
+                let response = response.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, received)
+    }
+
+    const REFRESH_PATH: &str = "/api/v1/auth/refresh";
+
+    fn manager(url: &str) -> (TokenManager, mpsc::Receiver<AuthEvent>) {
+        let (events, received) = mpsc::channel();
+        let events = Mutex::new(events);
+        let manager = TokenManager::new(url, None, move |event| {
+            let _ = events.lock().unwrap().send(event);
+        });
+        (manager, received)
+    }
+
+    fn credentials(access_exp: DateTime<Utc>) -> Credentials {
+        Credentials {
+            access_token: jwt(access_exp),
+            refresh_token: jwt(Utc::now() + chrono::Duration::days(7)),
+            user: json!({ "email": "op@triflow.ai" }),
+        }
+    }
+
+    #[test]
+    fn decodes_jwt_expiry() {
+        let exp = DateTime::from_timestamp(Utc::now().timestamp() + 900, 0).unwrap();
+        assert_eq!(token_expiry(&jwt(exp)), Some(exp));
+        assert_eq!(token_expiry("not-a-jwt"), None);
+        assert_eq!(token_expiry("a.!!!.c"), None);
+    }
+
+    #[test]
+    fn current_user_comes_from_session() {
+        let (manager, _events) = manager("http://127.0.0.1:9");
+        assert_eq!(manager.current_user(), None);
+
+        let mut creds = credentials(Utc::now() + chrono::Duration::hours(1));
+        manager.set(creds.clone()).unwrap();
+        assert_eq!(manager.current_user().as_deref(), Some("op@triflow.ai"));
+
+        creds.user = json!({ "user_id": "u-1" });
+        manager.set(creds.clone()).unwrap();
+        assert_eq!(manager.current_user().as_deref(), Some("u-1"));
+
+        creds.user = json!({});
+        manager.set(creds).unwrap();
+        assert_eq!(manager.current_user(), None);
+
+        manager.clear().unwrap();
+        assert_eq!(manager.current_user(), None);
+    }
+
+    #[tokio::test]
+    async fn refreshes_in_background_before_expiry() {
+        let new_access = jwt(Utc::now() + chrono::Duration::hours(1));
+        let (url, requests) = mock_auth_server(REFRESH_PATH, {
+            let new_access = new_access.clone();
+            move |_| {
+                let body = json!({ "access_token": new_access, "refresh_token": "rotated", "expires_in": 3600 });
+                (200, body)
+            }
+        });
+        let (manager, events) = manager(&url);
+        // 만료까지 REFRESH_MARGIN보다 적게 남은 토큰
+        let old = credentials(Utc::now() + chrono::Duration::seconds(30));
+        manager.set(old.clone()).unwrap();
+        tokio::spawn(manager.clone().run());
+
+        let event = tokio::task::spawn_blocking(move || {
+            events.recv_timeout(Duration::from_secs(5)).unwrap()
+        })
+        .await
+        .unwrap();
+        let AuthEvent::Refreshed(session) = event else {
+            panic!("expected refreshed event, got {event:?}");
+        };
+        assert_eq!(session.access_token, new_access);
+        assert_eq!(session.user, old.user);
+        assert_eq!(
+            requests.recv().unwrap()["refresh_token"],
+            json!(old.refresh_token)
+        );
+        assert_eq!(manager.access_token(), Some(new_access));
+        assert_eq!(
+            manager.inner.lock().as_ref().unwrap().refresh_token,
+            "rotated"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_refresh_expires_session() {
+        let (url, _requests) = mock_auth_server(REFRESH_PATH, |_| {
+            (401, json!({ "detail": "Invalid refresh token" }))
+        });
+        let (manager, events) = manager(&url);
+        manager
+            .set(credentials(Utc::now() + chrono::Duration::hours(1)))
+            .unwrap();
+
+        let result = manager.refresh().await;
+        assert!(
+            matches!(result, Err(AuthError::Expired(ref reason)) if reason == "Invalid refresh token")
+        );
+        assert_eq!(
+            events.try_recv().unwrap(),
+            AuthEvent::Expired {
+                reason: "Invalid refresh token".to_string()
+            }
+        );
+        assert!(manager.session().is_none());
+        assert!(matches!(
+            manager.refresh().await,
+            Err(AuthError::NotLoggedIn)
+        ));
+    }
+
+    #[tokio::test]
+    async fn server_error_keeps_session() {
+        let (url, _requests) =
+            mock_auth_server(REFRESH_PATH, |_| (503, json!({ "detail": "maintenance" })));
+        let (manager, events) = manager(&url);
+        let current = credentials(Utc::now() + chrono::Duration::hours(1));
+        manager.set(current.clone()).unwrap();
+
+        assert!(matches!(
+            manager.refresh().await,
+            Err(AuthError::Unavailable(_))
+        ));
+        assert!(events.try_recv().is_err());
+        assert_eq!(manager.access_token(), Some(current.access_token));
+    }
+
+    #[tokio::test]
+    async fn login_keeps_refresh_token_in_rust() {
+        let access = jwt(Utc::now() + chrono::Duration::hours(1));
+        let (url, requests) = mock_auth_server("/api/v1/auth/login", {
+            let access = access.clone();
+            move |body| match body["password"].as_str() {
+                Some("secret") => (
+                    200,
+                    json!({
+                        "access_token": access,
+                        "refresh_token": "refresh-secret",
+                        "token_type": "bearer",
+                        "expires_in": 3600,
+                        "user": { "email": "op@triflow.ai" },
+                    }),
+                ),
+                _ => (401, json!({ "detail": "Incorrect email or password" })),
+            }
+        });
+        let (manager, _events) = manager(&url);
+
+        let result = manager.login("op@triflow.ai", "wrong").await;
+        assert!(
+            matches!(result, Err(AuthError::InvalidCredentials(ref detail)) if detail == "Incorrect email or password")
+        );
+        assert!(manager.session().is_none());
+
+        let session = manager.login("op@triflow.ai", "secret").await.unwrap();
+        assert_eq!(session.access_token, access);
+        assert_eq!(session.user, json!({ "email": "op@triflow.ai" }));
+        assert!(!serde_json::to_string(&session)
+            .unwrap()
+            .contains("refresh-secret"));
+        assert_eq!(manager.session(), Some(session));
+        assert_eq!(
+            requests.recv().unwrap(),
+            json!({ "email": "op@triflow.ai", "password": "wrong" })
+        );
+    }
+}
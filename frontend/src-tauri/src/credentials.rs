@@ -0,0 +1,322 @@
+//! 인증 정보 저장소
+//!
+//! 로그인 토큰과 사용자 정보를 웹뷰의 `localStorage` 대신 OS 키체인(macOS Keychain,
+//! Windows 자격 증명 관리자, Linux Secret Service)에 저장한다. Secret Service가 없는
+//! 헤드리스 Linux에서는 앱 데이터 디렉터리의 AES-256-GCM 암호화 파일을 대신 사용한다.
+//! 웹뷰는 [`crate::auth`]의 커맨드를 통해서만 접근하며, refresh token은 웹뷰로
+//! 돌려주지 않는다.
+//!
+//! # 암호화 파일의 위협 모델
+//!
+//! 파일 키는 디스크에 저장하지 않고 machine-id와 설치마다 만든 무작위 salt에서
+//! HKDF-SHA256으로 유도한다. 데이터 디렉터리만 복사해 간 경우(백업, 다른 PC로 유출)에는
+//! 복호화할 수 없고, 파일 권한(0600)이 같은 머신의 다른 사용자를 막는다. 하지만 같은
+//! 머신에서 같은 사용자로 실행되는 프로세스는 같은 키를 유도할 수 있으므로 키체인만큼
+//! 안전하지는 않다.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use hkdf::Hkdf;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use thiserror::Error;
+
+/// 키체인 서비스 이름 (앱 identifier)
+pub const SERVICE: &str = "com.triflow.ai";
+const ACCOUNT: &str = "session";
+
+/// 암호화 파일 (nonce 12바이트 + 암호문)
+const FILE_NAME: &str = "credentials.enc";
+/// 키 유도용 salt 파일 (32바이트, 그 자체로는 키가 아님)
+const SALT_FILE_NAME: &str = "credentials.salt";
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 32;
+/// systemd, 없으면 D-Bus의 machine-id
+const MACHINE_ID_PATHS: &[&str] = &["/etc/machine-id", "/var/lib/dbus/machine-id"];
+const KEY_INFO: &[u8] = b"com.triflow.ai/credentials/v1";
+
+/// 저장되는 인증 정보
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Credentials {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// `/auth/me` 응답 (Rust에서는 해석하지 않음)
+    #[serde(default)]
+    pub user: Value,
+}
+
+impl fmt::Debug for Credentials {
+    // 로그에 토큰이 남지 않도록 가린다
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_token", &"***")
+            .field("refresh_token", &"***")
+            .field("user", &self.user)
+            .finish()
+    }
+}
+
+/// 인증 정보 저장소 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록 `{"kind": ..., "detail": ...}` 형태로
+/// 직렬화된다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum CredentialError {
+    #[error("keychain error: {0}")]
+    Keychain(String),
+    #[error("credential file error: {0}")]
+    File(String),
+    #[error("stored credentials are corrupted: {0}")]
+    Corrupted(String),
+}
+
+impl From<keyring::Error> for CredentialError {
+    fn from(e: keyring::Error) -> Self {
+        Self::Keychain(e.to_string())
+    }
+}
+
+impl From<std::io::Error> for CredentialError {
+    fn from(e: std::io::Error) -> Self {
+        Self::File(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CredentialError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Corrupted(e.to_string())
+    }
+}
+
+enum Backend {
+    Keyring(keyring::Entry),
+    File { path: PathBuf, salt_path: PathBuf },
+}
+
+/// 인증 정보 저장소 (키체인, 헤드리스 Linux에서는 암호화 파일)
+///
+/// 백엔드는 처음 사용할 때 정한다. Secret Service 호출은 D-Bus 왕복이 있으므로
+/// 메인 스레드가 아닌 커맨드 스레드에서 하기 위함이다.
+pub struct CredentialStore {
+    data_dir: PathBuf,
+    backend: OnceLock<Backend>,
+    lock: Mutex<()>,
+}
+
+impl CredentialStore {
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            backend: OnceLock::new(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn store(&self, credentials: &Credentials) -> Result<(), CredentialError> {
+        let _guard = self.lock();
+        let json = serde_json::to_string(credentials)?;
+        match self.backend() {
+            Backend::Keyring(entry) => entry.set_password(&json)?,
+            Backend::File { path, salt_path } => {
+                let key = file_key(salt_path)?;
+                let cipher = Aes256Gcm::new(&key);
+                let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+                let ciphertext = cipher
+                    .encrypt(&nonce, json.as_bytes())
+                    .map_err(|e| CredentialError::File(e.to_string()))?;
+                let mut data = nonce.to_vec();
+                data.extend_from_slice(&ciphertext);
+                write_private(path, &data)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 저장된 인증 정보 (없으면 None)
+    pub fn load(&self) -> Result<Option<Credentials>, CredentialError> {
+        let _guard = self.lock();
+        let json = match self.backend() {
+            Backend::Keyring(entry) => match entry.get_password() {
+                Ok(json) => json,
+                Err(keyring::Error::NoEntry) => return Ok(None),
+                Err(e) => return Err(e.into()),
+            },
+            Backend::File { path, salt_path } => {
+                let data = match fs::read(path) {
+                    Ok(data) => data,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                    Err(e) => return Err(e.into()),
+                };
+                if data.len() < NONCE_LEN {
+                    return Err(CredentialError::Corrupted("file too short".into()));
+                }
+                let key = file_key(salt_path)?;
+                let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+                let plaintext = Aes256Gcm::new(&key)
+                    .decrypt(Nonce::from_slice(nonce), ciphertext)
+                    .map_err(|_| CredentialError::Corrupted("decryption failed".into()))?;
+                String::from_utf8(plaintext)
+                    .map_err(|e| CredentialError::Corrupted(e.to_string()))?
+            }
+        };
+        Ok(Some(serde_json::from_str(&json)?))
+    }
+
+    /// 저장된 인증 정보 삭제 (로그아웃)
+    pub fn clear(&self) -> Result<(), CredentialError> {
+        let _guard = self.lock();
+        match self.backend() {
+            Backend::Keyring(entry) => match entry.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => {}
+                Err(e) => return Err(e.into()),
+            },
+            Backend::File { path, .. } => match fs::remove_file(path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            },
+        }
+        Ok(())
+    }
+
+    fn backend(&self) -> &Backend {
+        self.backend.get_or_init(|| {
+            let fallback = || Backend::File {
+                path: self.data_dir.join(FILE_NAME),
+                salt_path: self.data_dir.join(SALT_FILE_NAME),
+            };
+            let entry = match keyring::Entry::new(SERVICE, ACCOUNT) {
+                Ok(entry) => entry,
+                Err(_) => return fallback(),
+            };
+            match entry.get_password() {
+                Ok(_) | Err(keyring::Error::NoEntry) => Backend::Keyring(entry),
+                // 헤드리스 Linux: Secret Service(D-Bus 세션/gnome-keyring)가 없음
+                Err(keyring::Error::NoStorageAccess(_) | keyring::Error::PlatformFailure(_))
+                    if cfg!(target_os = "linux") =>
+                {
+                    fallback()
+                }
+                Err(_) => Backend::Keyring(entry),
+            }
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ()> {
+        self.lock.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 암호화 파일 키 (machine-id와 salt에서 유도)
+fn file_key(salt_path: &Path) -> Result<Key<Aes256Gcm>, CredentialError> {
+    let machine_id = MACHINE_ID_PATHS
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|id| id.trim().to_string())
+        .find(|id| !id.is_empty())
+        .ok_or_else(|| CredentialError::File("machine-id not found".into()))?;
+    let salt = load_or_create_salt(salt_path)?;
+    Ok(derive_key(machine_id.as_bytes(), &salt))
+}
+
+fn derive_key(machine_id: &[u8], salt: &[u8]) -> Key<Aes256Gcm> {
+    let mut key = Key::<Aes256Gcm>::default();
+    Hkdf::<Sha256>::new(Some(salt), machine_id)
+        .expand(KEY_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// salt 읽기 (없으면 새로 만들어 소유자만 읽을 수 있게 저장)
+fn load_or_create_salt(path: &Path) -> Result<Vec<u8>, CredentialError> {
+    match fs::read(path) {
+        Ok(bytes) if bytes.len() == SALT_LEN => Ok(bytes),
+        Ok(_) => Err(CredentialError::Corrupted("invalid salt file".into())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let mut salt = vec![0; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            write_private(path, &salt)?;
+            Ok(salt)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 소유자 전용 권한(0600)으로 원자적 쓰기 (임시 파일 + rename)
+fn write_private(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&tmp)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_store(dir: &Path) -> CredentialStore {
+        let store = CredentialStore::new(dir);
+        let _ = store.backend.set(Backend::File {
+            path: dir.join(FILE_NAME),
+            salt_path: dir.join(SALT_FILE_NAME),
+        });
+        store
+    }
+
+    #[test]
+    fn derived_key_depends_on_machine_and_salt() {
+        let key = derive_key(b"machine-a", &[1; SALT_LEN]);
+        assert_eq!(key, derive_key(b"machine-a", &[1; SALT_LEN]));
+        assert_ne!(key, derive_key(b"machine-b", &[1; SALT_LEN]));
+        assert_ne!(key, derive_key(b"machine-a", &[2; SALT_LEN]));
+    }
+
+    // 파일 백엔드는 machine-id가 있는 Linux에서만 쓰인다
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn file_backend_round_trip_without_storing_key() {
+        let dir = std::env::temp_dir().join(format!("triflow-credentials-{}", std::process::id()));
+        let store = file_store(&dir);
+        assert!(store.load().unwrap().is_none());
+
+        let credentials = Credentials {
+            access_token: "access".into(),
+            refresh_token: "refresh".into(),
+            user: serde_json::json!({"name": "kim"}),
+        };
+        store.store(&credentials).unwrap();
+        let loaded = file_store(&dir).load().unwrap().unwrap();
+        assert_eq!(loaded.refresh_token, "refresh");
+        assert_eq!(loaded.user, credentials.user);
+
+        // 디스크에는 암호문과 salt만 있다
+        let salt = fs::read(dir.join(SALT_FILE_NAME)).unwrap();
+        let data = fs::read(dir.join(FILE_NAME)).unwrap();
+        assert_eq!(salt.len(), SALT_LEN);
+        assert!(!String::from_utf8_lossy(&data).contains("refresh"));
+
+        store.clear().unwrap();
+        assert!(store.load().unwrap().is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
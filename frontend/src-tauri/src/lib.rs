@@ -1,23 +1,150 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 
-/// 앱 버전 정보 반환
-#[tauri::command]
-fn get_app_version() -> String {
-    env!("CARGO_PKG_VERSION").to_string()
-}
+mod api;
+mod auth;
+mod credentials;
+mod notification;
+mod rules;
+mod security;
+mod system;
+mod tray;
+mod workflow;
 
-/// 앱 이름 반환
-#[tauri::command]
-fn get_app_name() -> String {
-    "TriFlow AI".to_string()
-}
+use std::sync::Arc;
+
+use tauri::{Manager, WindowEvent};
+use triflow_rules::RuleEngine;
+use triflow_workflow::{
+    ActionRegistry, CheckpointStore, EventBus, HistoryStore, RunManager, Scheduler,
+};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
-        .invoke_handler(tauri::generate_handler![get_app_version, get_app_name])
+        .setup(|app| {
+            let engine = Arc::new(RuleEngine::new());
+            // 로컬 실행은 dry-run: 액션은 호출 내용만 기록한다
+            let actions = Arc::new(ActionRegistry::dry_run());
+
+            // 체크포인트에서 중단된 워크플로우 실행 복원
+            let data_dir = app.path().app_data_dir()?;
+            let store = CheckpointStore::open(data_dir.join("workflow-runs"))?;
+            // 실행/노드 기록은 SQLite 이력 저장소에도 남긴다
+            let history = HistoryStore::open(data_dir.join("workflow-history.db"))?;
+            let runs = RunManager::new(
+                Arc::clone(&engine),
+                Arc::clone(&actions),
+                Some(store),
+                (
+                    workflow::EventObserver(app.handle().clone()),
+                    history.clone(),
+                ),
+            );
+            runs.restore()?;
+
+            // 스케줄 트리거: 앱이 꺼져 있던 동안 놓친 실행은 catch_up 정책에 따라 처리
+            let scheduler = Scheduler::open(Some(data_dir.join("schedules.json")), {
+                let runs = runs.clone();
+                move |scheduled| workflow::start_scheduled_run(&runs, scheduled)
+            })?;
+            scheduler.start();
+
+            // 이벤트 트리거: 센서 어댑터가 발행한 이벤트로 바로 실행
+            let events = EventBus::new(Arc::clone(&engine), {
+                let runs = runs.clone();
+                move |trigger| workflow::start_event_run(&runs, trigger)
+            });
+
+            // 로그인 토큰은 OS 키체인 (헤드리스 Linux는 암호화 파일)에 두고,
+            // 만료 전에 백그라운드에서 갱신한다
+            let auth = auth::TokenManager::new(
+                &auth::api_base_url(),
+                Some(credentials::CredentialStore::new(&data_dir)),
+                auth::emit_events(app.handle().clone()),
+            );
+            // 저장된 세션은 웹뷰가 열리기 전에 여기서 불러온다
+            // (웹뷰는 refresh_credentials로 자동 로그인)
+            if let Err(e) = auth.load() {
+                log::warn!("failed to load stored credentials: {e}");
+            }
+            tauri::async_runtime::spawn(auth.clone().run());
+            // 웹뷰의 백엔드 요청은 모두 api_request 프록시를 거친다
+            let api = api::ApiClient::new(&auth::api_base_url(), auth.clone())?;
+
+            // 웹뷰 대신 고정된 명령으로 실행하는 로컬 백엔드
+            let backend = system::LocalBackend::new(
+                system::LocalBackend::default_dir(),
+                &app.path().app_log_dir()?,
+            );
+
+            tray::init(app)?;
+
+            app.manage(engine);
+            app.manage(actions);
+            app.manage(runs);
+            app.manage(history);
+            app.manage(scheduler);
+            app.manage(events);
+            app.manage(auth);
+            app.manage(api);
+            app.manage(backend);
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            // 창을 닫으면 트레이로 숨김 (스케줄러는 계속 동작, 종료는 트레이 메뉴)
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                if window.label() == tray::MAIN_WINDOW {
+                    api.prevent_close();
+                    let _ = window.hide();
+                }
+            }
+        })
+        // 창별 커맨드 허용 목록 (isolation 훅에서 인자를 검사한 뒤)
+        .invoke_handler(|invoke| {
+            security::guard(
+                invoke,
+                tauri::generate_handler![
+                    auth::login,
+                    auth::clear_credentials,
+                    auth::refresh_credentials,
+                    api::api_request,
+                    system::start_local_backend,
+                    system::stop_local_backend,
+                    system::local_backend_status,
+                    system::open_logs_folder,
+                    system::check_backend_health,
+                    rules::execute_ruleset,
+                    rules::execute_ruleset_batch,
+                    rules::validate_ruleset,
+                    rules::test_ruleset,
+                    rules::diff_ruleset,
+                    rules::invalidate_ruleset_cache,
+                    rules::ruleset_cache_stats,
+                    workflow::validate_workflow,
+                    workflow::render_workflow_plan,
+                    workflow::run_workflow_local,
+                    workflow::simulate_workflow,
+                    workflow::start_workflow_run,
+                    workflow::pause_workflow_run,
+                    workflow::resume_workflow_run,
+                    workflow::retry_workflow_run,
+                    workflow::cancel_workflow_run,
+                    workflow::approve_workflow_run,
+                    workflow::reject_workflow_run,
+                    workflow::get_workflow_run,
+                    workflow::list_workflow_runs,
+                    workflow::query_workflow_history,
+                    workflow::get_workflow_run_timeline,
+                    workflow::sync_workflow_schedules,
+                    workflow::list_workflow_schedules,
+                    workflow::sync_workflow_events,
+                    workflow::list_event_subscriptions,
+                    workflow::publish_sensor_event
+                ],
+            )
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,80 @@
+//! 승인 요청 데스크톱 알림
+//!
+//! XDG 알림을 지원하는 Linux 데스크톱에서는 알림의 승인/거부 버튼으로 바로
+//! 결정할 수 있다 (결정한 사람은 앱에 로그인한 사용자로 기록). 다른 플랫폼은
+//! 알림만 띄우며, 결정은 앱 화면에서 내린다.
+
+use tauri::AppHandle;
+use triflow_workflow::ApprovalRequest;
+
+const SUMMARY: &str = "TriFlow AI 승인 요청";
+
+/// 승인 요청 알림 표시
+pub fn notify_approval(app: &AppHandle, run_id: &str, request: &ApprovalRequest) {
+    imp::notify_approval(app, run_id, request);
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod imp {
+    use std::thread;
+
+    use notify_rust::{Notification, Timeout};
+    use tauri::{AppHandle, Manager};
+    use triflow_workflow::{ApprovalRequest, RunManager};
+
+    use crate::auth::TokenManager;
+
+    const APPROVE: &str = "approve";
+    const REJECT: &str = "reject";
+
+    pub fn notify_approval(app: &AppHandle, run_id: &str, request: &ApprovalRequest) {
+        let app = app.clone();
+        let run_id = run_id.to_string();
+        let message = request.message.clone();
+        // 사용자가 응답할 때까지 블록되므로 별도 스레드에서 대기
+        thread::spawn(move || {
+            let Ok(handle) = Notification::new()
+                .summary(super::SUMMARY)
+                .body(&message)
+                .action(APPROVE, "승인")
+                .action(REJECT, "거부")
+                .timeout(Timeout::Never)
+                .show()
+            else {
+                return;
+            };
+            handle.wait_for_action(|action| {
+                let Some(runs) = app.try_state::<RunManager>() else {
+                    return;
+                };
+                // 결정한 사람은 앱의 로그인 세션으로 기록 (로그아웃 상태면 앱 화면에서 결정)
+                let Some(decided_by) = app
+                    .try_state::<TokenManager>()
+                    .and_then(|auth| auth.current_user())
+                else {
+                    return;
+                };
+                // 앱 화면에서 이미 결정했으면 전이 오류가 나므로 무시
+                let _ = match action {
+                    APPROVE => runs.approve(&run_id, &decided_by, None),
+                    REJECT => runs.reject(&run_id, &decided_by, None),
+                    _ => return,
+                };
+            });
+        });
+    }
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+mod imp {
+    use notify_rust::Notification;
+    use tauri::AppHandle;
+    use triflow_workflow::ApprovalRequest;
+
+    pub fn notify_approval(_app: &AppHandle, _run_id: &str, request: &ApprovalRequest) {
+        let _ = Notification::new()
+            .summary(super::SUMMARY)
+            .body(&request.message)
+            .show();
+    }
+}
@@ -0,0 +1,149 @@
+//! 룰셋 로컬 실행 커맨드
+//!
+//! 백엔드 연결 없이 데스크톱에서 판단 룰을 테스트/실행한다.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::State;
+use triflow_rules::{
+    BatchResult, CacheStats, RuleEngine, RuleError, RulesetDiff, RulesetTestCase, TestReport,
+    Trace, ValidationReport, AST,
+};
+use uuid::Uuid;
+
+/// 룰셋 실행 결과 (백엔드 `RulesetExecuteResponse`와 동일한 형태)
+#[derive(Debug, Serialize)]
+pub struct RulesetExecuteResponse {
+    pub execution_id: String,
+    pub ruleset_id: Option<String>,
+    pub ruleset_name: Option<String>,
+    pub input_data: Value,
+    pub output_data: Value,
+    pub confidence_score: Option<f64>,
+    pub execution_time_ms: u64,
+    pub executed_at: DateTime<Utc>,
+    /// `trace: true`로 실행한 경우의 실행 경로
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Trace>,
+}
+
+/// 룰셋 로컬 실행
+///
+/// `ruleset_id`와 `version`이 함께 주어지면 컴파일된 AST를 캐시해 재사용한다.
+/// `trace`가 true이면 분기/바인딩/체크 추가 경로를 함께 반환한다.
+#[tauri::command(async)]
+pub fn execute_ruleset(
+    engine: State<'_, Arc<RuleEngine>>,
+    script: String,
+    input: Value,
+    ruleset_id: Option<String>,
+    ruleset_name: Option<String>,
+    version: Option<String>,
+    trace: Option<bool>,
+) -> Result<RulesetExecuteResponse, RuleError> {
+    let executed_at = Utc::now();
+    let started = Instant::now();
+
+    let ast = compile(&engine, &script, ruleset_id.as_deref(), version.as_deref())?;
+    let (output, trace) = if trace.unwrap_or(false) {
+        let (output, mut trace) = engine.execute_ast_traced(&ast, &input)?;
+        trace.attach_source(&script);
+        (output, Some(trace))
+    } else {
+        (engine.execute_ast(&ast, &input)?, None)
+    };
+    let execution_time_ms = started.elapsed().as_millis() as u64;
+
+    Ok(RulesetExecuteResponse {
+        execution_id: Uuid::new_v4().to_string(),
+        ruleset_id,
+        ruleset_name,
+        input_data: input,
+        confidence_score: output.confidence,
+        output_data: serde_json::to_value(output).unwrap_or(Value::Null),
+        execution_time_ms,
+        executed_at,
+        trace,
+    })
+}
+
+/// 여러 입력 행에 대해 룰셋 병렬 평가 (교대 단위 백필 판정 등)
+///
+/// 행별 오류는 전체 실패 대신 해당 행의 `error`로 반환된다.
+#[tauri::command(async)]
+pub fn execute_ruleset_batch(
+    engine: State<'_, Arc<RuleEngine>>,
+    script: String,
+    rows: Vec<Value>,
+    ruleset_id: Option<String>,
+    version: Option<String>,
+) -> Result<BatchResult, RuleError> {
+    let ast = compile(&engine, &script, ruleset_id.as_deref(), version.as_deref())?;
+    Ok(engine.execute_batch(&ast, &rows))
+}
+
+/// 룰셋 ID와 버전이 모두 있으면 캐시를 거쳐 컴파일
+fn compile(
+    engine: &RuleEngine,
+    script: &str,
+    ruleset_id: Option<&str>,
+    version: Option<&str>,
+) -> Result<Arc<AST>, RuleError> {
+    match (ruleset_id, version) {
+        (Some(id), Some(version)) => engine.compile_cached(id, version, script),
+        _ => Ok(Arc::new(engine.compile(script)?)),
+    }
+}
+
+/// 룰셋 스크립트 정적 검증 (실행하지 않음)
+///
+/// `input_fields`가 주어지면 그 목록에 없는 `input` 필드 참조를 경고로 보고한다.
+#[tauri::command]
+pub fn validate_ruleset(
+    engine: State<'_, Arc<RuleEngine>>,
+    script: String,
+    input_fields: Option<Vec<String>>,
+) -> ValidationReport {
+    engine.validate(&script, input_fields.as_deref())
+}
+
+/// 룰셋 단위 테스트 실행 (활성화 전 회귀 확인용)
+#[tauri::command(async)]
+pub fn test_ruleset(
+    engine: State<'_, Arc<RuleEngine>>,
+    script: String,
+    tests: Vec<RulesetTestCase>,
+) -> TestReport {
+    engine.run_tests(&script, &tests)
+}
+
+/// 룰셋 두 버전 비교 (AST 차이 + 샘플 입력의 status 변화)
+#[tauri::command(async)]
+pub fn diff_ruleset(
+    engine: State<'_, Arc<RuleEngine>>,
+    old_script: String,
+    new_script: String,
+    samples: Option<Vec<Value>>,
+) -> Result<RulesetDiff, RuleError> {
+    engine.diff_scripts(
+        &old_script,
+        &new_script,
+        samples.as_deref().unwrap_or_default(),
+    )
+}
+
+/// 룰셋 AST 캐시 무효화 (스크립트 수정 시 호출)
+#[tauri::command]
+pub fn invalidate_ruleset_cache(engine: State<'_, Arc<RuleEngine>>, ruleset_id: String) -> usize {
+    engine.invalidate(&ruleset_id)
+}
+
+/// 룰셋 AST 캐시 통계
+#[tauri::command]
+pub fn ruleset_cache_stats(engine: State<'_, Arc<RuleEngine>>) -> CacheStats {
+    engine.cache_stats()
+}
@@ -0,0 +1,204 @@
+//! 웹뷰 IPC 보안
+//!
+//! 웹뷰는 에이전트가 만든 마크다운과 차트를 그리므로, 주입된 스크립트가 권한 있는
+//! 커맨드를 호출하지 못하게 여러 겹으로 막는다.
+//!
+//! - CSP: `build.rs`에서 생성해 설정에 병합
+//! - 인자 검사: isolation 패턴 훅(`src-isolation/index.js`)
+//! - 창별 커맨드 허용 목록: 여기 ([`guard`])
+//!
+//! 플러그인 커맨드는 invoke handler를 거치지 않으며 capabilities 권한으로 제한된다.
+
+use serde::Serialize;
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::Runtime;
+use thiserror::Error;
+
+use crate::tray::MAIN_WINDOW;
+
+/// isolation 훅이 거부한 호출 (`src-isolation/index.js`의 `REJECTED_COMMAND`)
+pub const REJECTED_COMMAND: &str = "ipc_rejected";
+
+/// 메인 창이 호출할 수 있는 커맨드
+///
+/// 저장된 세션은 Rust가 시작할 때 불러오므로 웹뷰에는 조회 커맨드를 열지 않는다.
+const MAIN_COMMANDS: &[&str] = &[
+    "login",
+    "clear_credentials",
+    "refresh_credentials",
+    "api_request",
+    "start_local_backend",
+    "stop_local_backend",
+    "local_backend_status",
+    "open_logs_folder",
+    "check_backend_health",
+    "execute_ruleset",
+    "execute_ruleset_batch",
+    "validate_ruleset",
+    "test_ruleset",
+    "diff_ruleset",
+    "invalidate_ruleset_cache",
+    "ruleset_cache_stats",
+    "validate_workflow",
+    "render_workflow_plan",
+    "run_workflow_local",
+    "simulate_workflow",
+    "start_workflow_run",
+    "pause_workflow_run",
+    "resume_workflow_run",
+    "retry_workflow_run",
+    "cancel_workflow_run",
+    "approve_workflow_run",
+    "reject_workflow_run",
+    "get_workflow_run",
+    "list_workflow_runs",
+    "query_workflow_history",
+    "get_workflow_run_timeline",
+    "sync_workflow_schedules",
+    "list_workflow_schedules",
+    "sync_workflow_events",
+    "list_event_subscriptions",
+    "publish_sensor_event",
+];
+
+/// 창 label별 커맨드 허용 목록 (목록에 없는 창은 앱 커맨드를 호출할 수 없음)
+const WINDOW_COMMANDS: &[(&str, &[&str])] = &[(MAIN_WINDOW, MAIN_COMMANDS)];
+
+/// IPC 거부 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록 `{"kind": ..., "detail": ...}` 형태로
+/// 직렬화된다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum IpcError {
+    /// 이 창에서 호출할 수 없는 커맨드
+    #[error("command {command} is not allowed in window {label}")]
+    NotAllowed { label: String, command: String },
+    /// isolation 훅에서 거부된 인자
+    #[error("invalid arguments for {command}: {reason}")]
+    InvalidPayload { command: String, reason: String },
+}
+
+/// 창 `label`이 `command`를 호출할 수 있는지 여부
+pub fn is_allowed(label: &str, command: &str) -> bool {
+    WINDOW_COMMANDS
+        .iter()
+        .find(|(window, _)| *window == label)
+        .is_some_and(|(_, commands)| commands.contains(&command))
+}
+
+/// 허용 목록을 확인한 뒤 `handler`로 넘기는 invoke handler
+///
+/// 거부한 호출은 [`IpcError`]로 응답한다.
+pub fn guard<R: Runtime>(invoke: Invoke<R>, handler: impl Fn(Invoke<R>) -> bool) -> bool {
+    let label = invoke.message.webview_ref().label().to_string();
+    match check(&label, invoke.message.command(), invoke.message.payload()) {
+        Ok(()) => handler(invoke),
+        Err(error) => {
+            invoke.resolver.reject(error);
+            true
+        }
+    }
+}
+
+/// 창 `label`의 `command` 호출을 통과시킬지 결정
+///
+/// isolation 훅이 바꾼 거부 호출은 창과 관계없이 그 이유로 거부한다.
+fn check(label: &str, command: &str, payload: &InvokeBody) -> Result<(), IpcError> {
+    if command == REJECTED_COMMAND {
+        return Err(rejected(payload));
+    }
+    if !is_allowed(label, command) {
+        return Err(IpcError::NotAllowed {
+            label: label.to_string(),
+            command: command.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// isolation 훅이 넘긴 `{command, reason}`
+fn rejected(payload: &InvokeBody) -> IpcError {
+    let field = |name: &str| match payload {
+        InvokeBody::Json(value) => value[name].as_str().unwrap_or_default().to_string(),
+        InvokeBody::Raw(_) => String::new(),
+    };
+    IpcError::InvalidPayload {
+        command: field("command"),
+        reason: field("reason"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::{json, Value};
+
+    fn args(value: Value) -> InvokeBody {
+        InvokeBody::Json(value)
+    }
+
+    #[test]
+    fn main_window_passes_allowed_commands() {
+        for command in ["api_request", "login", "approve_workflow_run"] {
+            assert!(
+                check(MAIN_WINDOW, command, &args(json!({}))).is_ok(),
+                "{command}"
+            );
+        }
+    }
+
+    #[test]
+    fn unlisted_commands_are_not_allowed() {
+        for command in ["load_credentials", "get_app_version", "shell_execute", ""] {
+            let error = check(MAIN_WINDOW, command, &args(json!({}))).unwrap_err();
+            assert!(
+                matches!(&error, IpcError::NotAllowed { label, command: c } if label == MAIN_WINDOW && c == command),
+                "{error:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn other_windows_cannot_invoke_commands() {
+        for label in ["agent-output", ""] {
+            let error = check(label, "api_request", &args(json!({}))).unwrap_err();
+            assert!(matches!(error, IpcError::NotAllowed { .. }));
+        }
+    }
+
+    #[test]
+    fn isolation_rejections_are_reported() {
+        let payload = args(json!({ "command": "api_request", "reason": "invalid arguments" }));
+        for label in [MAIN_WINDOW, "agent-output"] {
+            let error = check(label, REJECTED_COMMAND, &payload).unwrap_err();
+            assert!(
+                matches!(&error, IpcError::InvalidPayload { command, reason }
+                    if command == "api_request" && reason == "invalid arguments"),
+                "{error:?}"
+            );
+        }
+        let error = check(MAIN_WINDOW, REJECTED_COMMAND, &InvokeBody::Raw(vec![1])).unwrap_err();
+        assert!(
+            matches!(&error, IpcError::InvalidPayload { command, reason } if command.is_empty() && reason.is_empty())
+        );
+    }
+
+    #[test]
+    fn build_time_csp_is_strict() {
+        let config: Value = serde_json::from_str(env!("TAURI_CONFIG")).unwrap();
+        let csp = config["app"]["security"]["csp"].as_str().unwrap();
+        let directive = |name: &str| {
+            csp.split(';')
+                .map(str::trim)
+                .find_map(|d| d.strip_prefix(name)?.strip_prefix(' '))
+                .unwrap_or_else(|| panic!("missing {name}: {csp}"))
+        };
+        assert_eq!(directive("script-src"), "'self'");
+        assert_eq!(directive("object-src"), "'none'");
+        assert_eq!(directive("base-uri"), "'none'");
+        assert!(!csp.contains("'unsafe-eval'") && !csp.contains("'unsafe-inline'"));
+        assert!(!directive("connect-src").contains("http://localhost"));
+    }
+}
@@ -0,0 +1,368 @@
+//! 로컬 시스템 커맨드
+//!
+//! 웹뷰에 `shell:allow-execute`를 주면 어떤 스크립트든 공장 PC에서 임의의 프로세스를
+//! 실행할 수 있다. 대신 필요한 작업만 고정된 프로그램과 검증된 인자로 실행하는
+//! 커맨드를 둔다.
+//!
+//! CSP가 웹뷰의 직접 요청을 막으므로 설정 화면의 백엔드 연결 확인도 여기서 한다.
+
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_opener::OpenerExt;
+use thiserror::Error;
+
+/// 로컬 백엔드 기본 포트
+pub const DEFAULT_BACKEND_PORT: u16 = 8000;
+/// 로컬 백엔드 출력 로그 파일 (앱 로그 폴더 아래)
+const BACKEND_LOG: &str = "backend.log";
+/// 백엔드 연결 확인 제한 시간
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 시스템 커맨드 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록 `{"kind": ..., "detail": ...}` 형태로
+/// 직렬화된다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum SystemError {
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+    /// 백엔드 폴더를 찾을 수 없음 (`TRIFLOW_BACKEND_DIR` 확인)
+    #[error("backend directory not found: {0}")]
+    BackendNotFound(String),
+    #[error("failed to launch process: {0}")]
+    Launch(String),
+    #[error("failed to open: {0}")]
+    Open(String),
+    /// 백엔드에 연결할 수 없거나 정상 응답이 아님
+    #[error("backend unreachable: {0}")]
+    Unreachable(String),
+}
+
+/// 로컬 백엔드 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendStatus {
+    pub running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    pub log_file: PathBuf,
+}
+
+/// 로컬 백엔드 프로세스 (uvicorn)
+///
+/// 항상 `<python> -m uvicorn app.main:app --host 127.0.0.1 --port <port>`만 실행하며,
+/// 바꿀 수 있는 인자는 포트뿐이다.
+pub struct LocalBackend {
+    /// 백엔드 폴더 (`app/main.py`가 있는 곳)
+    dir: Option<PathBuf>,
+    log_file: PathBuf,
+    process: Mutex<Option<(Child, u16)>>,
+}
+
+impl LocalBackend {
+    pub fn new(dir: Option<PathBuf>, log_dir: &Path) -> Self {
+        Self {
+            dir,
+            log_file: log_dir.join(BACKEND_LOG),
+            process: Mutex::new(None),
+        }
+    }
+
+    /// 백엔드 폴더 위치 (`TRIFLOW_BACKEND_DIR`, 개발 빌드에서는 저장소의 `backend/`)
+    pub fn default_dir() -> Option<PathBuf> {
+        std::env::var_os("TRIFLOW_BACKEND_DIR")
+            .map(PathBuf::from)
+            .or_else(|| {
+                cfg!(debug_assertions)
+                    .then(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../../backend"))
+            })
+    }
+
+    /// 백엔드 시작 (이미 실행 중이면 현재 상태 반환)
+    pub fn start(&self, port: u16) -> Result<BackendStatus, SystemError> {
+        if port < 1024 {
+            return Err(SystemError::InvalidArgument(format!(
+                "port must be between 1024 and 65535: {port}"
+            )));
+        }
+        let mut process = self.lock();
+        if let Some(status) = self.running(&mut process) {
+            return Ok(status);
+        }
+
+        let dir = self
+            .dir
+            .as_ref()
+            .filter(|dir| dir.join("app").join("main.py").is_file())
+            .ok_or_else(|| {
+                SystemError::BackendNotFound(
+                    self.dir
+                        .as_ref()
+                        .map(|dir| dir.display().to_string())
+                        .unwrap_or_else(|| "TRIFLOW_BACKEND_DIR is not set".to_string()),
+                )
+            })?;
+
+        if let Some(log_dir) = self.log_file.parent() {
+            fs::create_dir_all(log_dir).map_err(|e| SystemError::Launch(e.to_string()))?;
+        }
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.log_file)
+            .map_err(|e| SystemError::Launch(e.to_string()))?;
+        let stderr = log
+            .try_clone()
+            .map_err(|e| SystemError::Launch(e.to_string()))?;
+
+        let mut command = Command::new(python(dir));
+        command
+            .args([
+                "-m",
+                "uvicorn",
+                "app.main:app",
+                "--host",
+                "127.0.0.1",
+                "--port",
+            ])
+            .arg(port.to_string())
+            .current_dir(dir)
+            .stdin(Stdio::null())
+            .stdout(log)
+            .stderr(stderr);
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            // 콘솔 창을 띄우지 않음 (CREATE_NO_WINDOW)
+            command.creation_flags(0x0800_0000);
+        }
+        let child = command
+            .spawn()
+            .map_err(|e| SystemError::Launch(e.to_string()))?;
+        *process = Some((child, port));
+        Ok(self.running(&mut process).unwrap_or_else(|| self.stopped()))
+    }
+
+    /// 이 앱이 시작한 백엔드 중지
+    pub fn stop(&self) -> Result<BackendStatus, SystemError> {
+        let mut process = self.lock();
+        if let Some((mut child, _)) = process.take() {
+            child
+                .kill()
+                .map_err(|e| SystemError::Launch(e.to_string()))?;
+            let _ = child.wait();
+        }
+        Ok(self.stopped())
+    }
+
+    pub fn status(&self) -> BackendStatus {
+        let mut process = self.lock();
+        self.running(&mut process).unwrap_or_else(|| self.stopped())
+    }
+
+    /// 실행 중이면 상태, 이미 종료됐으면 정리하고 None
+    fn running(&self, process: &mut Option<(Child, u16)>) -> Option<BackendStatus> {
+        let (child, port) = process.as_mut()?;
+        if !matches!(child.try_wait(), Ok(None)) {
+            process.take();
+            return None;
+        }
+        Some(BackendStatus {
+            running: true,
+            pid: Some(child.id()),
+            port: Some(*port),
+            log_file: self.log_file.clone(),
+        })
+    }
+
+    fn stopped(&self) -> BackendStatus {
+        BackendStatus {
+            running: false,
+            pid: None,
+            port: None,
+            log_file: self.log_file.clone(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<(Child, u16)>> {
+        self.process.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 백엔드 가상환경의 Python (없으면 PATH의 Python)
+fn python(dir: &Path) -> PathBuf {
+    let venv = if cfg!(windows) {
+        dir.join(".venv").join("Scripts").join("python.exe")
+    } else {
+        dir.join(".venv").join("bin").join("python")
+    };
+    if venv.is_file() {
+        venv
+    } else if cfg!(windows) {
+        PathBuf::from("python")
+    } else {
+        PathBuf::from("python3")
+    }
+}
+
+/// 로컬 백엔드 시작 (`port` 생략 시 8000)
+#[tauri::command(async)]
+pub fn start_local_backend(
+    backend: State<'_, LocalBackend>,
+    port: Option<u16>,
+) -> Result<BackendStatus, SystemError> {
+    backend.start(port.unwrap_or(DEFAULT_BACKEND_PORT))
+}
+
+/// 로컬 백엔드 중지
+#[tauri::command(async)]
+pub fn stop_local_backend(backend: State<'_, LocalBackend>) -> Result<BackendStatus, SystemError> {
+    backend.stop()
+}
+
+/// 로컬 백엔드 상태
+#[tauri::command]
+pub fn local_backend_status(backend: State<'_, LocalBackend>) -> BackendStatus {
+    backend.status()
+}
+
+/// `base_url`의 `/health` 주소 (http/https만 허용)
+fn health_url(base_url: &str) -> Result<reqwest::Url, SystemError> {
+    let invalid = || SystemError::InvalidArgument(format!("invalid backend url: {base_url}"));
+    let base = reqwest::Url::parse(base_url.trim_end_matches('/')).map_err(|_| invalid())?;
+    if !matches!(base.scheme(), "http" | "https") || base.host().is_none() {
+        return Err(invalid());
+    }
+    let mut url = base;
+    url.path_segments_mut()
+        .map_err(|_| invalid())?
+        .pop_if_empty()
+        .push("health");
+    url.set_query(None);
+    url.set_fragment(None);
+    Ok(url)
+}
+
+/// 백엔드 연결 확인 (`GET {api_base_url}/health` 응답 본문)
+///
+/// 주소는 웹뷰가 정하지 않고 [`crate::auth::api_base_url`]을 쓴다.
+#[tauri::command]
+pub async fn check_backend_health() -> Result<Value, SystemError> {
+    let url = health_url(&crate::auth::api_base_url())?;
+    let unreachable = |e: reqwest::Error| SystemError::Unreachable(e.to_string());
+    let response = reqwest::Client::builder()
+        .timeout(HEALTH_TIMEOUT)
+        .build()
+        .map_err(unreachable)?
+        .get(url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .map_err(unreachable)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(SystemError::Unreachable(status.to_string()));
+    }
+    response.json().await.map_err(unreachable)
+}
+
+/// 앱 로그 폴더를 파일 탐색기로 열기
+#[tauri::command]
+pub fn open_logs_folder(app: AppHandle) -> Result<(), SystemError> {
+    let dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| SystemError::Open(e.to_string()))?;
+    fs::create_dir_all(&dir).map_err(|e| SystemError::Open(e.to_string()))?;
+    app.opener()
+        .open_path(dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| SystemError::Open(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 웹뷰에서 프로세스를 실행/제어할 수 있는 shell 플러그인 권한
+    const EXECUTE_PERMISSIONS: &[&str] = &[
+        "shell:allow-execute",
+        "shell:allow-spawn",
+        "shell:allow-stdin-write",
+        "shell:allow-kill",
+    ];
+
+    #[test]
+    fn capabilities_do_not_allow_generic_execution() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("capabilities");
+        let mut checked = 0;
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let capability: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+            for permission in capability["permissions"].as_array().unwrap() {
+                // "id" 또는 {"identifier": "id", "allow": [...]}
+                let id = permission
+                    .as_str()
+                    .or_else(|| permission["identifier"].as_str())
+                    .unwrap();
+                assert!(
+                    !EXECUTE_PERMISSIONS.contains(&id),
+                    "{} grants {id}",
+                    path.display()
+                );
+            }
+            checked += 1;
+        }
+        assert!(checked > 0, "no capability files found");
+    }
+
+    #[test]
+    fn rejects_privileged_port() {
+        let backend = LocalBackend::new(None, &std::env::temp_dir());
+        assert!(matches!(
+            backend.start(80),
+            Err(SystemError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            backend.start(DEFAULT_BACKEND_PORT),
+            Err(SystemError::BackendNotFound(_))
+        ));
+        assert!(!backend.status().running);
+    }
+
+    #[test]
+    fn health_url_accepts_only_http_backends() {
+        let url = |base: &str| health_url(base).map(|u| u.to_string());
+        assert_eq!(
+            url("http://localhost:8000").unwrap(),
+            "http://localhost:8000/health"
+        );
+        assert_eq!(
+            url("https://api.triflow.ai/v1/?x=1").unwrap(),
+            "https://api.triflow.ai/v1/health"
+        );
+        for base in [
+            "",
+            "localhost:8000",
+            "file:///etc/passwd",
+            "javascript:alert(1)",
+        ] {
+            assert!(
+                matches!(health_url(base), Err(SystemError::InvalidArgument(_))),
+                "{base}"
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! 시스템 트레이
+//!
+//! 창을 닫아도 앱은 트레이에 남아 스케줄 워크플로우를 계속 실행한다.
+//! 완전히 종료하려면 트레이 메뉴의 "종료"를 사용한다.
+
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{App, AppHandle, Manager};
+
+/// 메인 창 레이블
+pub const MAIN_WINDOW: &str = "main";
+
+const MENU_SHOW: &str = "show";
+const MENU_QUIT: &str = "quit";
+
+/// 트레이 아이콘과 메뉴 생성
+pub fn init(app: &App) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, MENU_SHOW, "열기", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, MENU_QUIT, "종료", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &quit])?;
+
+    let mut builder = TrayIconBuilder::with_id("main")
+        .tooltip("TriFlow AI")
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            MENU_SHOW => show_main_window(app),
+            MENU_QUIT => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        });
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    Ok(())
+}
+
+/// 숨겨진 메인 창을 다시 표시
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW) {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
@@ -0,0 +1,407 @@
+//! 워크플로우 커맨드
+//!
+//! 데스크톱 워크플로우 빌더에서 저장 전 DSL 검증과 로컬 시험 실행에 사용한다.
+//! `*_workflow_run` 커맨드는 [`RunManager`]로 실행을 관리하며, 실행 상태는
+//! 앱 데이터 디렉터리에 체크포인트로 저장되어 재시작 후에도 이어진다.
+//! 스케줄 트리거 워크플로우는 [`Scheduler`]가 창이 트레이로 숨겨진 동안에도 실행하고,
+//! 이벤트 트리거 워크플로우는 [`EventBus`]에 발행된 센서 이벤트로 실행한다.
+//! 끝난 실행도 [`HistoryStore`]에 남아 오프라인에서 타임라인을 조회할 수 있다.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tauri::{AppHandle, Emitter, State};
+use thiserror::Error;
+use triflow_rules::RuleEngine;
+use triflow_workflow::{
+    ActionCatalog, ActionRegistry, ApprovalRequest, EventBus, EventSubscription, EventSyncReport,
+    EventTrigger, HistoryError, HistoryQuery, HistoryStore, Language, NodeEvent, PublishOutcome,
+    RunError, RunManager, RunObserver, RunStatus, RunSummary, RunTimeline, ScheduleEntry,
+    ScheduleSyncReport, ScheduledRun, Scheduler, SensorEvent, SimulationOptions, SimulationReport,
+    ValidationError, WorkflowDsl, WorkflowExecutor, WorkflowPlan, WorkflowRun,
+};
+use uuid::Uuid;
+
+use crate::auth::TokenManager;
+
+/// 노드 진행 이벤트 이름 (payload: `NodeEvent`)
+pub const NODE_EVENT: &str = "workflow://node";
+
+/// 실행 상태 변경 이벤트 이름 (payload: `WorkflowRun`)
+pub const STATUS_EVENT: &str = "workflow://status";
+
+/// 승인 요청 이벤트 이름 (payload: `WorkflowRun`, `approval`에 요청 내용)
+pub const APPROVAL_EVENT: &str = "workflow://approval";
+
+/// 실행 진행 상황을 웹뷰 이벤트로 전달
+pub struct EventObserver(pub AppHandle);
+
+impl RunObserver for EventObserver {
+    fn on_node(&self, event: &NodeEvent<'_>) {
+        let _ = self.0.emit(NODE_EVENT, event);
+    }
+
+    fn on_status(&self, run: &WorkflowRun) {
+        let _ = self.0.emit(STATUS_EVENT, run);
+    }
+
+    fn on_approval(&self, run: &WorkflowRun, request: &ApprovalRequest) {
+        let _ = self.0.emit(APPROVAL_EVENT, run);
+        crate::notification::notify_approval(&self.0, &run.run_id, request);
+    }
+}
+
+/// 스케줄에 따라 워크플로우 실행 시작
+///
+/// 실행 입력에는 트리거 정보(`trigger`, `workflow_id`, `scheduled_at`, `catch_up`)가 담긴다.
+pub fn start_scheduled_run(runs: &RunManager, scheduled: ScheduledRun<'_>) {
+    let input = json!({
+        "trigger": "schedule",
+        "workflow_id": scheduled.workflow_id,
+        "scheduled_at": scheduled.scheduled_for,
+        "catch_up": scheduled.catch_up,
+    });
+    // 검증에 실패한 정의는 실행하지 않는다 (다음 동기화 때 갱신됨)
+    let _ = runs.start(scheduled.dsl.clone(), input);
+}
+
+/// 센서 이벤트에 따라 워크플로우 실행 시작
+///
+/// 실행 입력은 이벤트 필드(`line_code`, `sensor_type`, `value` 등)에
+/// 트리거 정보(`trigger`, `workflow_id`)를 더한 것이다.
+pub fn start_event_run(runs: &RunManager, trigger: EventTrigger<'_>) {
+    let mut input = match serde_json::to_value(trigger.event) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    };
+    input.insert("trigger".into(), json!("event"));
+    input.insert("workflow_id".into(), json!(trigger.workflow_id));
+    let _ = runs.start(trigger.dsl.clone(), Value::Object(input));
+}
+
+/// 트리거 동기화 대상 워크플로우
+#[derive(Debug, Deserialize)]
+pub struct TriggeredWorkflow {
+    pub workflow_id: String,
+    pub dsl: WorkflowDsl,
+}
+
+/// 워크플로우 DSL 검증 (오류가 없으면 빈 목록)
+///
+/// `catalog`는 `GET /workflows/actions` 응답을 그대로 넘긴다.
+/// 생략하면 액션 이름은 확인하지 않는다.
+#[tauri::command]
+pub fn validate_workflow(dsl: WorkflowDsl, catalog: Option<ActionCatalog>) -> Vec<ValidationError> {
+    triflow_workflow::validate(&dsl, catalog.as_ref())
+}
+
+/// 검토용 실행 계획 렌더링 (단계 설명 + Mermaid/DOT 그래프)
+///
+/// 검증에 실패한 정의는 렌더링하지 않고 오류 목록을 반환한다.
+#[tauri::command]
+pub fn render_workflow_plan(
+    dsl: WorkflowDsl,
+    catalog: Option<ActionCatalog>,
+    language: Option<Language>,
+) -> Result<WorkflowPlan, Vec<ValidationError>> {
+    let errors = triflow_workflow::validate(&dsl, catalog.as_ref());
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(triflow_workflow::render_plan(
+        &dsl,
+        catalog.as_ref(),
+        language.unwrap_or_default(),
+    ))
+}
+
+/// 워크플로우 로컬 실행
+///
+/// 노드마다 시작/종료 시 `workflow://node` 이벤트를 보내고, 끝나면 전체 실행
+/// 기록을 반환한다. DSL 검증에 실패하면 실행하지 않고 오류 목록을 반환한다.
+/// [`RunManager`]를 거치지 않으므로 실행 이력은 여기서 직접 [`HistoryStore`]에 남긴다.
+#[tauri::command(async)]
+pub fn run_workflow_local(
+    app: AppHandle,
+    engine: State<'_, Arc<RuleEngine>>,
+    actions: State<'_, Arc<ActionRegistry>>,
+    history: State<'_, HistoryStore>,
+    dsl: WorkflowDsl,
+    input: Option<Value>,
+) -> Result<WorkflowRun, Vec<ValidationError>> {
+    let errors = triflow_workflow::validate(&dsl, None);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let input = input.unwrap_or_else(|| Value::Object(Map::new()));
+    Ok(run_recorded(
+        &engine,
+        &actions,
+        &history,
+        &dsl,
+        input,
+        |event| {
+            let _ = app.emit(NODE_EVENT, event);
+        },
+    ))
+}
+
+/// 실행하면서 시작/노드/종료를 이력에 기록
+fn run_recorded(
+    engine: &RuleEngine,
+    actions: &ActionRegistry,
+    history: &HistoryStore,
+    dsl: &WorkflowDsl,
+    input: Value,
+    mut on_event: impl FnMut(&NodeEvent<'_>),
+) -> WorkflowRun {
+    let executor = WorkflowExecutor::new(engine, actions);
+    let mut run = WorkflowRun::new(Uuid::new_v4().to_string(), dsl, input);
+    // 시작 시각이 남도록 첫 노드 전에 한 번 기록
+    history.on_status(&run);
+    while executor.step(dsl, &mut run, &mut |event| {
+        history.on_node(event);
+        on_event(event);
+    }) {}
+    history.on_status(&run);
+    run
+}
+
+/// 워크플로우 시뮬레이션 (과거 센서 데이터 재생, 액션은 기록만 함)
+#[tauri::command(async)]
+pub fn simulate_workflow(
+    engine: State<'_, Arc<RuleEngine>>,
+    dsl: WorkflowDsl,
+    rows: Vec<Value>,
+    options: Option<SimulationOptions>,
+) -> Result<SimulationReport, Vec<ValidationError>> {
+    let errors = triflow_workflow::validate(&dsl, None);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(triflow_workflow::simulate(
+        &engine,
+        &dsl,
+        &rows,
+        &options.unwrap_or_default(),
+    ))
+}
+
+/// 워크플로우 실행 시작 (진행 상황은 이벤트로 전달)
+#[tauri::command]
+pub fn start_workflow_run(
+    runs: State<'_, RunManager>,
+    dsl: WorkflowDsl,
+    input: Option<Value>,
+) -> Result<WorkflowRun, RunError> {
+    runs.start(dsl, input.unwrap_or_else(|| Value::Object(Map::new())))
+}
+
+/// 실행 일시정지 (실행 중인 노드가 끝난 뒤 적용)
+#[tauri::command]
+pub fn pause_workflow_run(
+    runs: State<'_, RunManager>,
+    run_id: String,
+) -> Result<RunStatus, RunError> {
+    runs.pause(&run_id)
+}
+
+/// 일시정지된 실행 재개
+#[tauri::command]
+pub fn resume_workflow_run(
+    runs: State<'_, RunManager>,
+    run_id: String,
+) -> Result<RunStatus, RunError> {
+    runs.resume(&run_id)
+}
+
+/// 실패한 노드부터 다시 실행
+#[tauri::command]
+pub fn retry_workflow_run(
+    runs: State<'_, RunManager>,
+    run_id: String,
+) -> Result<RunStatus, RunError> {
+    runs.retry(&run_id)
+}
+
+/// 실행 취소
+#[tauri::command]
+pub fn cancel_workflow_run(
+    runs: State<'_, RunManager>,
+    run_id: String,
+) -> Result<RunStatus, RunError> {
+    runs.cancel(&run_id)
+}
+
+/// 승인/거부 오류
+///
+/// Tauri 커맨드 오류로 그대로 반환할 수 있도록 `{"kind": ..., "detail": ...}` 형태로
+/// 직렬화된다. 실행 관리 오류는 [`RunError`]의 형태를 그대로 쓴다.
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum DecisionError {
+    /// 결정한 사람을 기록할 로그인 세션이 없음
+    #[error("not logged in")]
+    NotLoggedIn,
+    #[error(transparent)]
+    #[serde(untagged)]
+    Run(#[from] RunError),
+}
+
+/// 승인 대기 중인 실행 승인 (`decided_by`는 Rust 쪽 로그인 세션의 사용자)
+#[tauri::command]
+pub fn approve_workflow_run(
+    runs: State<'_, RunManager>,
+    auth: State<'_, TokenManager>,
+    run_id: String,
+    comment: Option<String>,
+) -> Result<RunStatus, DecisionError> {
+    let decided_by = auth.current_user().ok_or(DecisionError::NotLoggedIn)?;
+    Ok(runs.approve(&run_id, &decided_by, comment)?)
+}
+
+/// 승인 대기 중인 실행 거부 (실행 취소)
+#[tauri::command]
+pub fn reject_workflow_run(
+    runs: State<'_, RunManager>,
+    auth: State<'_, TokenManager>,
+    run_id: String,
+    comment: Option<String>,
+) -> Result<RunStatus, DecisionError> {
+    let decided_by = auth.current_user().ok_or(DecisionError::NotLoggedIn)?;
+    Ok(runs.reject(&run_id, &decided_by, comment)?)
+}
+
+/// 실행 상태 조회
+#[tauri::command]
+pub fn get_workflow_run(
+    runs: State<'_, RunManager>,
+    run_id: String,
+) -> Result<WorkflowRun, RunError> {
+    runs.get(&run_id).ok_or(RunError::NotFound(run_id))
+}
+
+/// 모든 실행 목록 (최근 변경 순)
+#[tauri::command]
+pub fn list_workflow_runs(runs: State<'_, RunManager>) -> Vec<WorkflowRun> {
+    runs.list()
+}
+
+/// 실행 이력 조회 (워크플로우, 상태, 시작 시각 범위로 필터, 최근 시작 순)
+#[tauri::command(async)]
+pub fn query_workflow_history(
+    history: State<'_, HistoryStore>,
+    query: Option<HistoryQuery>,
+) -> Result<Vec<RunSummary>, HistoryError> {
+    history.query(&query.unwrap_or_default())
+}
+
+/// 실행 타임라인 (노드별 시작/종료, 입력, 출력, 오류)
+#[tauri::command(async)]
+pub fn get_workflow_run_timeline(
+    history: State<'_, HistoryStore>,
+    run_id: String,
+) -> Result<Option<RunTimeline>, HistoryError> {
+    history.timeline(&run_id)
+}
+
+/// 워크플로우 목록으로 스케줄 동기화
+///
+/// 스케줄 트리거 워크플로우를 등록하고, 목록에 없는 워크플로우의 스케줄은 제거한다.
+#[tauri::command]
+pub fn sync_workflow_schedules(
+    scheduler: State<'_, Scheduler>,
+    workflows: Vec<TriggeredWorkflow>,
+) -> ScheduleSyncReport {
+    scheduler.sync(
+        workflows
+            .into_iter()
+            .map(|w| (w.workflow_id, w.dsl))
+            .collect(),
+    )
+}
+
+/// 등록된 스케줄 목록 (다음 실행 시각 순)
+#[tauri::command]
+pub fn list_workflow_schedules(scheduler: State<'_, Scheduler>) -> Vec<ScheduleEntry> {
+    scheduler.entries()
+}
+
+/// 워크플로우 목록으로 이벤트 구독 동기화
+///
+/// 이벤트 트리거 워크플로우를 구독하고, 목록에 없는 워크플로우의 구독은 해제한다.
+#[tauri::command]
+pub fn sync_workflow_events(
+    bus: State<'_, EventBus>,
+    workflows: Vec<TriggeredWorkflow>,
+) -> EventSyncReport {
+    bus.sync(
+        workflows
+            .into_iter()
+            .map(|w| (w.workflow_id, w.dsl))
+            .collect(),
+    )
+}
+
+/// 이벤트 구독 목록
+#[tauri::command]
+pub fn list_event_subscriptions(bus: State<'_, EventBus>) -> Vec<EventSubscription> {
+    bus.subscriptions()
+}
+
+/// 센서 이벤트 발행 (웹뷰 쪽 센서 어댑터용, Rust 어댑터는 [`EventBus`]를 직접 사용)
+#[tauri::command]
+pub fn publish_sensor_event(bus: State<'_, EventBus>, event: SensorEvent) -> PublishOutcome {
+    bus.publish(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_runs_are_recorded_in_history() {
+        let dsl: WorkflowDsl = serde_json::from_value(json!({
+            "name": "local",
+            "trigger": {"type": "manual"},
+            "nodes": [
+                {"id": "check", "type": "condition", "config": {"condition": "temperature > 80"},
+                 "next": ["notify"]},
+                {"id": "notify", "type": "action", "config": {"action": "notify"}},
+            ],
+        }))
+        .unwrap();
+        let history = HistoryStore::in_memory().unwrap();
+        let mut events = 0;
+
+        let run = run_recorded(
+            &RuleEngine::new(),
+            &ActionRegistry::dry_run(),
+            &history,
+            &dsl,
+            json!({"temperature": 90}),
+            |_| events += 1,
+        );
+        assert_eq!(run.status, RunStatus::Completed);
+        assert_eq!(events, 4);
+
+        let timeline = history.timeline(&run.run_id).unwrap().unwrap();
+        assert_eq!(timeline.summary.status, RunStatus::Completed);
+        assert!(timeline.summary.finished_at.is_some());
+        assert!(timeline.summary.started_at <= timeline.nodes[0].started_at);
+        assert_eq!(
+            timeline
+                .nodes
+                .iter()
+                .map(|n| n.node_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["check", "notify"]
+        );
+        assert_eq!(
+            history.query(&HistoryQuery::default()).unwrap()[0].run_id,
+            run.run_id
+        );
+    }
+}
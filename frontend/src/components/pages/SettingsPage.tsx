@@ -3,6 +3,11 @@
  * 일반 설정, Backend 연결, AI 모델, 앱 정보
  */
 import { useState, useEffect } from 'react';
+import {
+  systemService,
+  type BackendHealth,
+  type LocalBackendStatus,
+} from '@/services/systemService';
 
 type Theme = 'system' | 'light' | 'dark';
 type Language = 'ko' | 'en';
@@ -59,6 +64,8 @@ export function SettingsPage() {
 
   const [saveStatus, setSaveStatus] = useState<'idle' | 'saving' | 'saved' | 'error'>('idle');
   const [testingConnection, setTestingConnection] = useState(false);
+  const [localBackend, setLocalBackend] = useState<LocalBackendStatus | null>(null);
+  const [localBackendError, setLocalBackendError] = useState<string | null>(null);
 
   // Load settings from localStorage on mount
   useEffect(() => {
@@ -93,20 +100,56 @@ export function SettingsPage() {
   // Check backend connection on mount
   useEffect(() => {
     checkBackendConnection();
+    if (systemService.isAvailable()) {
+      systemService.getLocalBackendStatus().then(setLocalBackend).catch(() => undefined);
+    }
   }, []);
 
+  const handleToggleLocalBackend = async () => {
+    setLocalBackendError(null);
+    try {
+      if (localBackend?.running) {
+        setLocalBackend(await systemService.stopLocalBackend());
+      } else {
+        setLocalBackend(await systemService.startLocalBackend());
+        // 서버가 뜰 때까지 잠시 기다렸다가 연결 확인
+        setTimeout(checkBackendConnection, 3000);
+      }
+    } catch (error) {
+      const { detail } = error as { detail?: string };
+      setLocalBackendError(detail || String(error));
+    }
+  };
+
+  const handleOpenLogsFolder = async () => {
+    setLocalBackendError(null);
+    try {
+      await systemService.openLogsFolder();
+    } catch (error) {
+      const { detail } = error as { detail?: string };
+      setLocalBackendError(detail || String(error));
+    }
+  };
+
   const checkBackendConnection = async () => {
     setConnectionStatus(prev => ({ ...prev, backend: 'checking' }));
     setTestingConnection(true);
 
     try {
-      const response = await fetch(`${settings.backendUrl}/health`, {
-        method: 'GET',
-        headers: { 'Accept': 'application/json' },
-      });
+      let data: BackendHealth | null = null;
+      if (systemService.isAvailable()) {
+        // 데스크톱 앱: CSP가 직접 요청을 막으므로 Rust 커맨드로 확인
+        // (API 프록시와 같은 백엔드 주소를 쓴다)
+        data = await systemService.checkBackendHealth();
+      } else {
+        const response = await fetch(`${settings.backendUrl}/health`, {
+          method: 'GET',
+          headers: { 'Accept': 'application/json' },
+        });
+        data = response.ok ? await response.json() : null;
+      }
 
-      if (response.ok) {
-        const data = await response.json();
+      if (data) {
         setConnectionStatus({
           backend: 'connected',
           database: data.database === 'connected' ? 'connected' : 'disconnected',
@@ -296,6 +339,37 @@ export function SettingsPage() {
                 />
               </div>
 
+              {/* 로컬 백엔드 (데스크톱 앱) */}
+              {systemService.isAvailable() && (
+                <div>
+                  <label className="block text-sm font-medium text-slate-700 dark:text-slate-300 mb-2">
+                    로컬 백엔드
+                  </label>
+                  <div className="flex items-center gap-2">
+                    <button
+                      onClick={handleToggleLocalBackend}
+                      className="px-3 py-1.5 text-sm font-medium rounded-lg bg-blue-600 text-white hover:bg-blue-700"
+                    >
+                      {localBackend?.running ? '중지' : '시작'}
+                    </button>
+                    <button
+                      onClick={handleOpenLogsFolder}
+                      className="px-3 py-1.5 text-sm font-medium rounded-lg bg-slate-200 dark:bg-slate-700 text-slate-700 dark:text-slate-200 hover:bg-slate-300 dark:hover:bg-slate-600"
+                    >
+                      로그 폴더 열기
+                    </button>
+                    {localBackend?.running && (
+                      <span className="text-xs text-slate-500 dark:text-slate-400">
+                        실행 중 (포트 {localBackend.port}, PID {localBackend.pid})
+                      </span>
+                    )}
+                  </div>
+                  {localBackendError && (
+                    <p className="mt-2 text-sm text-red-600 dark:text-red-400">{localBackendError}</p>
+                  )}
+                </div>
+              )}
+
               {/* 자동 재연결 */}
               <div className="flex items-center justify-between">
                 <label className="text-sm font-medium text-slate-700 dark:text-slate-300">
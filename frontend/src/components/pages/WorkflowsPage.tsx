@@ -35,6 +35,9 @@ import {
   type Workflow,
   type WorkflowInstance,
   type ActionCatalogItem,
+  type LocalRunSummary,
+  type LocalRunTimeline,
+  type WorkflowPlan,
 } from '@/services/workflowService';
 
 // 트리거 타입 한글 매핑
@@ -73,6 +76,13 @@ export function WorkflowsPage() {
   const [instances, setInstances] = useState<WorkflowInstance[]>([]);
   const [loadingInstances, setLoadingInstances] = useState(false);
 
+  // 데스크톱 실행 이력 (서버 없이 조회)
+  const [localRuns, setLocalRuns] = useState<LocalRunSummary[]>([]);
+  const [timeline, setTimeline] = useState<LocalRunTimeline | null>(null);
+
+  // 검토용 실행 계획 (데스크톱 앱에서만)
+  const [plan, setPlan] = useState<WorkflowPlan | null>(null);
+
   // 액션 카탈로그
   const [showCatalog, setShowCatalog] = useState(false);
   const [actions, setActions] = useState<ActionCatalogItem[]>([]);
@@ -89,6 +99,8 @@ export function WorkflowsPage() {
         search: search || undefined,
       });
       setWorkflows(response.workflows);
+      // 목록이 바뀌었을 수 있으므로 데스크톱 트리거 갱신
+      workflowService.syncLocalTriggers().catch(() => undefined);
     } catch (err) {
       setError(err instanceof Error ? err.message : '워크플로우 로드 실패');
       setWorkflows([]);
@@ -107,11 +119,26 @@ export function WorkflowsPage() {
     if (selectedWorkflow?.workflow_id === workflow.workflow_id) {
       setSelectedWorkflow(null);
       setInstances([]);
+      setLocalRuns([]);
+      setTimeline(null);
+      setPlan(null);
       return;
     }
 
     setSelectedWorkflow(workflow);
     setLoadingInstances(true);
+    setTimeline(null);
+    setPlan(null);
+
+    workflowService
+      .renderPlan(workflow.dsl_definition)
+      .then(setPlan)
+      .catch(() => setPlan(null));
+
+    workflowService
+      .queryLocalHistory({ workflow_id: workflow.workflow_id, limit: 5 })
+      .then(setLocalRuns)
+      .catch(() => setLocalRuns([]));
 
     try {
       const response = await workflowService.getInstances(workflow.workflow_id);
@@ -124,6 +151,20 @@ export function WorkflowsPage() {
     }
   };
 
+  // 로컬 실행 타임라인 토글
+  const handleSelectLocalRun = async (runId: string) => {
+    if (timeline?.run_id === runId) {
+      setTimeline(null);
+      return;
+    }
+    try {
+      setTimeline(await workflowService.getLocalRunTimeline(runId));
+    } catch (err) {
+      console.error('Failed to load run timeline:', err);
+      setTimeline(null);
+    }
+  };
+
   // 워크플로우 실행
   const handleRunWorkflow = async (workflow: Workflow, e: React.MouseEvent) => {
     e.stopPropagation();
@@ -443,16 +484,25 @@ export function WorkflowsPage() {
                                           className={`px-3 py-2 rounded-lg border ${
                                             node.type === 'condition'
                                               ? 'border-yellow-300 bg-yellow-50 dark:bg-yellow-900/20'
-                                              : 'border-blue-300 bg-blue-50 dark:bg-blue-900/20'
+                                              : node.type === 'approval'
+                                                ? 'border-purple-300 bg-purple-50 dark:bg-purple-900/20'
+                                                : 'border-blue-300 bg-blue-50 dark:bg-blue-900/20'
                                           }`}
                                         >
                                           <div className="text-xs text-slate-500">
-                                            {idx + 1}. {node.type === 'condition' ? '조건' : '액션'}
+                                            {idx + 1}.{' '}
+                                            {node.type === 'condition'
+                                              ? '조건'
+                                              : node.type === 'approval'
+                                                ? '승인'
+                                                : '액션'}
                                           </div>
                                           <div className="text-sm font-mono">
                                             {node.type === 'condition'
                                               ? (node.config as { condition?: string })?.condition || node.id
-                                              : (node.config as { action?: string })?.action || node.id}
+                                              : node.type === 'approval'
+                                                ? (node.config as { message?: string })?.message || node.id
+                                                : (node.config as { action?: string })?.action || node.id}
                                           </div>
                                         </div>
                                       ))
@@ -462,6 +512,33 @@ export function WorkflowsPage() {
                                   </div>
                                 </div>
 
+                                {/* 실행 계획 */}
+                                {plan && (
+                                  <div>
+                                    <h4 className="text-sm font-semibold mb-2">실행 계획</h4>
+                                    <p className="text-sm text-slate-600 dark:text-slate-300 mb-2">
+                                      {plan.trigger}
+                                    </p>
+                                    <ol className="space-y-1">
+                                      {plan.steps.map((step) => (
+                                        <li key={step.node_id} className="text-sm">
+                                          <span className="font-medium">{step.number}.</span>{' '}
+                                          {step.summary}{' '}
+                                          <span className="text-slate-500">{step.then}</span>
+                                        </li>
+                                      ))}
+                                    </ol>
+                                    <details className="mt-2">
+                                      <summary className="text-xs text-slate-500 cursor-pointer">
+                                        Mermaid 그래프
+                                      </summary>
+                                      <pre className="mt-1 p-2 text-xs font-mono bg-white dark:bg-slate-900 rounded border border-slate-200 dark:border-slate-700 overflow-x-auto">
+                                        {plan.mermaid}
+                                      </pre>
+                                    </details>
+                                  </div>
+                                )}
+
                                 {/* 실행 이력 */}
                                 <div>
                                   <h4 className="text-sm font-semibold mb-2">최근 실행 이력</h4>
@@ -500,6 +577,56 @@ export function WorkflowsPage() {
                                     </div>
                                   )}
                                 </div>
+
+                                {/* 데스크톱 실행 이력 */}
+                                {localRuns.length > 0 && (
+                                  <div>
+                                    <h4 className="text-sm font-semibold mb-2">데스크톱 실행 이력</h4>
+                                    <div className="space-y-2">
+                                      {localRuns.map((run) => {
+                                        const StatusIcon = statusIcons[run.status] || Clock;
+                                        return (
+                                          <div key={run.run_id}>
+                                            <button
+                                              type="button"
+                                              onClick={() => handleSelectLocalRun(run.run_id)}
+                                              className="w-full flex items-center gap-3 p-2 bg-white dark:bg-slate-900 rounded border border-slate-200 dark:border-slate-700 text-left"
+                                            >
+                                              <div className={`p-1 rounded ${statusColors[run.status] ?? ''}`}>
+                                                <StatusIcon className="w-4 h-4" />
+                                              </div>
+                                              <div className="flex-1">
+                                                <span className="text-sm font-medium">{run.status}</span>
+                                                <span className="text-xs text-slate-500 ml-2">
+                                                  {new Date(run.started_at).toLocaleString('ko-KR')}
+                                                </span>
+                                              </div>
+                                              <span className="text-xs text-slate-500">
+                                                노드 {run.node_count}개
+                                              </span>
+                                            </button>
+                                            {timeline?.run_id === run.run_id && (
+                                              <ol className="mt-1 ml-4 space-y-1 border-l border-slate-300 dark:border-slate-600 pl-3">
+                                                {timeline.nodes.map((node, idx) => (
+                                                  <li key={idx} className="text-xs">
+                                                    <span className="font-mono">{node.node_id}</span>
+                                                    <span className="ml-2 text-slate-500">
+                                                      {node.state} · {node.duration_ms}ms ·{' '}
+                                                      {new Date(node.started_at).toLocaleTimeString('ko-KR')}
+                                                    </span>
+                                                    {node.error && (
+                                                      <span className="ml-2 text-red-500">{node.error}</span>
+                                                    )}
+                                                  </li>
+                                                ))}
+                                              </ol>
+                                            )}
+                                          </div>
+                                        );
+                                      })}
+                                    </div>
+                                  </div>
+                                )}
                               </div>
                             </TableCell>
                           </TableRow>
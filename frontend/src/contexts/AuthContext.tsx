@@ -11,7 +11,7 @@ import {
   useCallback,
   type ReactNode,
 } from 'react';
-import type { User, AuthContextType, AuthError } from '../types/auth';
+import type { User, AuthContextType, AuthError, AuthSession } from '../types/auth';
 import * as authService from '../services/authService';
 
 const AuthContext = createContext<AuthContextType | null>(null);
@@ -23,7 +23,6 @@ interface AuthProviderProps {
 export function AuthProvider({ children }: AuthProviderProps) {
   const [user, setUser] = useState<User | null>(null);
   const [accessToken, setAccessToken] = useState<string | null>(null);
-  const [refreshToken, setRefreshToken] = useState<string | null>(null);
   const [isLoading, setIsLoading] = useState(true);
 
   const isAuthenticated = !!user && !!accessToken;
@@ -35,18 +34,16 @@ export function AuthProvider({ children }: AuthProviderProps) {
     authService.clearAuth();
     setUser(null);
     setAccessToken(null);
-    setRefreshToken(null);
   }, []);
 
   /**
    * 자동 로그인 시도 (앱 시작 시)
    */
   const tryAutoLogin = useCallback(async () => {
+    await authService.loadStoredAuth();
     const storedAccessToken = authService.getAccessToken();
-    const storedRefreshToken = authService.getRefreshToken();
-    const storedUser = authService.getSavedUser();
 
-    if (!storedAccessToken || !storedRefreshToken) {
+    if (!storedAccessToken) {
       setIsLoading(false);
       return;
     }
@@ -58,11 +55,19 @@ export function AuthProvider({ children }: AuthProviderProps) {
       // 토큰 유효 - 로그인 상태 복원
       setUser(userInfo);
       setAccessToken(storedAccessToken);
-      setRefreshToken(storedRefreshToken);
       authService.saveUser(userInfo);
     } else {
       // Access Token 만료 - Refresh 시도
-      const newToken = await authService.refreshAccessToken();
+      let newToken: AuthSession | null;
+      try {
+        newToken = await authService.refreshAccessToken();
+      } catch {
+        // 서버에 연결할 수 없음 - 저장된 세션으로 시작 (백그라운드에서 다시 갱신)
+        setUser(authService.getSavedUser());
+        setAccessToken(storedAccessToken);
+        setIsLoading(false);
+        return;
+      }
 
       if (newToken) {
         // Refresh 성공 - 새 토큰으로 사용자 정보 조회
@@ -71,7 +76,6 @@ export function AuthProvider({ children }: AuthProviderProps) {
         if (refreshedUser) {
           setUser(refreshedUser);
           setAccessToken(newToken.access_token);
-          setRefreshToken(storedRefreshToken);
           authService.saveUser(refreshedUser);
         } else {
           // 여전히 실패 - 로그아웃
@@ -81,7 +85,6 @@ export function AuthProvider({ children }: AuthProviderProps) {
         // Refresh 실패 - 로그아웃 (authService에서 이미 clearAuth 호출됨)
         setUser(null);
         setAccessToken(null);
-        setRefreshToken(null);
       }
     }
 
@@ -96,7 +99,6 @@ export function AuthProvider({ children }: AuthProviderProps) {
 
     setUser(response.user);
     setAccessToken(response.access_token);
-    setRefreshToken(response.refresh_token);
   }, []);
 
   // 앱 시작 시 자동 로그인 시도
@@ -104,10 +106,23 @@ export function AuthProvider({ children }: AuthProviderProps) {
     tryAutoLogin();
   }, [tryAutoLogin]);
 
+  // 백그라운드 토큰 갱신 결과 반영 (데스크톱 앱)
+  useEffect(() => {
+    const unlisten = authService.onAuthEvents({
+      onRefreshed: (session) => setAccessToken(session.access_token),
+      onExpired: () => {
+        setUser(null);
+        setAccessToken(null);
+      },
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   const value: AuthContextType = {
     user,
     accessToken,
-    refreshToken,
     isAuthenticated,
     isLoading,
     login,
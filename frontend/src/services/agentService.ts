@@ -11,7 +11,10 @@ export const agentService = {
    * Meta Router를 통한 채팅
    */
   async chat(request: AgentRequest): Promise<AgentResponse> {
-    return await apiClient.post<AgentResponse>('/api/v1/agents/chat', request);
+    // LLM 응답은 오래 걸릴 수 있으므로 시간 제한을 넉넉히
+    return await apiClient.post<AgentResponse>('/api/v1/agents/chat', request, {
+      timeoutMs: 300_000,
+    });
   },
 
   /**
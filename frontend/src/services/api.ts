@@ -1,5 +1,68 @@
+/**
+ * 백엔드 API 클라이언트
+ *
+ * 데스크톱 앱에서는 웹뷰가 백엔드에 직접 요청하지 않고 Rust `api_request`
+ * 커맨드를 거친다. 인증 헤더, 시간 제한, 재시도는 Rust에서 처리한다.
+ * 웹(브라우저)에서는 기존처럼 fetch로 요청한다.
+ */
+
+import { invoke, isTauri } from '@tauri-apps/api/core';
+
 const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
 
+type ApiMethod = 'GET' | 'POST' | 'PUT' | 'PATCH' | 'DELETE';
+
+/** `api_request` 오류 종류 (Rust `ApiError`) */
+export type ApiErrorKind =
+  | 'invalid_request'
+  | 'unauthorized'
+  | 'forbidden'
+  | 'not_found'
+  | 'validation'
+  | 'client'
+  | 'server'
+  | 'timeout'
+  | 'network'
+  | 'invalid_response';
+
+/** API 요청 오류 */
+export class ApiRequestError extends Error {
+  kind: ApiErrorKind;
+  detail: unknown;
+
+  constructor(kind: ApiErrorKind, detail: unknown, message: string) {
+    super(message);
+    this.name = 'ApiRequestError';
+    this.kind = kind;
+    this.detail = detail;
+  }
+}
+
+/** Rust 커맨드 오류(`{kind, detail}`)를 ApiRequestError로 */
+function toApiError(err: unknown): ApiRequestError {
+  const { kind, detail } = (err ?? {}) as { kind?: ApiErrorKind; detail?: unknown };
+  if (!kind) {
+    return new ApiRequestError('network', err, String(err));
+  }
+  let message: string;
+  if (typeof detail === 'string') {
+    message = detail;
+  } else if (detail && typeof detail === 'object' && 'message' in detail) {
+    const { status, message: text } = detail as { status: number; message: string };
+    message = `HTTP ${status}: ${text}`;
+  } else if (kind === 'timeout') {
+    message = '요청 시간이 초과되었습니다';
+  } else {
+    message = detail === undefined ? kind : JSON.stringify(detail);
+  }
+  return new ApiRequestError(kind, detail, message);
+}
+
+export interface RequestOptions {
+  /** 시간 제한 (ms, 데스크톱 앱에서만 적용) */
+  timeoutMs?: number;
+}
+
 export class ApiClient {
   private baseUrl: string;
 
@@ -8,17 +71,31 @@ export class ApiClient {
   }
 
   async request<T>(
+    method: ApiMethod,
     endpoint: string,
-    options: RequestInit = {}
+    body?: unknown,
+    options: RequestOptions = {}
   ): Promise<T> {
+    if (isTauri()) {
+      try {
+        return await invoke<T>('api_request', {
+          request: { method, path: endpoint, body, timeout_ms: options.timeoutMs },
+        });
+      } catch (error) {
+        const apiError = toApiError(error);
+        console.error('API request failed:', apiError);
+        throw apiError;
+      }
+    }
+
     const url = `${this.baseUrl}${endpoint}`;
 
     const config: RequestInit = {
-      ...options,
+      method,
       headers: {
         'Content-Type': 'application/json',
-        ...options.headers,
       },
+      body: body === undefined ? undefined : JSON.stringify(body),
     };
 
     try {
@@ -43,26 +120,20 @@ export class ApiClient {
     }
   }
 
-  async get<T>(endpoint: string): Promise<T> {
-    return this.request<T>(endpoint, { method: 'GET' });
+  async get<T>(endpoint: string, options?: RequestOptions): Promise<T> {
+    return this.request<T>('GET', endpoint, undefined, options);
   }
 
-  async post<T>(endpoint: string, data: any): Promise<T> {
-    return this.request<T>(endpoint, {
-      method: 'POST',
-      body: JSON.stringify(data),
-    });
+  async post<T>(endpoint: string, data: any, options?: RequestOptions): Promise<T> {
+    return this.request<T>('POST', endpoint, data, options);
   }
 
-  async patch<T>(endpoint: string, data: any): Promise<T> {
-    return this.request<T>(endpoint, {
-      method: 'PATCH',
-      body: JSON.stringify(data),
-    });
+  async patch<T>(endpoint: string, data: any, options?: RequestOptions): Promise<T> {
+    return this.request<T>('PATCH', endpoint, data, options);
   }
 
-  async delete<T>(endpoint: string): Promise<T> {
-    return this.request<T>(endpoint, { method: 'DELETE' });
+  async delete<T>(endpoint: string, options?: RequestOptions): Promise<T> {
+    return this.request<T>('DELETE', endpoint, undefined, options);
   }
 }
 
@@ -1,9 +1,19 @@
 /**
  * 인증 API 서비스
  * 로그인, 토큰 갱신, 사용자 정보 조회
+ *
+ * 토큰은 웹뷰 스크립트가 읽을 수 있는 localStorage에 두지 않는다.
+ * 데스크톱 앱에서는 Rust 토큰 관리자가 토큰 쌍을 OS 키체인에 보관하고
+ * 만료 전에 백그라운드에서 갱신한다(`auth://refreshed`, `auth://expired`).
+ * JS는 access token만 메모리에 두며 refresh token은 받지 않는다.
+ * 웹(브라우저)에서는 메모리에만 두므로 새로고침하면 다시 로그인해야 한다.
  */
 
+import { invoke, isTauri } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import { apiClient, ApiRequestError } from './api';
 import type {
+  AuthSession,
   LoginResponse,
   TokenResponse,
   User,
@@ -14,17 +24,35 @@ import type {
 const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
 const AUTH_API_URL = `${API_BASE_URL}/api/v1/auth`;
 
-// localStorage 키
-const STORAGE_KEYS = {
+// 이전 버전이 사용하던 localStorage 키 (시작 시 삭제)
+const LEGACY_STORAGE_KEYS = {
   ACCESS_TOKEN: 'triflow_access_token',
   REFRESH_TOKEN: 'triflow_refresh_token',
   USER: 'triflow_user',
 } as const;
 
+// 현재 세션 (메모리 전용)
+let session: AuthSession | null = null;
+// 웹(브라우저)에서만 사용하는 refresh token (메모리 전용)
+let webRefreshToken: string | null = null;
+
+/** Rust 커맨드 오류(`{kind, detail}`)의 kind */
+function commandErrorKind(error: unknown): string | undefined {
+  const kind = (error as { kind?: unknown } | null)?.kind;
+  return typeof kind === 'string' ? kind : undefined;
+}
+
 /**
  * 에러 타입 판별
  */
 function getErrorType(error: unknown): AuthErrorType {
+  const kind = commandErrorKind(error);
+  if (kind === 'invalid_credentials') return 'credentials';
+  if (kind === 'unavailable') return 'network';
+  if (error instanceof ApiRequestError) {
+    if (error.kind === 'unauthorized') return 'credentials';
+    if (error.kind === 'network' || error.kind === 'timeout') return 'network';
+  }
   if (error instanceof TypeError && error.message.includes('fetch')) {
     return 'network';
   }
@@ -67,11 +95,15 @@ function createAuthError(error: unknown): AuthError {
 
 /**
  * 로그인 API 호출
+ * 데스크톱에서는 Rust 토큰 관리자가 로그인하고 토큰 쌍을 보관하므로 JS는
+ * refresh token을 받지 않는다.
  */
-export async function login(
-  email: string,
-  password: string
-): Promise<LoginResponse> {
+export async function login(email: string, password: string): Promise<AuthSession> {
+  if (isTauri()) {
+    session = await invoke<AuthSession>('login', { email, password });
+    return session;
+  }
+
   const response = await fetch(`${AUTH_API_URL}/login`, {
     method: 'POST',
     headers: {
@@ -86,23 +118,33 @@ export async function login(
   }
 
   const data: LoginResponse = await response.json();
-
-  // 토큰 및 사용자 정보 저장
-  saveTokens(data.access_token, data.refresh_token);
-  saveUser(data.user);
-
-  return data;
+  webRefreshToken = data.refresh_token;
+  session = { access_token: data.access_token, expires_at: null, user: data.user };
+  return session;
 }
 
 /**
- * 토큰 갱신 API 호출
- * 실패 시 자동으로 로그아웃 처리
+ * 토큰 갱신
+ * 데스크톱에서는 Rust 토큰 관리자가 갱신한다. 세션이 만료되면 로그아웃 처리한다.
  */
-export async function refreshAccessToken(): Promise<TokenResponse | null> {
-  const refreshToken = getRefreshToken();
+export async function refreshAccessToken(): Promise<AuthSession | null> {
+  if (isTauri()) {
+    try {
+      session = await invoke<AuthSession>('refresh_credentials');
+      return session;
+    } catch (err) {
+      // 세션이 끝났으면(만료/로그아웃) 지운다. 연결 오류는 세션을 유지하고 호출자에게 알린다
+      const kind = commandErrorKind(err);
+      if (kind === 'expired' || kind === 'not_logged_in') {
+        session = null;
+        return null;
+      }
+      throw err;
+    }
+  }
 
-  if (!refreshToken) {
-    clearAuth();
+  if (!webRefreshToken) {
+    await clearAuth();
     return null;
   }
 
@@ -112,32 +154,62 @@ export async function refreshAccessToken(): Promise<TokenResponse | null> {
       headers: {
         'Content-Type': 'application/json',
       },
-      body: JSON.stringify({ refresh_token: refreshToken }),
+      body: JSON.stringify({ refresh_token: webRefreshToken }),
     });
 
     if (!response.ok) {
       // Refresh 실패 시 모든 토큰 삭제 (무한 루프 방지)
-      clearAuth();
+      await clearAuth();
       return null;
     }
 
     const data: TokenResponse = await response.json();
-
-    // Access Token만 업데이트
-    localStorage.setItem(STORAGE_KEYS.ACCESS_TOKEN, data.access_token);
-
-    return data;
+    session = {
+      access_token: data.access_token,
+      expires_at: null,
+      user: session?.user ?? null,
+    };
+    return session;
   } catch {
     // 네트워크 오류 등 - 토큰 삭제
-    clearAuth();
+    await clearAuth();
     return null;
   }
 }
 
+/**
+ * Rust 토큰 관리자의 갱신/만료 이벤트 구독 (데스크톱 앱에서만)
+ */
+export async function onAuthEvents(handlers: {
+  onRefreshed: (session: AuthSession) => void;
+  onExpired: (reason: string) => void;
+}): Promise<UnlistenFn> {
+  if (!isTauri()) {
+    return () => undefined;
+  }
+  const unlistenRefreshed = await listen<AuthSession>('auth://refreshed', (event) => {
+    session = event.payload;
+    handlers.onRefreshed(event.payload);
+  });
+  const unlistenExpired = await listen<{ reason: string }>('auth://expired', (event) => {
+    session = null;
+    handlers.onExpired(event.payload.reason);
+  });
+  return () => {
+    unlistenRefreshed();
+    unlistenExpired();
+  };
+}
+
 /**
  * 현재 사용자 정보 조회
  */
 export async function getMe(accessToken: string): Promise<User | null> {
+  if (isTauri()) {
+    // 인증 헤더는 Rust 토큰 관리자가 붙인다
+    return await apiClient.get<User>('/api/v1/auth/me').catch(() => null);
+  }
+
   try {
     const response = await fetch(`${AUTH_API_URL}/me`, {
       method: 'GET',
@@ -158,65 +230,67 @@ export async function getMe(accessToken: string): Promise<User | null> {
 }
 
 /**
- * 토큰 저장
+ * 저장된 인증 정보 불러오기 (앱 시작 시)
+ * 데스크톱에서는 Rust가 시작할 때 불러 둔 세션을 갱신해서 받는다.
+ * 이전 버전이 localStorage에 남긴 토큰은 옮기지 않고 삭제한다 (다시 로그인).
  */
-export function saveTokens(
-  accessToken: string,
-  refreshToken: string
-): void {
-  localStorage.setItem(STORAGE_KEYS.ACCESS_TOKEN, accessToken);
-  localStorage.setItem(STORAGE_KEYS.REFRESH_TOKEN, refreshToken);
+export async function loadStoredAuth(): Promise<boolean> {
+  if (!isTauri()) {
+    return !!session;
+  }
+
+  Object.values(LEGACY_STORAGE_KEYS).forEach((key) => localStorage.removeItem(key));
+
+  try {
+    await refreshAccessToken();
+  } catch (err) {
+    console.error('Failed to restore session:', err);
+    session = null;
+  }
+  return !!session;
 }
 
 /**
- * 사용자 정보 저장
+ * 사용자 정보 갱신 (메모리, 키체인에는 로그인 시점 정보가 남음)
  */
 export function saveUser(user: User): void {
-  localStorage.setItem(STORAGE_KEYS.USER, JSON.stringify(user));
+  if (session) {
+    session = { ...session, user };
+  }
 }
 
 /**
  * Access Token 조회
  */
 export function getAccessToken(): string | null {
-  return localStorage.getItem(STORAGE_KEYS.ACCESS_TOKEN);
-}
-
-/**
- * Refresh Token 조회
- */
-export function getRefreshToken(): string | null {
-  return localStorage.getItem(STORAGE_KEYS.REFRESH_TOKEN);
+  return session?.access_token ?? null;
 }
 
 /**
  * 저장된 사용자 정보 조회
  */
 export function getSavedUser(): User | null {
-  const userJson = localStorage.getItem(STORAGE_KEYS.USER);
-  if (!userJson) return null;
-
-  try {
-    return JSON.parse(userJson) as User;
-  } catch {
-    return null;
-  }
+  return session?.user ?? null;
 }
 
 /**
  * 모든 인증 정보 삭제 (로그아웃)
  */
-export function clearAuth(): void {
-  localStorage.removeItem(STORAGE_KEYS.ACCESS_TOKEN);
-  localStorage.removeItem(STORAGE_KEYS.REFRESH_TOKEN);
-  localStorage.removeItem(STORAGE_KEYS.USER);
+export async function clearAuth(): Promise<void> {
+  session = null;
+  webRefreshToken = null;
+  if (isTauri()) {
+    await invoke('clear_credentials').catch((err) => {
+      console.error('Failed to clear credentials:', err);
+    });
+  }
 }
 
 /**
  * 인증 상태 확인
  */
 export function hasStoredAuth(): boolean {
-  return !!getAccessToken() && !!getRefreshToken();
+  return !!session;
 }
 
-export { createAuthError, STORAGE_KEYS };
+export { createAuthError };
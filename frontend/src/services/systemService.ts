@@ -0,0 +1,62 @@
+/**
+ * 로컬 시스템 서비스
+ * 웹뷰는 임의의 프로세스를 실행할 수 없고, 정해진 Rust 커맨드만 호출한다.
+ * (데스크톱 앱에서만 동작)
+ */
+
+import { invoke, isTauri } from '@tauri-apps/api/core';
+
+/** 로컬 백엔드 상태 (`local_backend_status`) */
+export interface LocalBackendStatus {
+  running: boolean;
+  pid?: number;
+  port?: number;
+  log_file: string;
+}
+
+/** 백엔드 `/health` 응답 */
+export interface BackendHealth {
+  database?: string;
+  [key: string]: unknown;
+}
+
+export const systemService = {
+  /** 데스크톱 앱 여부 (로컬 시스템 기능 사용 가능) */
+  isAvailable(): boolean {
+    return isTauri();
+  },
+
+  /**
+   * 로컬 백엔드 시작 (이미 실행 중이면 현재 상태)
+   */
+  async startLocalBackend(port?: number): Promise<LocalBackendStatus> {
+    return await invoke<LocalBackendStatus>('start_local_backend', { port });
+  },
+
+  /**
+   * 이 앱이 시작한 로컬 백엔드 중지
+   */
+  async stopLocalBackend(): Promise<LocalBackendStatus> {
+    return await invoke<LocalBackendStatus>('stop_local_backend');
+  },
+
+  async getLocalBackendStatus(): Promise<LocalBackendStatus> {
+    return await invoke<LocalBackendStatus>('local_backend_status');
+  },
+
+  /**
+   * 백엔드 `/health` 응답 (CSP로 웹뷰가 직접 요청할 수 없어 Rust에서 확인)
+   *
+   * 데스크톱 앱이 API 요청에 쓰는 백엔드 주소를 확인한다.
+   */
+  async checkBackendHealth(): Promise<BackendHealth> {
+    return await invoke<BackendHealth>('check_backend_health');
+  },
+
+  /**
+   * 앱 로그 폴더 열기
+   */
+  async openLogsFolder(): Promise<void> {
+    await invoke('open_logs_folder');
+  },
+};
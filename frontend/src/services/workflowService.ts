@@ -3,6 +3,8 @@
  * 워크플로우 API 통신 서비스
  */
 
+import { invoke, isTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { apiClient } from './api';
 
 // ============ Types ============
@@ -14,7 +16,7 @@ export interface WorkflowTrigger {
 
 export interface WorkflowNode {
   id: string;
-  type: 'condition' | 'action';
+  type: 'condition' | 'action' | 'approval';
   config: Record<string, unknown>;
   next: string[];
 }
@@ -78,6 +80,235 @@ export interface WorkflowCreateParams {
   dsl_definition: WorkflowDSL;
 }
 
+/** Rust 워크플로우 검증기(`validate_workflow`)가 반환하는 오류 */
+export type DslValidationError =
+  | { kind: 'empty_workflow' }
+  | {
+      kind:
+        | 'duplicate_node_id'
+        | 'unknown_node_type'
+        | 'missing_condition'
+        | 'missing_action'
+        | 'unreachable_node';
+      node_id: string;
+    }
+  | { kind: 'unknown_action'; node_id: string; action: string }
+  | { kind: 'invalid_approval'; node_id: string; reason: string }
+  | { kind: 'dangling_next'; node_id: string; target: string }
+  | { kind: 'cycle_without_exit'; node_ids: string[] };
+
+/** 실행 계획 설명 언어 */
+export type PlanLanguage = 'ko' | 'en';
+
+/** 실행 계획의 한 단계 */
+export interface WorkflowPlanStep {
+  number: number;
+  node_id: string;
+  node_type: 'condition' | 'action' | 'approval' | 'unknown';
+  summary: string;
+  then: string;
+  next: number[];
+}
+
+/** `render_workflow_plan`이 반환하는 검토용 실행 계획 */
+export interface WorkflowPlan {
+  language: PlanLanguage;
+  title: string;
+  description?: string;
+  trigger: string;
+  steps: WorkflowPlanStep[];
+  mermaid: string;
+  dot: string;
+}
+
+/** 로컬 실행 노드 진행 이벤트 (`workflow://node`) */
+export interface WorkflowNodeEvent {
+  run_id: string;
+  node_id: string;
+  node_type: 'condition' | 'action' | 'approval' | 'unknown';
+  state: 'running' | 'succeeded' | 'failed' | 'skipped' | 'waiting_approval';
+  output?: unknown;
+  error?: string;
+  started_at: string;
+  duration_ms: number;
+}
+
+/** 로컬 실행 상태 (B-5 생명주기 + paused) */
+export type LocalRunStatus =
+  | 'pending'
+  | 'running'
+  | 'paused'
+  | 'waiting_approval'
+  | 'completed'
+  | 'failed'
+  | 'cancelled'
+  | 'timed_out';
+
+/** 승인 노드의 대기 중인 승인 요청 */
+export interface ApprovalRequest {
+  node_id: string;
+  message: string;
+  requested_at: string;
+  expires_at?: string;
+  on_timeout: 'approve' | 'reject';
+  next: string[];
+}
+
+/** 승인 결정 기록 */
+export interface ApprovalRecord {
+  node_id: string;
+  approved: boolean;
+  decided_by?: string;
+  decided_at: string;
+  timed_out: boolean;
+  comment?: string;
+}
+
+/** 로컬 실행 결과 */
+export interface LocalWorkflowRun {
+  run_id: string;
+  workflow_name: string;
+  status: LocalRunStatus;
+  input: Record<string, unknown>;
+  outputs: Record<string, unknown>;
+  queue: string[];
+  history: Omit<WorkflowNodeEvent, 'run_id'>[];
+  error?: string;
+  failed_node?: string;
+  approval?: ApprovalRequest;
+  approvals?: ApprovalRecord[];
+  updated_at: string;
+}
+
+/** 로컬 실행 이력 조회 조건 (지정한 조건은 AND) */
+export interface LocalHistoryQuery {
+  workflow_id?: string;
+  workflow_name?: string;
+  statuses?: LocalRunStatus[];
+  /** 이 시각 이후 시작한 실행 (ISO 8601) */
+  from?: string;
+  /** 이 시각 이전 시작한 실행 (ISO 8601) */
+  to?: string;
+  limit?: number;
+  offset?: number;
+}
+
+/** 로컬 실행 이력 요약 */
+export interface LocalRunSummary {
+  run_id: string;
+  workflow_id: string | null;
+  workflow_name: string;
+  status: LocalRunStatus;
+  error: string | null;
+  started_at: string;
+  updated_at: string;
+  finished_at: string | null;
+  node_count: number;
+}
+
+/** 로컬 실행 타임라인의 노드 기록 */
+export interface LocalNodeTiming {
+  node_id: string;
+  node_type: WorkflowNodeEvent['node_type'];
+  state: WorkflowNodeEvent['state'];
+  input: Record<string, unknown> | null;
+  output: unknown;
+  error: string | null;
+  started_at: string;
+  finished_at: string;
+  duration_ms: number;
+}
+
+/** 로컬 실행 타임라인 */
+export interface LocalRunTimeline extends LocalRunSummary {
+  input: Record<string, unknown>;
+  outputs: Record<string, unknown>;
+  nodes: LocalNodeTiming[];
+}
+
+/** 시뮬레이션에서 실행되었을 액션 */
+export interface SimulatedAction {
+  row: number;
+  at?: string;
+  node_id: string;
+  action: string;
+  parameters: Record<string, unknown>;
+}
+
+/** 시뮬레이션 결과 */
+export interface SimulationReport {
+  workflow_name: string;
+  rows: number;
+  triggered_rows: number;
+  fired: SimulatedAction[];
+  action_counts: Record<string, number>;
+  failures: { row: number; at?: string; error: string }[];
+}
+
+/** 스케줄 트리거 설정 (trigger.config) */
+export interface ScheduleConfig {
+  cron: string;
+  catch_up: 'skip' | 'run_once' | 'run_all';
+  timezone: 'local' | 'utc';
+}
+
+/** 데스크톱 스케줄러에 등록된 스케줄 */
+export interface WorkflowSchedule {
+  workflow_id: string;
+  dsl: WorkflowDSL;
+  config: ScheduleConfig;
+  next_run: string | null;
+  last_run: string | null;
+}
+
+/** 스케줄 동기화 결과 */
+export interface ScheduleSyncReport {
+  scheduled: WorkflowSchedule[];
+  rejected: { workflow_id: string; error: { kind: string; detail?: unknown } }[];
+}
+
+/** 센서 이벤트 (core.sensor_data 행과 같은 필드) */
+export interface SensorEvent {
+  sensor_id?: string;
+  line_code: string;
+  sensor_type: string;
+  value: number;
+  unit?: string;
+  recorded_at?: string;
+  metadata?: Record<string, unknown>;
+}
+
+/** 이벤트 트리거 설정 (trigger.config) */
+export interface EventTriggerConfig {
+  line_code?: string | string[];
+  sensor_type?: string | string[];
+  filter?: string;
+  debounce_ms?: number;
+}
+
+/** 이벤트 구독 동기화 결과 */
+export interface EventSyncReport {
+  subscribed: {
+    workflow_id: string;
+    dsl: WorkflowDSL;
+    filter: EventTriggerConfig;
+    last_fired: string | null;
+  }[];
+  rejected: { workflow_id: string; error: { kind: string; detail?: unknown } }[];
+}
+
+/** 센서 이벤트 발행 결과 */
+export type PublishOutcome =
+  | { outcome: 'duplicate' }
+  | { outcome: 'dispatched'; started: string[]; debounced: string[] };
+
+export class WorkflowValidationFailedError extends Error {
+  constructor(public readonly errors: DslValidationError[]) {
+    super(`Workflow DSL is invalid (${errors.length} error(s))`);
+    this.name = 'WorkflowValidationFailedError';
+  }
+}
+
 export interface WorkflowListParams {
   is_active?: boolean;
   search?: string;
@@ -113,9 +344,197 @@ export const workflowService = {
   },
 
   /**
-   * 워크플로우 생성
+   * 워크플로우 DSL 검증 (데스크톱 앱에서만 수행, 웹에서는 빈 목록)
+   */
+  async validate(
+    dsl: WorkflowDSL,
+    catalog?: ActionCatalogResponse
+  ): Promise<DslValidationError[]> {
+    if (!isTauri()) {
+      return [];
+    }
+    return await invoke<DslValidationError[]>('validate_workflow', { dsl, catalog });
+  },
+
+  /**
+   * 검토용 실행 계획 렌더링 (데스크톱 앱에서만, 웹에서는 null)
+   *
+   * 검증에 실패하면 `DslValidationError[]`로 reject된다.
+   */
+  async renderPlan(
+    dsl: WorkflowDSL,
+    language: PlanLanguage = 'ko',
+    catalog?: ActionCatalogResponse
+  ): Promise<WorkflowPlan | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<WorkflowPlan>('render_workflow_plan', { dsl, catalog, language });
+  },
+
+  /**
+   * 데스크톱에서 워크플로우 시험 실행 (액션은 dry-run)
+   */
+  async runLocal(
+    dsl: WorkflowDSL,
+    inputData: Record<string, unknown> = {},
+    onNode?: (event: WorkflowNodeEvent) => void
+  ): Promise<LocalWorkflowRun> {
+    const unlisten = onNode
+      ? await listen<WorkflowNodeEvent>('workflow://node', (event) => onNode(event.payload))
+      : undefined;
+    try {
+      return await invoke<LocalWorkflowRun>('run_workflow_local', { dsl, input: inputData });
+    } catch (error) {
+      if (Array.isArray(error)) {
+        throw new WorkflowValidationFailedError(error as DslValidationError[]);
+      }
+      throw error;
+    } finally {
+      unlisten?.();
+    }
+  },
+
+  /**
+   * 과거 센서 데이터로 워크플로우 시뮬레이션 (액션은 실제로 실행하지 않음)
+   *
+   * rows는 시각순으로 정렬된 센서 데이터 행 (예: sensorService 조회 결과의 data)
+   */
+  async simulate(
+    dsl: WorkflowDSL,
+    rows: Record<string, unknown>[],
+    timeField = 'recorded_at'
+  ): Promise<SimulationReport> {
+    try {
+      return await invoke<SimulationReport>('simulate_workflow', {
+        dsl,
+        rows,
+        options: { time_field: timeField },
+      });
+    } catch (error) {
+      if (Array.isArray(error)) {
+        throw new WorkflowValidationFailedError(error as DslValidationError[]);
+      }
+      throw error;
+    }
+  },
+
+  /**
+   * 데스크톱에서 워크플로우 실행 시작 (`workflow://node`, `workflow://status` 이벤트로 진행 전달)
+   */
+  async startLocalRun(
+    dsl: WorkflowDSL,
+    inputData: Record<string, unknown> = {}
+  ): Promise<LocalWorkflowRun> {
+    return await invoke<LocalWorkflowRun>('start_workflow_run', { dsl, input: inputData });
+  },
+
+  /**
+   * 로컬 실행 제어 (일시정지/재개/실패 노드 재시도/취소)
+   */
+  async controlLocalRun(
+    runId: string,
+    command: 'pause' | 'resume' | 'retry' | 'cancel'
+  ): Promise<LocalRunStatus> {
+    return await invoke<LocalRunStatus>(`${command}_workflow_run`, { runId });
+  },
+
+  /**
+   * 승인 대기 중인 로컬 실행에 결정 전달 (승인하면 이어서 실행, 거부하면 취소)
+   *
+   * 결정한 사람은 Rust 쪽 로그인 세션에서 정한다 (로그아웃 상태면 `not_logged_in` 오류).
+   */
+  async decideApproval(
+    runId: string,
+    approved: boolean,
+    comment?: string
+  ): Promise<LocalRunStatus> {
+    const command = approved ? 'approve_workflow_run' : 'reject_workflow_run';
+    return await invoke<LocalRunStatus>(command, { runId, comment });
+  },
+
+  /**
+   * 승인 요청 알림 구독 (`workflow://approval`)
+   */
+  async onApprovalRequested(
+    handler: (run: LocalWorkflowRun) => void
+  ): Promise<() => void> {
+    return await listen<LocalWorkflowRun>('workflow://approval', (event) =>
+      handler(event.payload)
+    );
+  },
+
+  /**
+   * 로컬 실행 목록 (재시작 후 복원된 실행 포함)
+   */
+  async listLocalRuns(): Promise<LocalWorkflowRun[]> {
+    return await invoke<LocalWorkflowRun[]>('list_workflow_runs');
+  },
+
+  /**
+   * 활성 워크플로우로 데스크톱 트리거(스케줄, 센서 이벤트) 동기화 (웹에서는 아무것도 하지 않음)
+   *
+   * 스케줄 트리거 워크플로우는 창이 트레이로 숨겨진 동안에도 데스크톱에서 실행되고,
+   * 이벤트 트리거 워크플로우는 publishSensorEvent로 들어온 이벤트에 바로 실행된다.
+   */
+  async syncLocalTriggers(): Promise<{
+    schedules: ScheduleSyncReport;
+    events: EventSyncReport;
+  } | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    const { workflows } = await workflowService.list({ is_active: true });
+    const payload = {
+      workflows: workflows.map((w) => ({ workflow_id: w.workflow_id, dsl: w.dsl_definition })),
+    };
+    const [schedules, events] = await Promise.all([
+      invoke<ScheduleSyncReport>('sync_workflow_schedules', payload),
+      invoke<EventSyncReport>('sync_workflow_events', payload),
+    ]);
+    return { schedules, events };
+  },
+
+  /**
+   * 센서 이벤트 발행 (일치하는 이벤트 트리거 워크플로우를 데스크톱에서 실행)
+   */
+  async publishSensorEvent(event: SensorEvent): Promise<PublishOutcome> {
+    return await invoke<PublishOutcome>('publish_sensor_event', { event });
+  },
+
+  /**
+   * 데스크톱 스케줄러에 등록된 스케줄 목록 (다음 실행 시각 순)
+   */
+  async listSchedules(): Promise<WorkflowSchedule[]> {
+    return await invoke<WorkflowSchedule[]>('list_workflow_schedules');
+  },
+
+  /**
+   * 데스크톱 실행 이력 조회 (최근 시작 순, 웹에서는 빈 목록)
+   */
+  async queryLocalHistory(query: LocalHistoryQuery = {}): Promise<LocalRunSummary[]> {
+    if (!isTauri()) {
+      return [];
+    }
+    return await invoke<LocalRunSummary[]>('query_workflow_history', { query });
+  },
+
+  /**
+   * 데스크톱 실행 타임라인 (노드별 시작/종료, 입력, 출력, 오류)
+   */
+  async getLocalRunTimeline(runId: string): Promise<LocalRunTimeline | null> {
+    return await invoke<LocalRunTimeline | null>('get_workflow_run_timeline', { runId });
+  },
+
+  /**
+   * 워크플로우 생성 (저장 전 DSL 검증)
    */
   async create(params: WorkflowCreateParams): Promise<Workflow> {
+    const catalog = await workflowService.getActionCatalog();
+    const errors = await workflowService.validate(params.dsl_definition, catalog);
+    if (errors.length > 0) {
+      throw new WorkflowValidationFailedError(errors);
+    }
     return await apiClient.post<Workflow>('/api/v1/workflows', params);
   },
 
@@ -35,10 +35,16 @@ export interface TokenResponse {
   expires_in: number;
 }
 
+/** Rust 토큰 관리자가 웹뷰에 공개하는 세션 (refresh token 제외) */
+export interface AuthSession {
+  access_token: string;
+  expires_at: string | null;
+  user: User | null;
+}
+
 export interface AuthState {
   user: User | null;
   accessToken: string | null;
-  refreshToken: string | null;
   isAuthenticated: boolean;
   isLoading: boolean;
 }